@@ -0,0 +1,21 @@
+-- Collapse the body data history back into a single row holding the latest version
+DROP INDEX IF EXISTS idx_body_data_effective_date;
+
+CREATE TABLE body_data_old
+(
+    id             INTEGER NOT NULL PRIMARY KEY,
+    age            INTEGER NOT NULL,
+    height         REAL    NOT NULL,
+    weight         REAL    NOT NULL,
+    sex            TEXT    NOT NULL,
+    activity_level REAL    NOT NULL DEFAULT 1.0
+);
+
+INSERT INTO body_data_old (id, age, height, weight, sex, activity_level)
+SELECT 1, age, height, weight, sex, activity_level
+FROM body_data
+ORDER BY effective_date DESC
+LIMIT 1;
+
+DROP TABLE body_data;
+ALTER TABLE body_data_old RENAME TO body_data;
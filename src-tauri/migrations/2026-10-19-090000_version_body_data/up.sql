@@ -0,0 +1,37 @@
+-- Version body data by effective date. A profile change records a new row instead of
+-- overwriting the single one, so age, activity level and weight changes keep their
+-- history and past calculations can be reproduced from the profile that applied then.
+CREATE TABLE body_data_new
+(
+    id             INTEGER NOT NULL PRIMARY KEY,
+    age            INTEGER NOT NULL,
+    height         REAL    NOT NULL,
+    weight         REAL    NOT NULL,
+    sex            TEXT    NOT NULL,
+    activity_level REAL    NOT NULL DEFAULT 1.0,
+    effective_date TEXT    NOT NULL
+);
+
+-- The existing profile has applied since the earliest tracked day (today if nothing
+-- has been tracked yet).
+INSERT INTO body_data_new (id, age, height, weight, sex, activity_level, effective_date)
+SELECT id,
+       age,
+       height,
+       weight,
+       sex,
+       activity_level,
+       COALESCE((SELECT MIN(d)
+                 FROM (SELECT MIN(start_date) AS d FROM weight_target
+                       UNION ALL
+                       SELECT MIN(start_date) FROM intake_target
+                       UNION ALL
+                       SELECT MIN(added) FROM weight_tracker)),
+                date('now'))
+FROM body_data;
+
+DROP TABLE body_data;
+ALTER TABLE body_data_new RENAME TO body_data;
+
+-- One version per day: correcting the profile twice on the same day replaces that day's row.
+CREATE UNIQUE INDEX idx_body_data_effective_date ON body_data (effective_date);
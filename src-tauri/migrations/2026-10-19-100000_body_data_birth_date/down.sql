@@ -0,0 +1,27 @@
+-- Restore the static age, derived from the birth date as of today
+CREATE TABLE body_data_old
+(
+    id             INTEGER NOT NULL PRIMARY KEY,
+    age            INTEGER NOT NULL,
+    height         REAL    NOT NULL,
+    weight         REAL    NOT NULL,
+    sex            TEXT    NOT NULL,
+    activity_level REAL    NOT NULL DEFAULT 1.0,
+    effective_date TEXT    NOT NULL
+);
+
+INSERT INTO body_data_old (id, age, height, weight, sex, activity_level, effective_date)
+SELECT id,
+       CAST((julianday('now') - julianday(birth_date)) / 365.25 AS INTEGER),
+       height,
+       weight,
+       sex,
+       activity_level,
+       effective_date
+FROM body_data;
+
+DROP INDEX IF EXISTS idx_body_data_effective_date;
+DROP TABLE body_data;
+ALTER TABLE body_data_old RENAME TO body_data;
+
+CREATE UNIQUE INDEX idx_body_data_effective_date ON body_data (effective_date);
@@ -0,0 +1,31 @@
+-- Replace the static age with a birth date so age is derived at calculation time instead of
+-- silently going stale every year.
+CREATE TABLE body_data_new
+(
+    id             INTEGER NOT NULL PRIMARY KEY,
+    birth_date     TEXT    NOT NULL,
+    height         REAL    NOT NULL,
+    weight         REAL    NOT NULL,
+    sex            TEXT    NOT NULL,
+    activity_level REAL    NOT NULL DEFAULT 1.0,
+    effective_date TEXT    NOT NULL
+);
+
+-- Approximate the birth date from the age stated when the version was recorded: `age` years
+-- and half a year before the effective date puts the birthday mid-year, so the derived age on
+-- the effective date matches the stored one.
+INSERT INTO body_data_new (id, birth_date, height, weight, sex, activity_level, effective_date)
+SELECT id,
+       date(effective_date, '-' || age || ' years', '-6 months'),
+       height,
+       weight,
+       sex,
+       activity_level,
+       effective_date
+FROM body_data;
+
+DROP INDEX IF EXISTS idx_body_data_effective_date;
+DROP TABLE body_data;
+ALTER TABLE body_data_new RENAME TO body_data;
+
+CREATE UNIQUE INDEX idx_body_data_effective_date ON body_data (effective_date);
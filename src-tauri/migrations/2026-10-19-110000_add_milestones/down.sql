@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_milestone_kind;
+DROP TABLE milestone;
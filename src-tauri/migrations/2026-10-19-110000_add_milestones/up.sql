@@ -0,0 +1,10 @@
+-- Milestones reached by the user (streaks, weight lost). Each kind is recorded once,
+-- on the date it was first reached.
+CREATE TABLE milestone
+(
+    id          INTEGER NOT NULL PRIMARY KEY,
+    kind        TEXT    NOT NULL,
+    achieved_on TEXT    NOT NULL
+);
+
+CREATE UNIQUE INDEX idx_milestone_kind ON milestone (kind);
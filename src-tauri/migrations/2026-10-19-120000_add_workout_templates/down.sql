@@ -0,0 +1,10 @@
+ALTER TABLE workout_exercise DROP COLUMN plan_rest_seconds;
+ALTER TABLE workout_exercise DROP COLUMN target_rpe;
+ALTER TABLE workout_exercise DROP COLUMN target_weight_kg;
+ALTER TABLE workout_exercise DROP COLUMN target_reps_max;
+ALTER TABLE workout_exercise DROP COLUMN target_reps_min;
+ALTER TABLE workout_exercise DROP COLUMN target_sets;
+
+DROP INDEX IF EXISTS idx_workout_template_exercise_template;
+DROP TABLE IF EXISTS workout_template_exercise;
+DROP TABLE IF EXISTS workout_template;
@@ -0,0 +1,34 @@
+-- Workout templates: named routines of ordered exercises with per-exercise targets.
+CREATE TABLE workout_template
+(
+    id           INTEGER NOT NULL PRIMARY KEY,
+    name         TEXT    NOT NULL,
+    workout_type TEXT    NOT NULL DEFAULT 'wl' REFERENCES workout_type (shortvalue)
+);
+
+-- target_weight_kg and target_rpe are alternatives (at most one is set); rest_seconds is
+-- the plan-derived rest target, the highest-precedence source for the rest countdown.
+CREATE TABLE workout_template_exercise
+(
+    id               INTEGER NOT NULL PRIMARY KEY,
+    template_id      INTEGER NOT NULL REFERENCES workout_template (id),
+    exercise_id      INTEGER NOT NULL REFERENCES exercise (id),
+    position         INTEGER NOT NULL,
+    target_sets      INTEGER NOT NULL,
+    target_reps_min  INTEGER NOT NULL,
+    target_reps_max  INTEGER NOT NULL,
+    target_weight_kg REAL,
+    target_rpe       REAL,
+    rest_seconds     INTEGER
+);
+
+CREATE INDEX idx_workout_template_exercise_template ON workout_template_exercise (template_id, position);
+
+-- A session started from a template snapshots each exercise's plan, so later template
+-- edits don't rewrite the targets of past workouts. All NULL for ad-hoc exercises.
+ALTER TABLE workout_exercise ADD COLUMN target_sets INTEGER;
+ALTER TABLE workout_exercise ADD COLUMN target_reps_min INTEGER;
+ALTER TABLE workout_exercise ADD COLUMN target_reps_max INTEGER;
+ALTER TABLE workout_exercise ADD COLUMN target_weight_kg REAL;
+ALTER TABLE workout_exercise ADD COLUMN target_rpe REAL;
+ALTER TABLE workout_exercise ADD COLUMN plan_rest_seconds INTEGER;
@@ -0,0 +1,9 @@
+DROP INDEX idx_exercise_workout_type;
+
+DELETE FROM exercise_muscle WHERE exercise_id BETWEEN 50 AND 61;
+DELETE FROM exercise WHERE id BETWEEN 50 AND 61;
+
+ALTER TABLE exercise DROP COLUMN workout_type;
+
+DELETE FROM exercise_category WHERE shortvalue = 'cardio';
+DELETE FROM workout_type WHERE shortvalue IN ('cardio', 'run', 'cycle', 'row', 'swim');
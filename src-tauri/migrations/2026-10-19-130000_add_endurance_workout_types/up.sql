@@ -0,0 +1,72 @@
+-- Endurance workout types. Each has its own metric payload schema (see `SetMetrics`);
+-- the session's workout_type decides how workout_set.metrics is parsed.
+INSERT INTO workout_type (shortvalue, longvalue)
+VALUES ('cardio', 'Cardio'),
+       ('run', 'Running'),
+       ('cycle', 'Cycling'),
+       ('row', 'Rowing'),
+       ('swim', 'Swimming');
+
+INSERT INTO exercise_category (shortvalue, longvalue)
+VALUES ('cardio', 'Cardio');
+
+-- The workout type an exercise can be logged in, so each type gets its own library.
+-- No REFERENCES clause: SQLite only allows adding a foreign key column with a NULL default.
+ALTER TABLE exercise ADD COLUMN workout_type TEXT NOT NULL DEFAULT 'wl';
+
+-- Rest between endurance intervals falls back to the global default.
+INSERT INTO exercise (id, name, category, default_rest_seconds, workout_type)
+VALUES (50, 'Elliptical', 'cardio', NULL, 'cardio'),
+       (51, 'Stair Climber', 'cardio', NULL, 'cardio'),
+       (52, 'Jump Rope', 'cardio', NULL, 'cardio'),
+       (53, 'Outdoor Run', 'cardio', NULL, 'run'),
+       (54, 'Treadmill Run', 'cardio', NULL, 'run'),
+       (55, 'Outdoor Ride', 'cardio', NULL, 'cycle'),
+       (56, 'Indoor Cycling', 'cardio', NULL, 'cycle'),
+       (57, 'Rowing Machine', 'cardio', NULL, 'row'),
+       (58, 'Freestyle', 'cardio', NULL, 'swim'),
+       (59, 'Breaststroke', 'cardio', NULL, 'swim'),
+       (60, 'Backstroke', 'cardio', NULL, 'swim'),
+       (61, 'Butterfly', 'cardio', NULL, 'swim');
+
+INSERT INTO exercise_muscle (exercise_id, muscle, role)
+VALUES (50, 'quadriceps', 'primary'),
+       (50, 'gluteal', 'secondary'),
+       (50, 'hamstring', 'secondary'),
+       (51, 'quadriceps', 'primary'),
+       (51, 'gluteal', 'secondary'),
+       (51, 'calves', 'secondary'),
+       (52, 'calves', 'primary'),
+       (52, 'quadriceps', 'secondary'),
+       (53, 'quadriceps', 'primary'),
+       (53, 'hamstring', 'secondary'),
+       (53, 'calves', 'secondary'),
+       (53, 'gluteal', 'secondary'),
+       (54, 'quadriceps', 'primary'),
+       (54, 'hamstring', 'secondary'),
+       (54, 'calves', 'secondary'),
+       (54, 'gluteal', 'secondary'),
+       (55, 'quadriceps', 'primary'),
+       (55, 'gluteal', 'secondary'),
+       (55, 'calves', 'secondary'),
+       (56, 'quadriceps', 'primary'),
+       (56, 'gluteal', 'secondary'),
+       (56, 'calves', 'secondary'),
+       (57, 'upper-back', 'primary'),
+       (57, 'quadriceps', 'secondary'),
+       (57, 'biceps', 'secondary'),
+       (57, 'lower-back', 'secondary'),
+       (58, 'upper-back', 'primary'),
+       (58, 'deltoids', 'secondary'),
+       (58, 'triceps', 'secondary'),
+       (59, 'chest', 'primary'),
+       (59, 'adductors', 'secondary'),
+       (59, 'quadriceps', 'secondary'),
+       (60, 'upper-back', 'primary'),
+       (60, 'deltoids', 'secondary'),
+       (60, 'lower-back', 'secondary'),
+       (61, 'chest', 'primary'),
+       (61, 'deltoids', 'secondary'),
+       (61, 'abs', 'secondary');
+
+CREATE INDEX idx_exercise_workout_type ON exercise (workout_type);
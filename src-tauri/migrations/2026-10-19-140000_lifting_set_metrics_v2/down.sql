@@ -0,0 +1,14 @@
+-- Back to v1 (reps and external load only). Lossy: time-only sets get a single rep,
+-- and assistance is dropped while added load becomes the external load again.
+UPDATE workout_set
+SET metrics     = json_remove(json_set(metrics,
+                                       '$.reps', coalesce(json_extract(metrics, '$.reps'), 1),
+                                       '$.weightKg', json_extract(metrics, '$.weightKg') +
+                                                     max(coalesce(json_extract(metrics, '$.addedLoadKg'), 0), 0)),
+                              '$.durationSeconds', '$.bodyweight', '$.addedLoadKg'),
+    payload_ver = 1
+WHERE payload_ver = 2
+  AND workout_exercise_id IN (SELECT we.id
+                              FROM workout_exercise we
+                                       JOIN workout_session s ON s.id = we.session_id
+                              WHERE s.workout_type = 'wl');
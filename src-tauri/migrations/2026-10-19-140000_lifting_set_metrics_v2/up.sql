@@ -0,0 +1,28 @@
+-- Weight-lifting set metrics v2 (`LiftingSetMetrics`, WL_PAYLOAD_VER = 2): optional reps
+-- and duration, a bodyweight flag and an added (positive) or assisting (negative) load.
+-- Only `wl` sessions are touched; endurance payloads have their own versions.
+--
+-- v1 sets on bodyweight exercises carried their extra weight as `weightKg`; in v2 that is
+-- the added load, and the external load is zero.
+UPDATE workout_set
+SET metrics     = json_set(metrics,
+                           '$.weightKg', 0.0,
+                           '$.bodyweight', json('true'),
+                           '$.addedLoadKg', nullif(json_extract(metrics, '$.weightKg'), 0)),
+    payload_ver = 2
+WHERE payload_ver = 1
+  AND workout_exercise_id IN (SELECT we.id
+                              FROM workout_exercise we
+                                       JOIN workout_session s ON s.id = we.session_id
+                                       JOIN exercise e ON e.id = we.exercise_id
+                              WHERE s.workout_type = 'wl'
+                                AND e.category = 'bodyweight');
+
+UPDATE workout_set
+SET metrics     = json_set(metrics, '$.bodyweight', json('false')),
+    payload_ver = 2
+WHERE payload_ver = 1
+  AND workout_exercise_id IN (SELECT we.id
+                              FROM workout_exercise we
+                                       JOIN workout_session s ON s.id = we.session_id
+                              WHERE s.workout_type = 'wl');
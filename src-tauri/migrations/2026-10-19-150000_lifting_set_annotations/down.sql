@@ -0,0 +1,9 @@
+-- Back to v2. Lossy: annotations are dropped, and warm-ups count as working sets again.
+UPDATE workout_set
+SET metrics     = json_remove(metrics, '$.rpe', '$.rir', '$.kind', '$.note'),
+    payload_ver = 2
+WHERE payload_ver = 3
+  AND workout_exercise_id IN (SELECT we.id
+                              FROM workout_exercise we
+                                       JOIN workout_session s ON s.id = we.session_id
+                              WHERE s.workout_type = 'wl');
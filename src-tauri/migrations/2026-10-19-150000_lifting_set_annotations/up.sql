@@ -0,0 +1,10 @@
+-- Weight-lifting set metrics v3 (`LiftingSetMetrics`, WL_PAYLOAD_VER = 3): optional RPE or
+-- RIR, a set kind and a note. Existing sets become plain working sets.
+UPDATE workout_set
+SET metrics     = json_set(metrics, '$.kind', 'working'),
+    payload_ver = 3
+WHERE payload_ver = 2
+  AND workout_exercise_id IN (SELECT we.id
+                              FROM workout_exercise we
+                                       JOIN workout_session s ON s.id = we.session_id
+                              WHERE s.workout_type = 'wl');
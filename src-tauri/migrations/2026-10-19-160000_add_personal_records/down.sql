@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_personal_record_set;
+DROP INDEX IF EXISTS idx_personal_record_exercise;
+DROP TABLE personal_record;
@@ -0,0 +1,14 @@
+-- Personal records per exercise, derived from the weight-lifting set history. One row per
+-- set that set a record of a kind at the time it was logged; rebuilt for an exercise
+-- whenever its sets change, so it is a cache and may be dropped and recomputed at any time.
+CREATE TABLE personal_record
+(
+    id          INTEGER NOT NULL PRIMARY KEY,
+    exercise_id INTEGER NOT NULL REFERENCES exercise (id),
+    set_id      INTEGER NOT NULL REFERENCES workout_set (id) ON DELETE CASCADE,
+    kind        TEXT    NOT NULL,
+    value       REAL    NOT NULL
+);
+
+CREATE INDEX idx_personal_record_exercise ON personal_record (exercise_id);
+CREATE INDEX idx_personal_record_set ON personal_record (set_id);
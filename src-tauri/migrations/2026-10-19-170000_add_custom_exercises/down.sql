@@ -0,0 +1,5 @@
+DELETE FROM exercise_muscle WHERE exercise_id IN (SELECT id FROM exercise WHERE custom = 1);
+DELETE FROM exercise WHERE custom = 1;
+
+ALTER TABLE exercise DROP COLUMN archived;
+ALTER TABLE exercise DROP COLUMN custom;
@@ -0,0 +1,4 @@
+-- Exercises added by the user. Seeded exercises keep custom = 0 and are read-only;
+-- archived exercises are hidden from the library but keep their logged history.
+ALTER TABLE exercise ADD COLUMN custom INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE exercise ADD COLUMN archived INTEGER NOT NULL DEFAULT 0;
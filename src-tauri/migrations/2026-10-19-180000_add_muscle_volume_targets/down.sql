@@ -0,0 +1 @@
+DROP TABLE muscle_volume_target;
@@ -0,0 +1,12 @@
+-- Weekly hard-set range per muscle that training volume is compared against. Seeded with
+-- the common 10-20 sets per week and adjustable by the user.
+CREATE TABLE muscle_volume_target
+(
+    muscle          TEXT    NOT NULL PRIMARY KEY REFERENCES muscle (shortvalue),
+    min_weekly_sets INTEGER NOT NULL,
+    max_weekly_sets INTEGER NOT NULL
+);
+
+INSERT INTO muscle_volume_target (muscle, min_weekly_sets, max_weekly_sets)
+SELECT shortvalue, 10, 20
+FROM muscle;
@@ -0,0 +1,3 @@
+DROP INDEX idx_workout_exercise_position;
+ALTER TABLE workout_exercise DROP COLUMN group_id;
+ALTER TABLE workout_exercise DROP COLUMN position;
@@ -0,0 +1,14 @@
+-- Exercises of a session are ordered by an explicit position instead of their id, so
+-- they can be reordered and the same exercise can appear in several blocks (e.g. a
+-- heavy and a back-off block). Blocks sharing a group_id are performed as a superset
+-- or circuit; their sets are rounds in logging order.
+ALTER TABLE workout_exercise ADD COLUMN position INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE workout_exercise ADD COLUMN group_id INTEGER;
+
+UPDATE workout_exercise
+SET position = (SELECT COUNT(*)
+                FROM workout_exercise earlier
+                WHERE earlier.session_id = workout_exercise.session_id
+                  AND earlier.id < workout_exercise.id);
+
+CREATE INDEX idx_workout_exercise_position ON workout_exercise (session_id, position);
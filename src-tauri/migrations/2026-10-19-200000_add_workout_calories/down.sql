@@ -0,0 +1,2 @@
+DROP TABLE app_settings;
+ALTER TABLE workout_type DROP COLUMN met;
@@ -0,0 +1,21 @@
+-- Metabolic equivalent of each workout type, for estimating the calories a completed
+-- workout burned (MET × body weight in kg × active hours). Values follow the
+-- Compendium of Physical Activities for a moderate-to-vigorous effort.
+ALTER TABLE workout_type ADD COLUMN met REAL NOT NULL DEFAULT 5.0;
+
+UPDATE workout_type SET met = 5.0 WHERE shortvalue = 'wl';
+UPDATE workout_type SET met = 7.0 WHERE shortvalue = 'cardio';
+UPDATE workout_type SET met = 9.8 WHERE shortvalue = 'run';
+UPDATE workout_type SET met = 7.5 WHERE shortvalue = 'cycle';
+UPDATE workout_type SET met = 7.0 WHERE shortvalue = 'row';
+UPDATE workout_type SET met = 6.0 WHERE shortvalue = 'swim';
+
+-- App-wide preferences. There is exactly one row.
+CREATE TABLE app_settings
+(
+    id                   INTEGER NOT NULL PRIMARY KEY CHECK (id = 1),
+    add_workout_calories INTEGER NOT NULL DEFAULT 0
+);
+
+INSERT INTO app_settings (id)
+VALUES (1);
@@ -0,0 +1 @@
+ALTER TABLE workout_set DROP COLUMN rest_seconds;
@@ -0,0 +1,4 @@
+-- Optional rest target for the rest after a set, overriding the exercise default
+-- (the plan's rest still takes precedence). The rest actually taken is derived from
+-- consecutive logged_at values and pauses, not stored.
+ALTER TABLE workout_set ADD COLUMN rest_seconds INTEGER;
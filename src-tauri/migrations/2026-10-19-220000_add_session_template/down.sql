@@ -0,0 +1 @@
+ALTER TABLE workout_session DROP COLUMN template_id;
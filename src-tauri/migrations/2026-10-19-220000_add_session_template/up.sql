@@ -0,0 +1,3 @@
+-- The template a session was started from, so its summary can be compared with the
+-- previous session of the same template. Deleting the template keeps the session.
+ALTER TABLE workout_session ADD COLUMN template_id INTEGER REFERENCES workout_template (id) ON DELETE SET NULL;
@@ -0,0 +1,2 @@
+ALTER TABLE workout_session DROP COLUMN auto_completed;
+ALTER TABLE app_settings DROP COLUMN stale_session_hours;
@@ -0,0 +1,5 @@
+-- Inactivity after which an active session is auto-completed; NULL never auto-completes.
+ALTER TABLE app_settings ADD COLUMN stale_session_hours INTEGER DEFAULT 4;
+
+-- Sessions ended by the stale check rather than by the user
+ALTER TABLE workout_session ADD COLUMN auto_completed INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,7 @@
+-- The powerlift an exercise counts as for strength scores. Bench press, back squat and
+-- deadlift of the seeded library count by default; users can tag variations too.
+ALTER TABLE exercise ADD COLUMN strength_lift TEXT CHECK (strength_lift IN ('squat', 'bench', 'deadlift'));
+
+UPDATE exercise SET strength_lift = 'bench' WHERE id = 1;
+UPDATE exercise SET strength_lift = 'squat' WHERE id = 2;
+UPDATE exercise SET strength_lift = 'deadlift' WHERE id = 3;
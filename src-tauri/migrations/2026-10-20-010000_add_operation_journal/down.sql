@@ -0,0 +1,2 @@
+DROP INDEX idx_operation_journal_performed_at;
+DROP TABLE operation_journal;
@@ -0,0 +1,10 @@
+-- Rows removed by destructive operations, kept as JSON so the latest one can be undone.
+-- Entries older than the retention window are purged.
+CREATE TABLE operation_journal (
+    id           INTEGER PRIMARY KEY NOT NULL,
+    operation    TEXT    NOT NULL,
+    payload      TEXT    NOT NULL,
+    performed_at TEXT    NOT NULL
+);
+
+CREATE INDEX idx_operation_journal_performed_at ON operation_journal (performed_at);
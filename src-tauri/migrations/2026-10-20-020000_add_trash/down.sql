@@ -0,0 +1,23 @@
+-- Journal entries refer to trashed rows by id, which the previous format can't undo
+DELETE FROM operation_journal;
+
+-- Trashed rows would reappear as live ones
+DELETE FROM intake WHERE deleted_at IS NOT NULL;
+DELETE FROM weight_tracker WHERE deleted_at IS NOT NULL;
+DELETE FROM workout_set WHERE workout_exercise_id IN (
+    SELECT workout_exercise.id FROM workout_exercise
+    JOIN workout_session ON workout_session.id = workout_exercise.session_id
+    WHERE workout_session.deleted_at IS NOT NULL
+);
+DELETE FROM workout_exercise WHERE session_id IN (
+    SELECT id FROM workout_session WHERE deleted_at IS NOT NULL
+);
+DELETE FROM workout_pause WHERE session_id IN (
+    SELECT id FROM workout_session WHERE deleted_at IS NOT NULL
+);
+DELETE FROM workout_session WHERE deleted_at IS NOT NULL;
+
+ALTER TABLE app_settings DROP COLUMN trash_retention_days;
+ALTER TABLE workout_session DROP COLUMN deleted_at;
+ALTER TABLE weight_tracker DROP COLUMN deleted_at;
+ALTER TABLE intake DROP COLUMN deleted_at;
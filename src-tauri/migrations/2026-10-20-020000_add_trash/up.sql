@@ -0,0 +1,19 @@
+-- Deleted entries and workouts are moved to the trash rather than removed, and purged
+-- for good after trash_retention_days or when the trash is emptied.
+ALTER TABLE intake ADD COLUMN deleted_at TEXT;
+ALTER TABLE weight_tracker ADD COLUMN deleted_at TEXT;
+ALTER TABLE workout_session ADD COLUMN deleted_at TEXT;
+
+ALTER TABLE app_settings ADD COLUMN trash_retention_days INTEGER NOT NULL DEFAULT 30;
+
+-- The journal now refers to trashed rows by id instead of keeping copies of them, so it
+-- starts over in that format.
+DROP TABLE operation_journal;
+CREATE TABLE operation_journal (
+    id           INTEGER PRIMARY KEY NOT NULL,
+    operation    TEXT    NOT NULL,
+    payload      TEXT    NOT NULL,
+    performed_at TEXT    NOT NULL
+);
+
+CREATE INDEX idx_operation_journal_performed_at ON operation_journal (performed_at);
@@ -0,0 +1,2 @@
+DELETE FROM operation_journal WHERE undone_at IS NOT NULL;
+ALTER TABLE operation_journal DROP COLUMN undone_at;
@@ -0,0 +1,3 @@
+-- Undone operations stay in the journal until a new operation is recorded, so they can be
+-- redone.
+ALTER TABLE operation_journal ADD COLUMN undone_at TEXT;
@@ -1,13 +1,23 @@
 // @generated automatically by Diesel CLI.
 
+diesel::table! {
+    app_settings (id) {
+        id -> Integer,
+        add_workout_calories -> Bool,
+        stale_session_hours -> Nullable<Integer>,
+        trash_retention_days -> Integer,
+    }
+}
+
 diesel::table! {
     body_data (id) {
         id -> Integer,
-        age -> Integer,
+        birth_date -> Text,
         height -> Float,
         weight -> Float,
         sex -> Text,
         activity_level -> Float,
+        effective_date -> Text,
     }
 }
 
@@ -26,6 +36,7 @@ diesel::table! {
         category -> Text,
         description -> Nullable<Text>,
         time -> Text,
+        deleted_at -> Nullable<Text>,
     }
 }
 
@@ -65,6 +76,30 @@ diesel::table! {
         added -> Text,
         amount -> Float,
         time -> Text,
+        deleted_at -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    workout_template (id) {
+        id -> Integer,
+        name -> Text,
+        workout_type -> Text,
+    }
+}
+
+diesel::table! {
+    workout_template_exercise (id) {
+        id -> Integer,
+        template_id -> Integer,
+        exercise_id -> Integer,
+        position -> Integer,
+        target_sets -> Integer,
+        target_reps_min -> Integer,
+        target_reps_max -> Integer,
+        target_weight_kg -> Nullable<Double>,
+        target_rpe -> Nullable<Float>,
+        rest_seconds -> Nullable<Integer>,
     }
 }
 
@@ -72,6 +107,7 @@ diesel::table! {
     workout_type (shortvalue) {
         longvalue -> Text,
         shortvalue -> Text,
+        met -> Float,
     }
 }
 
@@ -82,6 +118,14 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    milestone (id) {
+        id -> Integer,
+        kind -> Text,
+        achieved_on -> Text,
+    }
+}
+
 diesel::table! {
     muscle (shortvalue) {
         longvalue -> Text,
@@ -89,12 +133,24 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    muscle_volume_target (muscle) {
+        muscle -> Text,
+        min_weekly_sets -> Integer,
+        max_weekly_sets -> Integer,
+    }
+}
+
 diesel::table! {
     exercise (id) {
         id -> Integer,
         name -> Text,
         category -> Text,
         default_rest_seconds -> Nullable<Integer>,
+        workout_type -> Text,
+        custom -> Bool,
+        archived -> Bool,
+        strength_lift -> Nullable<Text>,
     }
 }
 
@@ -113,6 +169,9 @@ diesel::table! {
         name -> Nullable<Text>,
         started_at -> Text,
         ended_at -> Nullable<Text>,
+        template_id -> Nullable<Integer>,
+        auto_completed -> Bool,
+        deleted_at -> Nullable<Text>,
     }
 }
 
@@ -121,6 +180,14 @@ diesel::table! {
         id -> Integer,
         session_id -> Integer,
         exercise_id -> Integer,
+        target_sets -> Nullable<Integer>,
+        target_reps_min -> Nullable<Integer>,
+        target_reps_max -> Nullable<Integer>,
+        target_weight_kg -> Nullable<Double>,
+        target_rpe -> Nullable<Float>,
+        plan_rest_seconds -> Nullable<Integer>,
+        position -> Integer,
+        group_id -> Nullable<Integer>,
     }
 }
 
@@ -131,6 +198,17 @@ diesel::table! {
         logged_at -> Text,
         payload_ver -> Integer,
         metrics -> Text,
+        rest_seconds -> Nullable<Integer>,
+    }
+}
+
+diesel::table! {
+    personal_record (id) {
+        id -> Integer,
+        exercise_id -> Integer,
+        set_id -> Integer,
+        kind -> Text,
+        value -> Double,
     }
 }
 
@@ -143,16 +221,34 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    operation_journal (id) {
+        id -> Integer,
+        operation -> Text,
+        payload -> Text,
+        performed_at -> Text,
+        undone_at -> Nullable<Text>,
+    }
+}
+
 diesel::joinable!(exercise -> exercise_category (category));
 diesel::joinable!(exercise_muscle -> exercise (exercise_id));
 diesel::joinable!(exercise_muscle -> muscle (muscle));
 diesel::joinable!(workout_session -> workout_type (workout_type));
+diesel::joinable!(workout_session -> workout_template (template_id));
 diesel::joinable!(workout_exercise -> workout_session (session_id));
 diesel::joinable!(workout_exercise -> exercise (exercise_id));
 diesel::joinable!(workout_set -> workout_exercise (workout_exercise_id));
 diesel::joinable!(workout_pause -> workout_session (session_id));
+diesel::joinable!(muscle_volume_target -> muscle (muscle));
+diesel::joinable!(personal_record -> exercise (exercise_id));
+diesel::joinable!(personal_record -> workout_set (set_id));
+diesel::joinable!(workout_template -> workout_type (workout_type));
+diesel::joinable!(workout_template_exercise -> workout_template (template_id));
+diesel::joinable!(workout_template_exercise -> exercise (exercise_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
+    app_settings,
     body_data,
     exercise,
     exercise_category,
@@ -161,12 +257,18 @@ diesel::allow_tables_to_appear_in_same_query!(
     intake,
     intake_target,
     libre_user,
+    milestone,
     muscle,
+    muscle_volume_target,
+    operation_journal,
+    personal_record,
     weight_target,
     weight_tracker,
     workout_exercise,
     workout_pause,
     workout_session,
     workout_set,
+    workout_template,
+    workout_template_exercise,
     workout_type,
 );
@@ -4,22 +4,48 @@ extern crate rust_i18n;
 i18n!("locales", fallback = "en");
 
 // Composite service commands (composition of multiple models)
+use crate::service::calendar::{
+    get_activity_dates_in_range, get_workout_dates_in_range, get_workout_frequency,
+};
 use crate::service::dashboard::daily_dashboard;
-use crate::service::progress::get_tracker_progress;
+use crate::service::energy::get_workout_energy;
+use crate::service::progress::{get_exercise_progress, get_tracker_progress, get_weight_forecast};
+use crate::service::report::get_period_report;
 use crate::service::tracker_history::get_tracker_history;
 use crate::service::wizard::{
     wizard_calculate_for_target_date, wizard_calculate_for_target_weight, wizard_calculate_tdee,
-    wizard_create_targets,
+    wizard_calculate_tdee_for_date, wizard_create_targets,
 };
 
 // Individual model commands
-use crate::service::body::{get_body_data, update_body_data};
-use crate::service::export::{cancel_export, export_database_file, ExportCancellation};
+use crate::service::adherence::get_adherence;
+use crate::service::body::{
+    get_body_data, get_body_data_as_of, get_body_data_history, update_body_data,
+};
+use crate::service::exercise::{
+    archive_exercise, create_exercise, delete_exercise, get_exercise_categories, get_muscles,
+    update_exercise,
+};
+use crate::service::export::{
+    cancel_export, export_database_file, export_period_report, ExportCancellation,
+};
 use crate::service::import::{cancel_import, import_data_file, ImportCancellation};
 use crate::service::intake::{
     create_intake, create_intake_target, delete_intake, get_food_categories,
     get_intake_dates_in_range, get_intake_for_date_range, get_last_intake_target, update_intake,
 };
+use crate::service::journal::{
+    get_last_operation, get_last_undone_operation, redo_last_operation, undo_last_operation,
+    JournalEntry,
+};
+use crate::service::muscle_volume::{
+    get_muscle_volume, get_muscle_volume_targets, update_muscle_volume_target,
+};
+use crate::service::personal_record::{get_personal_records, PersonalRecord};
+use crate::service::progression::get_previous_performance;
+use crate::service::settings::{get_app_settings, update_app_settings};
+use crate::service::strength::{get_strength_scores, tag_strength_lift};
+use crate::service::trash::{self, empty_trash, get_trash, restore_from_trash};
 use crate::service::user::{get_user, update_user};
 use crate::service::weight::{
     create_weight_target, create_weight_tracker_entry, delete_weight_tracker_entry,
@@ -27,10 +53,17 @@ use crate::service::weight::{
     update_weight_tracker_entry,
 };
 use crate::service::workout::{
-    add_workout_set, create_workout_for_date, delete_workout, delete_workout_set,
-    discard_workout_session, end_workout_session, get_active_workout, get_exercise_library,
-    list_workouts, log_workout_set, pause_workout_session, resume_workout_session,
-    start_workout_session, update_workout_set,
+    add_workout_exercise, add_workout_set, create_workout_for_date, delete_workout,
+    delete_workout_set, discard_workout_session, end_workout_session, get_active_workout,
+    get_exercise_library, get_workout_types, group_workout_exercises, list_workouts,
+    log_activity_set, log_workout_block_set, log_workout_set, pause_workout_session,
+    reorder_workout_exercises, resume_workout_session, search_exercises, start_activity_session,
+    start_workout_session, ungroup_workout_exercise, update_activity_set, update_workout_session,
+    update_workout_set, update_workout_set_logged_at, update_workout_set_rest, WorkoutSession,
+};
+use crate::service::workout_template::{
+    create_workout_template, delete_workout_template, get_workout_template, list_workout_templates,
+    start_workout_session_from_template, update_workout_template,
 };
 
 use crate::db::{connection, migrations};
@@ -68,7 +101,10 @@ pub fn run() {
         .setup(setup_db)
         .invoke_handler(tauri::generate_handler![
             daily_dashboard,
+            get_adherence,
             get_tracker_progress,
+            get_weight_forecast,
+            get_period_report,
             get_tracker_history,
             get_food_categories,
             create_intake,
@@ -91,15 +127,25 @@ pub fn run() {
             wizard_create_targets,
             wizard_calculate_for_target_date,
             wizard_calculate_for_target_weight,
+            wizard_calculate_tdee_for_date,
             get_body_data,
+            get_body_data_as_of,
+            get_body_data_history,
             update_body_data,
             export_database_file,
             cancel_export,
+            export_period_report,
             import_data_file,
             cancel_import,
             start_workout_session,
+            start_activity_session,
             log_workout_set,
+            log_activity_set,
             update_workout_set,
+            update_activity_set,
+            update_workout_set_rest,
+            update_workout_set_logged_at,
+            update_workout_session,
             delete_workout_set,
             pause_workout_session,
             resume_workout_session,
@@ -107,10 +153,50 @@ pub fn run() {
             discard_workout_session,
             get_active_workout,
             get_exercise_library,
+            search_exercises,
+            get_workout_types,
             list_workouts,
             delete_workout,
             create_workout_for_date,
-            add_workout_set
+            add_workout_set,
+            add_workout_exercise,
+            log_workout_block_set,
+            reorder_workout_exercises,
+            group_workout_exercises,
+            ungroup_workout_exercise,
+            create_workout_template,
+            update_workout_template,
+            delete_workout_template,
+            list_workout_templates,
+            get_workout_template,
+            start_workout_session_from_template,
+            get_personal_records,
+            get_previous_performance,
+            get_exercise_progress,
+            create_exercise,
+            update_exercise,
+            archive_exercise,
+            delete_exercise,
+            get_exercise_categories,
+            get_muscles,
+            get_muscle_volume,
+            get_muscle_volume_targets,
+            update_muscle_volume_target,
+            get_workout_energy,
+            get_app_settings,
+            update_app_settings,
+            get_strength_scores,
+            tag_strength_lift,
+            get_workout_dates_in_range,
+            get_workout_frequency,
+            get_activity_dates_in_range,
+            get_last_operation,
+            undo_last_operation,
+            get_last_undone_operation,
+            redo_last_operation,
+            get_trash,
+            restore_from_trash,
+            empty_trash
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -189,6 +275,23 @@ fn setup_db(app: &mut App) -> Result<(), Box<dyn std::error::Error>> {
         }
     }
 
+    // Personal records are a cache; fill it for histories logged before it existed
+    if let Err(e) = PersonalRecord::backfill(&mut conn) {
+        log::error!("Failed to backfill personal records: {}", e);
+    }
+    // A workout forgotten since the last run is completed before anything reads it
+    if let Err(e) = WorkoutSession::auto_complete_if_stale(&mut conn) {
+        log::error!("Failed to auto-complete a stale workout: {}", e);
+    }
+    // Deleted rows are only kept for a while
+    if let Err(e) = trash::purge_expired(&mut conn) {
+        log::error!("Failed to purge the trash: {}", e);
+    }
+    if let Err(e) = JournalEntry::purge_expired(&mut conn) {
+        log::error!("Failed to purge the operation journal: {}", e);
+    }
+    drop(conn);
+
     // Store the pool and cancellation states in Tauri's managed state
     app.manage(pool);
     app.manage(ExportCancellation::new());
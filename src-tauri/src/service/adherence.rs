@@ -0,0 +1,344 @@
+use crate::db::connection::DbPool;
+use crate::db::schema::{intake, milestone, weight_target, weight_tracker};
+use crate::service::intake::{Intake, IntakeTarget};
+use crate::service::workout::{local_day_start, WorkoutSession};
+use crate::util::error_handler::handle_error;
+use crate::util::math_f32::floor_f32;
+use chrono::{Days, NaiveDate};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use tauri::{command, State};
+
+/// Logging streak lengths (days) that are recorded as milestones
+const STREAK_MILESTONES: [i32; 4] = [7, 30, 100, 365];
+
+/// Total weight loss (kg) that is recorded as milestones
+const WEIGHT_LOST_MILESTONES: [i32; 5] = [5, 10, 15, 20, 30];
+
+/// Window for the workout frequency, in days (ending on the requested date)
+const WORKOUT_WINDOW_DAYS: u64 = 28;
+
+// ============================================================================
+// MODELS
+// ============================================================================
+
+/// A milestone the user reached, e.g. `streak_30` or `weight_lost_5`. Each kind is
+/// recorded once, on the date it was first reached.
+#[derive(Queryable, Selectable, Serialize, Deserialize, Debug, Clone)]
+#[diesel(table_name = milestone)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+#[serde(rename_all = "camelCase")]
+pub struct Milestone {
+    pub id: i32,
+    pub kind: String,
+    pub achieved_on: String,
+}
+
+/// For recording a new [Milestone].
+#[derive(Insertable, Serialize, Deserialize, Debug)]
+#[diesel(table_name = milestone)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+#[serde(rename_all = "camelCase")]
+pub struct NewMilestone {
+    pub kind: String,
+    pub achieved_on: String,
+}
+
+// ============================================================================
+// COMPOSITION MODEL
+// ============================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Adherence {
+    /// Consecutive days with an intake or weight entry, ending on the given date (or the
+    /// day before, while the given date has nothing logged yet)
+    pub logging_streak: i32,
+    pub longest_logging_streak: i32,
+    /// Completed days of the current intake target up to the day before the given date
+    pub days_elapsed: i32,
+    /// Days of `days_elapsed` with calories logged
+    pub days_logged: i32,
+    /// Logged days that stayed within `target_calories`
+    pub days_within_target: i32,
+    /// Logged days that stayed within `maximum_calories` (includes `days_within_target`)
+    pub days_within_maximum: i32,
+    pub workouts_last_28_days: i32,
+    pub workouts_per_week: f32,
+    pub milestones: Vec<Milestone>,
+}
+
+// ============================================================================
+// REPOSITORY
+// ============================================================================
+
+impl Milestone {
+    /// Record a milestone, or move its kind to the date it was first reached
+    pub fn record(conn: &mut SqliteConnection, new_milestone: &NewMilestone) -> QueryResult<usize> {
+        diesel::insert_into(milestone::table)
+            .values(new_milestone)
+            .on_conflict(milestone::kind)
+            .do_update()
+            .set(milestone::achieved_on.eq(&new_milestone.achieved_on))
+            .execute(conn)
+    }
+
+    /// Milestones reached up to `date_str`, oldest first
+    pub fn reached_by(conn: &mut SqliteConnection, date_str: &str) -> QueryResult<Vec<Self>> {
+        milestone::table
+            .filter(milestone::achieved_on.le(date_str))
+            .order((milestone::achieved_on.asc(), milestone::id.asc()))
+            .load(conn)
+    }
+
+    /// Record the milestones the logged history reaches, each on the date it was first
+    /// reached, and revoke those it no longer reaches, e.g. after an entry was deleted.
+    /// Called within the transaction of every write to intake or weight entries, trash
+    /// included.
+    pub fn refresh(conn: &mut SqliteConnection) -> QueryResult<()> {
+        let mut reached: Vec<NewMilestone> = Vec::new();
+
+        let mut streak = 0;
+        let mut previous: Option<NaiveDate> = None;
+        for date in logged_dates(conn)? {
+            streak = match previous {
+                Some(previous) if previous.succ_opt() == Some(date) => streak + 1,
+                _ => 1,
+            };
+            if STREAK_MILESTONES.contains(&streak) {
+                reached.push(NewMilestone {
+                    kind: format!("streak_{}", streak),
+                    achieved_on: date.format("%Y-%m-%d").to_string(),
+                });
+            }
+            previous = Some(date);
+        }
+
+        let initial_weight: Option<f32> = weight_target::table
+            .order(weight_target::id.asc())
+            .select(weight_target::initial_weight)
+            .first(conn)
+            .optional()?;
+        if let Some(initial_weight) = initial_weight {
+            for kg in WEIGHT_LOST_MILESTONES {
+                let first_reached: Option<String> = weight_tracker::table
+                    .filter(weight_tracker::deleted_at.is_null())
+                    .filter(weight_tracker::amount.le(initial_weight - kg as f32))
+                    .order((weight_tracker::added.asc(), weight_tracker::time.asc()))
+                    .select(weight_tracker::added)
+                    .first(conn)
+                    .optional()?;
+                if let Some(added) = first_reached {
+                    reached.push(NewMilestone {
+                        kind: format!("weight_lost_{}", kg),
+                        achieved_on: added,
+                    });
+                }
+            }
+        }
+
+        let kinds: Vec<&str> = reached.iter().map(|m| m.kind.as_str()).collect();
+        diesel::delete(milestone::table.filter(milestone::kind.ne_all(&kinds))).execute(conn)?;
+        // A kind reached again later keeps its first date
+        for new_milestone in &reached {
+            Self::record(conn, new_milestone)?;
+        }
+        Ok(())
+    }
+}
+
+// ============================================================================
+// COMPOSITION LOGIC
+// ============================================================================
+
+impl Adherence {
+    /// Compute streaks, calorie adherence and workout frequency as of the given date, with
+    /// the milestones reached by then.
+    pub fn build_for_date(conn: &mut SqliteConnection, date_str: &str) -> Result<Self, String> {
+        let date = NaiveDate::parse_from_str(date_str, "%Y-%m-%d")
+            .map_err(|_| "Invalid date format".to_string())?;
+
+        let logged_dates: BTreeSet<NaiveDate> = logged_dates(conn)
+            .map_err(handle_error)?
+            .range(..=date)
+            .copied()
+            .collect();
+        let logging_streak = current_streak(&logged_dates, &date);
+        let longest_logging_streak = longest_streak(&logged_dates);
+
+        let (days_elapsed, days_logged, days_within_target, days_within_maximum) =
+            match IntakeTarget::find_last(conn)
+                .optional()
+                .map_err(handle_error)?
+            {
+                Some(intake_target) => calorie_adherence(conn, &intake_target, &date)?,
+                None => (0, 0, 0, 0),
+            };
+
+        let workouts_last_28_days = workouts_in_window(conn, &date)?;
+        let workouts_per_week = floor_f32(
+            workouts_last_28_days as f32 * 7.0 / WORKOUT_WINDOW_DAYS as f32,
+            1,
+        );
+
+        let milestones = Milestone::reached_by(conn, date_str).map_err(handle_error)?;
+
+        Ok(Adherence {
+            logging_streak,
+            longest_logging_streak,
+            days_elapsed,
+            days_logged,
+            days_within_target,
+            days_within_maximum,
+            workouts_last_28_days,
+            workouts_per_week,
+            milestones,
+        })
+    }
+}
+
+// ============================================================================
+// HELPER FUNCTIONS
+// ============================================================================
+
+/// Distinct dates with an intake or weight entry
+fn logged_dates(conn: &mut SqliteConnection) -> QueryResult<BTreeSet<NaiveDate>> {
+    let intake_dates: Vec<String> = intake::table
+        .filter(intake::deleted_at.is_null())
+        .select(intake::added)
+        .distinct()
+        .load(conn)?;
+    let weight_dates: Vec<String> = weight_tracker::table
+        .filter(weight_tracker::deleted_at.is_null())
+        .select(weight_tracker::added)
+        .distinct()
+        .load(conn)?;
+
+    Ok(intake_dates
+        .iter()
+        .chain(weight_dates.iter())
+        .filter_map(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
+        .collect())
+}
+
+/// Streak ending on `date`, or on the day before if nothing is logged on `date` yet
+fn current_streak(logged_dates: &BTreeSet<NaiveDate>, date: &NaiveDate) -> i32 {
+    let mut day = if logged_dates.contains(date) {
+        *date
+    } else {
+        match date.checked_sub_days(Days::new(1)) {
+            Some(day) => day,
+            None => return 0,
+        }
+    };
+
+    let mut streak = 0;
+    while logged_dates.contains(&day) {
+        streak += 1;
+        day = match day.checked_sub_days(Days::new(1)) {
+            Some(previous) => previous,
+            None => break,
+        };
+    }
+    streak
+}
+
+fn longest_streak(logged_dates: &BTreeSet<NaiveDate>) -> i32 {
+    let mut longest = 0;
+    let mut streak = 0;
+    let mut previous: Option<NaiveDate> = None;
+
+    for date in logged_dates {
+        streak = match previous {
+            Some(previous) if previous.succ_opt() == Some(*date) => streak + 1,
+            _ => 1,
+        };
+        longest = i32::max(longest, streak);
+        previous = Some(*date);
+    }
+    longest
+}
+
+/// `(days_elapsed, days_logged, days_within_target, days_within_maximum)` for the intake
+/// target, counting completed days only (up to the day before `date`).
+fn calorie_adherence(
+    conn: &mut SqliteConnection,
+    intake_target: &IntakeTarget,
+    date: &NaiveDate,
+) -> Result<(i32, i32, i32, i32), String> {
+    let start_date = NaiveDate::parse_from_str(&intake_target.start_date, "%Y-%m-%d")
+        .map_err(|_| "Invalid date format".to_string())?;
+    let end_date = NaiveDate::parse_from_str(&intake_target.end_date, "%Y-%m-%d")
+        .map_err(|_| "Invalid date format".to_string())?;
+
+    let query_end_date = match date.checked_sub_days(Days::new(1)) {
+        Some(yesterday) if yesterday < end_date => yesterday,
+        Some(_) => end_date,
+        None => return Ok((0, 0, 0, 0)),
+    };
+
+    if query_end_date < start_date {
+        return Ok((0, 0, 0, 0));
+    }
+
+    let intake = Intake::find_by_date_range(
+        conn,
+        &intake_target.start_date,
+        &query_end_date.format("%Y-%m-%d").to_string(),
+    )
+    .map_err(handle_error)?;
+
+    let mut daily_sums: BTreeMap<String, i32> = BTreeMap::new();
+    for entry in intake {
+        *daily_sums.entry(entry.added).or_insert(0) += entry.amount;
+    }
+
+    let days_elapsed = query_end_date.signed_duration_since(start_date).num_days() as i32 + 1;
+    let days_logged = daily_sums.len() as i32;
+    let days_within_target = daily_sums
+        .values()
+        .filter(|sum| **sum <= intake_target.target_calories)
+        .count() as i32;
+    let days_within_maximum = daily_sums
+        .values()
+        .filter(|sum| **sum <= intake_target.maximum_calories)
+        .count() as i32;
+
+    Ok((
+        days_elapsed,
+        days_logged,
+        days_within_target,
+        days_within_maximum,
+    ))
+}
+
+/// Completed workouts started in the 28 local days ending on `date`
+fn workouts_in_window(conn: &mut SqliteConnection, date: &NaiveDate) -> Result<i32, String> {
+    let from = date
+        .checked_sub_days(Days::new(WORKOUT_WINDOW_DAYS - 1))
+        .ok_or("Failed to subtract days".to_string())?;
+    let to = date.succ_opt().ok_or("Failed to add day".to_string())?;
+
+    let sessions =
+        WorkoutSession::completed_in_range(conn, &local_day_start(&from)?, &local_day_start(&to)?)
+            .map_err(handle_error)?;
+
+    Ok(sessions.len() as i32)
+}
+
+// ============================================================================
+// COMMANDS (Tauri)
+// ============================================================================
+
+/// Return streaks, calorie adherence, workout frequency and milestones as of the given date
+#[command]
+pub fn get_adherence(pool: State<DbPool>, date_str: String) -> Result<Adherence, String> {
+    log::debug!(">>> get_adherence date_str={:?}", date_str);
+
+    let mut conn = pool
+        .get()
+        .map_err(|e| format!("Failed to get connection: {}", e))?;
+
+    Adherence::build_for_date(&mut conn, &date_str)
+}
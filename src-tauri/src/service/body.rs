@@ -1,6 +1,7 @@
 use crate::db::schema::body_data;
 use crate::db::{connection::DbPool, DbExecutor};
 
+use chrono::{Local, NaiveDate};
 use diesel::prelude::*;
 use serde::{Deserialize, Serialize};
 use tauri::{command, State};
@@ -10,17 +11,19 @@ use validator::Validate;
 // MODELS
 // ============================================================================
 
-/// Represents the body data upon profile creation.
-#[derive(
-    Insertable, Queryable, Selectable, AsChangeset, Serialize, Deserialize, Debug, Validate,
-)]
+/// Represents the body data in effect from `effective_date` on. Every profile change
+/// records a new version; the one with the latest effective date is the current profile.
+#[derive(Queryable, Selectable, Serialize, Deserialize, Debug, Validate)]
 #[diesel(table_name = body_data)]
 #[diesel(check_for_backend(diesel::sqlite::Sqlite))]
 #[serde(rename_all = "camelCase")]
 pub struct BodyData {
     pub id: i32,
-    #[validate(range(min = 18, max = 99, message = "Age must be between 18 and 99 years."))]
-    pub age: i32,
+    #[validate(custom(
+        function = "validate_birth_date",
+        message = "Age must be between 18 and 99 years."
+    ))]
+    pub birth_date: String,
     #[validate(range(
         min = 100.0,
         max = 220.0,
@@ -39,6 +42,25 @@ pub struct BodyData {
         message = "Activity level must be one of: 1.0, 1.25, 1.5, 1.75, 2.0"
     ))]
     pub activity_level: f32,
+    #[validate(custom(
+        function = "validate_effective_date",
+        message = "Effective date must be a date (YYYY-MM-DD) that does not lie in the future."
+    ))]
+    pub effective_date: String,
+}
+
+/// For recording a new [BodyData] version.
+#[derive(Insertable, AsChangeset, Serialize, Deserialize, Debug)]
+#[diesel(table_name = body_data)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+#[serde(rename_all = "camelCase")]
+pub struct NewBodyData {
+    pub birth_date: String,
+    pub height: f32,
+    pub weight: f32,
+    pub sex: String,
+    pub activity_level: f32,
+    pub effective_date: String,
 }
 
 fn validate_activity_level(activity_level: f32) -> Result<(), validator::ValidationError> {
@@ -48,109 +70,191 @@ fn validate_activity_level(activity_level: f32) -> Result<(), validator::Validat
     }
 }
 
+/// Birth dates must be YYYY-MM-DD and put today's age into the supported 18..=99 range
+pub fn validate_birth_date(birth_date: &str) -> Result<(), validator::ValidationError> {
+    validate_age_on(birth_date, &Local::now().date_naive()).map(|_| ())
+}
+
+/// The age on `date` if the birth date is valid and the age lies in the supported
+/// 18..=99 range
+pub fn validate_age_on(
+    birth_date: &str,
+    date: &NaiveDate,
+) -> Result<i32, validator::ValidationError> {
+    match age_on(birth_date, date) {
+        Ok(age) if (18..=99).contains(&age) => Ok(age),
+        _ => Err(validator::ValidationError::new("invalid_birth_date")),
+    }
+}
+
+/// Age in completed years on the given date; 0 if `date` lies before the birth date
+pub fn calculate_age(birth_date: &NaiveDate, date: &NaiveDate) -> i32 {
+    date.years_since(*birth_date).unwrap_or(0) as i32
+}
+
+/// Age in completed years on the given date of a YYYY-MM-DD birth date
+pub fn age_on(birth_date: &str, date: &NaiveDate) -> Result<i32, String> {
+    NaiveDate::parse_from_str(birth_date, "%Y-%m-%d")
+        .map(|parsed| calculate_age(&parsed, date))
+        .map_err(|e| format!("Invalid birth date {:?}: {}", birth_date, e))
+}
+
+fn validate_effective_date(effective_date: &str) -> Result<(), validator::ValidationError> {
+    match NaiveDate::parse_from_str(effective_date, "%Y-%m-%d") {
+        Ok(date) if date <= Local::now().date_naive() => Ok(()),
+        _ => Err(validator::ValidationError::new("invalid_effective_date")),
+    }
+}
+
+/// Today's date, the default effective date of a profile change
+fn today() -> String {
+    Local::now().format("%Y-%m-%d").to_string()
+}
+
 // ============================================================================
 // REPOSITORY
 // ============================================================================
 
 impl BodyData {
-    /// Return the body data (there can only be one)
+    /// Age in completed years on the given date
+    pub fn age_on(&self, date: &NaiveDate) -> Result<i32, String> {
+        age_on(&self.birth_date, date)
+    }
+
+    /// Return the current body data (the version with the latest effective date)
     pub fn get(conn: &mut SqliteConnection) -> QueryResult<Self> {
-        body_data::table.first(conn)
+        body_data::table
+            .order(body_data::effective_date.desc())
+            .first(conn)
     }
 
-    /// Update body data, or create if it doesn't exist
+    /// Return the body data that applied on the given date (YYYY-MM-DD)
+    pub fn as_of(conn: &mut SqliteConnection, date: &str) -> QueryResult<Self> {
+        body_data::table
+            .filter(body_data::effective_date.le(date))
+            .order(body_data::effective_date.desc())
+            .first(conn)
+    }
+
+    /// Return every recorded body data version, oldest first
+    pub fn history(conn: &mut SqliteConnection) -> QueryResult<Vec<Self>> {
+        body_data::table
+            .order(body_data::effective_date.asc())
+            .load(conn)
+    }
+
+    /// Record a body data change effective today, or create the first version
     pub fn update(
         conn: &mut SqliteConnection,
-        age: &i32,
+        birth_date: &str,
         height: &f32,
         weight: &f32,
         sex: &str,
         activity_level: &f32,
     ) -> QueryResult<Self> {
-        match body_data::table.first::<Self>(conn) {
-            Ok(existing) => {
-                let record = Self {
-                    id: existing.id,
-                    age: *age,
-                    height: *height,
-                    weight: *weight,
-                    sex: sex.to_owned(),
-                    activity_level: *activity_level,
-                };
-
-                diesel::update(body_data::table)
-                    .set(&record)
+        Self::record(
+            conn,
+            &NewBodyData {
+                birth_date: birth_date.to_owned(),
+                height: *height,
+                weight: *weight,
+                sex: sex.to_owned(),
+                activity_level: *activity_level,
+                effective_date: today(),
+            },
+        )
+    }
+
+    /// Record a body data version. A version already effective on the same date is
+    /// replaced, so correcting the profile twice on one day doesn't pile up rows.
+    pub fn record(conn: &mut SqliteConnection, new_version: &NewBodyData) -> QueryResult<Self> {
+        let existing = body_data::table
+            .filter(body_data::effective_date.eq(&new_version.effective_date))
+            .first::<Self>(conn)
+            .optional()?;
+
+        match existing {
+            Some(existing) => {
+                diesel::update(body_data::table.filter(body_data::id.eq(existing.id)))
+                    .set(new_version)
                     .returning(Self::as_returning())
                     .get_result(conn)
             }
-            Err(_) => Self::create(conn, age, height, weight, sex, activity_level),
+            None => diesel::insert_into(body_data::table)
+                .values(new_version)
+                .returning(Self::as_returning())
+                .get_result(conn),
         }
     }
-
-    /// Create body data (allowed to be called only once upon the first setup)
-    fn create(
-        conn: &mut SqliteConnection,
-        age: &i32,
-        height: &f32,
-        weight: &f32,
-        sex: &str,
-        activity_level: &f32,
-    ) -> QueryResult<Self> {
-        let new_body_data = Self {
-            id: 1,
-            age: *age,
-            height: *height,
-            weight: *weight,
-            sex: sex.to_owned(),
-            activity_level: *activity_level,
-        };
-
-        diesel::insert_into(body_data::table)
-            .values(&new_body_data)
-            .returning(Self::as_returning())
-            .get_result(conn)
-    }
 }
 
 // ============================================================================
 // COMMANDS (Tauri)
 // ============================================================================
 
+/// Return the current body data
 #[command]
 pub fn get_body_data(pool: State<DbPool>) -> Result<BodyData, String> {
     pool.execute(BodyData::get)
 }
 
+/// Return the body data that applied on the given date
+#[command]
+pub fn get_body_data_as_of(pool: State<DbPool>, date_str: String) -> Result<BodyData, String> {
+    log::debug!(">>> get_body_data_as_of: date_str={:?}", date_str);
+
+    pool.execute(|conn| BodyData::as_of(conn, &date_str))
+}
+
+/// Return all body data versions, oldest first
+#[command]
+pub fn get_body_data_history(pool: State<DbPool>) -> Result<Vec<BodyData>, String> {
+    pool.execute(BodyData::history)
+}
+
+/// Record a body data change, effective today unless an effective date is given
 #[command]
 pub fn update_body_data(
     pool: State<DbPool>,
-    age: i32,
+    birth_date: String,
     height: f32,
     weight: f32,
     sex: String,
     activity_level: f32,
+    effective_date: Option<String>,
 ) -> Result<BodyData, String> {
     log::debug!(
-        ">>> update_body_data: age={:?} height={:?} weight={:?} sex={:?} activity_level={:?}",
-        age,
+        ">>> update_body_data: birth_date={:?} height={:?} weight={:?} sex={:?} activity_level={:?} effective_date={:?}",
+        birth_date,
         height,
         weight,
         sex,
-        activity_level
+        activity_level,
+        effective_date
     );
 
     let body_data = BodyData {
         id: 0,
-        age,
+        birth_date,
         height,
         weight,
         sex: sex.clone(),
         activity_level,
+        effective_date: effective_date.unwrap_or_else(today),
     };
 
     if let Err(validation_errors) = body_data.validate() {
         return Err(format!("Validation failed: {:?}", validation_errors));
     }
 
-    pool.execute(|conn| BodyData::update(conn, &age, &height, &weight, &sex, &activity_level))
+    let new_version = NewBodyData {
+        birth_date: body_data.birth_date,
+        height,
+        weight,
+        sex,
+        activity_level,
+        effective_date: body_data.effective_date,
+    };
+
+    pool.execute(|conn| BodyData::record(conn, &new_version))
 }
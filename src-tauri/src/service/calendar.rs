@@ -0,0 +1,249 @@
+//! Lightweight calendar queries — which days had workouts, intake or weigh-ins, and how
+//! often and how long the user trained per week — for views that don't need the full
+//! workout detail of `list_workouts` (`[HI-024]`, `[HI-025]`, `[HI-026]`).
+
+use crate::db::connection::DbPool;
+use crate::db::schema::{intake, weight_tracker, workout_session};
+use crate::service::report::ReportPeriod;
+use crate::service::workout::{local_day_start, WorkoutSession};
+use crate::util::error_handler::handle_error;
+use chrono::{Days, NaiveDate};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use tauri::{command, State};
+
+// ============================================================================
+// COMPOSITION MODEL
+// ============================================================================
+
+/// Completed workouts of an ISO week (Monday to Sunday)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WeeklyWorkouts {
+    /// The Monday of the week
+    pub week_start: String,
+    pub sessions: i32,
+    /// Active work time without pauses, rounded to whole minutes
+    pub active_minutes: i64,
+}
+
+/// What was tracked on a day
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivityDate {
+    pub date: String,
+    pub intake: bool,
+    pub weight: bool,
+    pub workout: bool,
+}
+
+// ============================================================================
+// COMPOSITION LOGIC
+// ============================================================================
+
+fn parse_range(date_from: &str, date_to: &str) -> Result<(NaiveDate, NaiveDate), String> {
+    let from = NaiveDate::parse_from_str(date_from, "%Y-%m-%d")
+        .map_err(|_| "Invalid date_from format".to_string())?;
+    let to = NaiveDate::parse_from_str(date_to, "%Y-%m-%d")
+        .map_err(|_| "Invalid date_to format".to_string())?;
+    if to < from {
+        return Err("The end date must not lie before the start date".to_string());
+    }
+    Ok((from, to))
+}
+
+/// Start timestamps of the completed workouts started from `from` to `to` (local days,
+/// both inclusive)
+fn workout_starts(
+    conn: &mut SqliteConnection,
+    from: &NaiveDate,
+    to: &NaiveDate,
+) -> Result<Vec<String>, String> {
+    let from_ts = local_day_start(from)?;
+    let to_ts = local_day_start(&to.succ_opt().ok_or("Failed to add day".to_string())?)?;
+    workout_session::table
+        .filter(workout_session::ended_at.is_not_null())
+        .filter(workout_session::deleted_at.is_null())
+        .filter(workout_session::started_at.ge(&from_ts))
+        .filter(workout_session::started_at.lt(&to_ts))
+        .order(workout_session::started_at.asc())
+        .select(workout_session::started_at)
+        .load(conn)
+        .map_err(handle_error)
+}
+
+/// The local calendar day a stored timestamp falls on
+fn local_date(timestamp: &str) -> Result<NaiveDate, String> {
+    chrono::DateTime::parse_from_rfc3339(timestamp)
+        .map(|ts| ts.with_timezone(&chrono::Local).date_naive())
+        .map_err(|e| format!("Invalid timestamp '{}': {}", timestamp, e))
+}
+
+/// Local days with a completed workout, oldest first
+pub fn workout_dates(
+    conn: &mut SqliteConnection,
+    date_from: &str,
+    date_to: &str,
+) -> Result<Vec<String>, String> {
+    let (from, to) = parse_range(date_from, date_to)?;
+    let mut dates = Vec::new();
+    for started_at in workout_starts(conn, &from, &to)? {
+        let date = local_date(&started_at)?.format("%Y-%m-%d").to_string();
+        if dates.last() != Some(&date) {
+            dates.push(date);
+        }
+    }
+    Ok(dates)
+}
+
+/// Sessions and active minutes per ISO week touching the range, empty weeks included.
+/// Only workouts started within the range count.
+pub fn weekly_workouts(
+    conn: &mut SqliteConnection,
+    date_from: &str,
+    date_to: &str,
+) -> Result<Vec<WeeklyWorkouts>, String> {
+    let (from, to) = parse_range(date_from, date_to)?;
+    let from_ts = local_day_start(&from)?;
+    let to_ts = local_day_start(&to.succ_opt().ok_or("Failed to add day".to_string())?)?;
+
+    // week start -> (sessions, active seconds)
+    let mut weeks: BTreeMap<NaiveDate, (i32, i64)> = BTreeMap::new();
+    let (mut monday, _) = ReportPeriod::Week.bounds(&from)?;
+    while monday <= to {
+        weeks.insert(monday, (0, 0));
+        monday = monday
+            .checked_add_days(Days::new(7))
+            .ok_or("Failed to add week".to_string())?;
+    }
+    for session in
+        WorkoutSession::completed_in_range(conn, &from_ts, &to_ts).map_err(handle_error)?
+    {
+        let (week_start, _) = ReportPeriod::Week.bounds(&local_date(&session.started_at)?)?;
+        let active_seconds = WorkoutSession::active_seconds(conn, &session)?.unwrap_or(0);
+        let week = weeks.entry(week_start).or_default();
+        week.0 += 1;
+        week.1 += active_seconds;
+    }
+
+    Ok(weeks
+        .into_iter()
+        .map(|(week_start, (sessions, active_seconds))| WeeklyWorkouts {
+            week_start: week_start.format("%Y-%m-%d").to_string(),
+            sessions,
+            active_minutes: (active_seconds as f64 / 60.0).round() as i64,
+        })
+        .collect())
+}
+
+fn activity_on(dates: &mut BTreeMap<String, ActivityDate>, date: String) -> &mut ActivityDate {
+    dates.entry(date.clone()).or_insert(ActivityDate {
+        date,
+        intake: false,
+        weight: false,
+        workout: false,
+    })
+}
+
+/// Days with intake, a weigh-in or a completed workout, oldest first
+pub fn activity_dates(
+    conn: &mut SqliteConnection,
+    date_from: &str,
+    date_to: &str,
+) -> Result<Vec<ActivityDate>, String> {
+    parse_range(date_from, date_to)?;
+
+    let mut dates: BTreeMap<String, ActivityDate> = BTreeMap::new();
+    for date in intake::table
+        .filter(intake::added.between(date_from, date_to))
+        .filter(intake::deleted_at.is_null())
+        .select(intake::added)
+        .distinct()
+        .load::<String>(conn)
+        .map_err(handle_error)?
+    {
+        activity_on(&mut dates, date).intake = true;
+    }
+    for date in weight_tracker::table
+        .filter(weight_tracker::added.between(date_from, date_to))
+        .filter(weight_tracker::deleted_at.is_null())
+        .select(weight_tracker::added)
+        .distinct()
+        .load::<String>(conn)
+        .map_err(handle_error)?
+    {
+        activity_on(&mut dates, date).weight = true;
+    }
+    for date in workout_dates(conn, date_from, date_to)? {
+        activity_on(&mut dates, date).workout = true;
+    }
+
+    Ok(dates.into_values().collect())
+}
+
+// ============================================================================
+// COMMANDS (Tauri)
+// ============================================================================
+
+/// Return the local days with a completed workout in the given range (`[HI-024]`)
+#[command]
+pub fn get_workout_dates_in_range(
+    pool: State<DbPool>,
+    date_from_str: String,
+    date_to_str: String,
+) -> Result<Vec<String>, String> {
+    log::debug!(
+        ">>> get_workout_dates_in_range date_from_str={:?} date_to_str={:?}",
+        date_from_str,
+        date_to_str
+    );
+
+    let mut conn = pool
+        .get()
+        .map_err(|e| format!("Failed to get connection: {}", e))?;
+
+    workout_dates(&mut conn, &date_from_str, &date_to_str)
+}
+
+/// Return the completed workouts and active minutes per week of the given range
+/// (`[HI-025]`)
+#[command]
+pub fn get_workout_frequency(
+    pool: State<DbPool>,
+    date_from_str: String,
+    date_to_str: String,
+) -> Result<Vec<WeeklyWorkouts>, String> {
+    log::debug!(
+        ">>> get_workout_frequency date_from_str={:?} date_to_str={:?}",
+        date_from_str,
+        date_to_str
+    );
+
+    let mut conn = pool
+        .get()
+        .map_err(|e| format!("Failed to get connection: {}", e))?;
+
+    weekly_workouts(&mut conn, &date_from_str, &date_to_str)
+}
+
+/// Return the days with intake, weigh-ins or completed workouts in the given range
+/// (`[HI-026]`)
+#[command]
+pub fn get_activity_dates_in_range(
+    pool: State<DbPool>,
+    date_from_str: String,
+    date_to_str: String,
+) -> Result<Vec<ActivityDate>, String> {
+    log::debug!(
+        ">>> get_activity_dates_in_range date_from_str={:?} date_to_str={:?}",
+        date_from_str,
+        date_to_str
+    );
+
+    let mut conn = pool
+        .get()
+        .map_err(|e| format!("Failed to get connection: {}", e))?;
+
+    activity_dates(&mut conn, &date_from_str, &date_to_str)
+}
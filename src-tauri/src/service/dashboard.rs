@@ -1,7 +1,11 @@
 use crate::db::connection::DbPool;
+use crate::service::adherence::Adherence;
+use crate::service::energy::WorkoutEnergy;
 use crate::service::intake::{FoodCategory, Intake, IntakeTarget};
+use crate::service::settings::AppSettings;
 use crate::service::user::LibreUser;
 use crate::service::weight::{WeightTarget, WeightTracker};
+use crate::util::error_handler::handle_error;
 use chrono::{NaiveDate, TimeDelta};
 use diesel::SqliteConnection;
 use serde::{Deserialize, Serialize};
@@ -26,6 +30,11 @@ pub struct Dashboard {
     pub current_day: i32,
     pub days_total: i32,
     pub weight_latest: WeightTracker,
+    pub adherence: Adherence,
+    /// Estimated calories burned by the day's completed workouts
+    pub calories_burned: i32,
+    /// The target calories, plus `calories_burned` if the user opted in
+    pub calorie_budget: i32,
 }
 
 // ============================================================================
@@ -94,6 +103,17 @@ impl Dashboard {
         let weight_latest =
             WeightTracker::get_latest(conn).map_err(|_| "No weight tracker found".to_string())?;
 
+        let adherence = Adherence::build_for_date(conn, date_str)?;
+
+        // Workouts may extend the day's budget if the user opted in
+        let calories_burned = WorkoutEnergy::burned_on(conn, &date)?;
+        let settings = AppSettings::get(conn).map_err(handle_error)?;
+        let calorie_budget = if settings.add_workout_calories {
+            intake_target.target_calories + calories_burned
+        } else {
+            intake_target.target_calories
+        };
+
         Ok(Self {
             user_data,
             intake_target,
@@ -106,6 +126,9 @@ impl Dashboard {
             current_day,
             days_total,
             weight_latest,
+            adherence,
+            calories_burned,
+            calorie_budget,
         })
     }
 }
@@ -0,0 +1,100 @@
+//! Calories burned by completed workouts, estimated as MET × body weight × active hours
+//! (`[WO-047]`). The MET comes from the workout type, the body weight from the latest
+//! weigh-in, and pauses don't count as active time.
+
+use crate::db::connection::DbPool;
+use crate::service::weight::WeightTracker;
+use crate::service::workout::{local_day_start, WorkoutSession, WorkoutType};
+use crate::util::error_handler::handle_error;
+use chrono::NaiveDate;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use tauri::{command, State};
+
+// ============================================================================
+// COMPOSITION MODEL
+// ============================================================================
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkoutEnergy {
+    pub session_id: i32,
+    pub workout_type: String,
+    pub active_seconds: i64,
+    pub met: f32,
+    pub body_weight_kg: f32,
+    pub kcal: i32,
+}
+
+// ============================================================================
+// COMPOSITION LOGIC
+// ============================================================================
+
+/// Calories burned in `active_seconds` of an activity of the given MET
+pub fn estimate_kcal(met: f32, body_weight_kg: f32, active_seconds: i64) -> i32 {
+    (met as f64 * body_weight_kg as f64 * active_seconds as f64 / 3600.0).round() as i32
+}
+
+impl WorkoutEnergy {
+    /// Estimate for a completed session; `None` while it is active or without any weigh-in.
+    pub fn for_session(
+        conn: &mut SqliteConnection,
+        session: &WorkoutSession,
+    ) -> Result<Option<Self>, String> {
+        let Some(active_seconds) = WorkoutSession::active_seconds(conn, session)? else {
+            return Ok(None);
+        };
+        let Some(weight) = WeightTracker::get_latest(conn)
+            .optional()
+            .map_err(handle_error)?
+        else {
+            return Ok(None);
+        };
+        let workout_type =
+            WorkoutType::find_by_key(conn, &session.workout_type).map_err(handle_error)?;
+
+        Ok(Some(WorkoutEnergy {
+            session_id: session.id,
+            workout_type: workout_type.shortvalue,
+            active_seconds,
+            met: workout_type.met,
+            body_weight_kg: weight.amount,
+            kcal: estimate_kcal(workout_type.met, weight.amount, active_seconds),
+        }))
+    }
+
+    /// Calories burned by the completed workouts started on a local calendar day
+    pub fn burned_on(conn: &mut SqliteConnection, date: &NaiveDate) -> Result<i32, String> {
+        let from = local_day_start(date)?;
+        let to = local_day_start(&date.succ_opt().ok_or("Failed to add day".to_string())?)?;
+
+        let mut kcal = 0;
+        for session in WorkoutSession::completed_in_range(conn, &from, &to).map_err(handle_error)? {
+            if let Some(energy) = Self::for_session(conn, &session)? {
+                kcal += energy.kcal;
+            }
+        }
+        Ok(kcal)
+    }
+}
+
+// ============================================================================
+// COMMANDS (Tauri)
+// ============================================================================
+
+/// Return the estimated calories burned by a workout, `None` while it is active or
+/// before the first weigh-in (`[WO-047]`)
+#[command]
+pub fn get_workout_energy(
+    pool: State<DbPool>,
+    session_id: i32,
+) -> Result<Option<WorkoutEnergy>, String> {
+    log::debug!(">>> get_workout_energy session_id={}", session_id);
+
+    let mut conn = pool
+        .get()
+        .map_err(|e| format!("Failed to get connection: {}", e))?;
+
+    let session = WorkoutSession::find(&mut conn, session_id).map_err(handle_error)?;
+    WorkoutEnergy::for_session(&mut conn, &session)
+}
@@ -0,0 +1,395 @@
+//! Custom exercises — user-defined additions to the seeded exercise library.
+//!
+//! Seeded exercises are read-only. Custom exercises can be edited, archived (hidden from
+//! the library and from logging, their history stays intact) and deleted as long as no
+//! workout or template refers to them (`[WO-043]`, `[WO-044]`).
+
+use crate::db::connection::DbPool;
+use crate::db::schema::{
+    exercise, exercise_category, exercise_muscle, muscle, workout_exercise, workout_session,
+    workout_template_exercise, workout_type,
+};
+use crate::db::DbExecutor;
+use crate::service::workout::{Exercise, ExerciseCategory, ExerciseDetail, ExerciseMuscle, Muscle};
+use crate::util::error_handler::handle_error;
+use diesel::prelude::*;
+use diesel::sql_types::Text;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use tauri::{command, State};
+use validator::{Validate, ValidationError};
+
+#[declare_sql_function]
+extern "SQL" {
+    /// SQLite's `lower`, to compare names ignoring case
+    fn lower(text: Text) -> Text;
+}
+
+// ============================================================================
+// MODELS
+// ============================================================================
+
+#[derive(Insertable, AsChangeset, Serialize, Deserialize, Debug)]
+#[diesel(table_name = exercise)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+#[diesel(treat_none_as_null = true)]
+#[serde(rename_all = "camelCase")]
+pub struct NewExercise {
+    pub name: String,
+    pub category: String,
+    pub default_rest_seconds: Option<i32>,
+    pub workout_type: String,
+    pub custom: bool,
+}
+
+// ============================================================================
+// INPUT (validated on create/update)
+// ============================================================================
+
+/// A muscle the exercise works, either as `primary` or `secondary` mover
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExerciseMuscleInput {
+    pub muscle: String,
+    pub role: String,
+}
+
+/// A custom exercise. Needs at least one primary muscle; each muscle is listed once.
+#[derive(Serialize, Deserialize, Validate, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+#[validate(schema(function = "validate_exercise_muscles"))]
+pub struct ExerciseInput {
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "Name must be between 1 and 100 characters"
+    ))]
+    pub name: String,
+    pub category: String,
+    #[validate(range(
+        min = 0,
+        max = 3600,
+        message = "Rest must be between 0 and 3600 seconds"
+    ))]
+    pub default_rest_seconds: Option<i32>,
+    pub workout_type: String,
+    pub muscles: Vec<ExerciseMuscleInput>,
+}
+
+fn validate_exercise_muscles(input: &ExerciseInput) -> Result<(), ValidationError> {
+    if input
+        .muscles
+        .iter()
+        .any(|m| m.role != "primary" && m.role != "secondary")
+    {
+        return Err(ValidationError::new(
+            "A muscle's role must be primary or secondary",
+        ));
+    }
+    if !input.muscles.iter().any(|m| m.role == "primary") {
+        return Err(ValidationError::new(
+            "An exercise needs at least one primary muscle",
+        ));
+    }
+    let mut seen = HashSet::new();
+    if !input.muscles.iter().all(|m| seen.insert(m.muscle.as_str())) {
+        return Err(ValidationError::new("Each muscle may only be listed once"));
+    }
+    Ok(())
+}
+
+// ============================================================================
+// REPOSITORY
+// ============================================================================
+
+impl Exercise {
+    /// Insert a custom exercise together with its muscles.
+    pub fn create_custom(conn: &mut SqliteConnection, input: &ExerciseInput) -> QueryResult<Self> {
+        conn.transaction(|conn| {
+            let created = diesel::insert_into(exercise::table)
+                .values(&NewExercise::from_input(input))
+                .returning(Self::as_returning())
+                .get_result(conn)?;
+            ExerciseMuscle::insert_all(conn, created.id, &input.muscles)?;
+            Ok(created)
+        })
+    }
+
+    /// Replace a custom exercise's fields and muscles.
+    pub fn update_custom(
+        conn: &mut SqliteConnection,
+        id: i32,
+        input: &ExerciseInput,
+    ) -> QueryResult<Self> {
+        conn.transaction(|conn| {
+            let updated = diesel::update(exercise::table.filter(exercise::id.eq(id)))
+                .set(&NewExercise::from_input(input))
+                .returning(Self::as_returning())
+                .get_result(conn)?;
+            diesel::delete(exercise_muscle::table.filter(exercise_muscle::exercise_id.eq(id)))
+                .execute(conn)?;
+            ExerciseMuscle::insert_all(conn, id, &input.muscles)?;
+            Ok(updated)
+        })
+    }
+
+    pub fn set_archived(conn: &mut SqliteConnection, id: i32, archived: bool) -> QueryResult<Self> {
+        diesel::update(exercise::table.filter(exercise::id.eq(id)))
+            .set(exercise::archived.eq(archived))
+            .returning(Self::as_returning())
+            .get_result(conn)
+    }
+
+    /// Delete an exercise and its muscles.
+    pub fn delete(conn: &mut SqliteConnection, id: i32) -> QueryResult<usize> {
+        conn.transaction(|conn| {
+            diesel::delete(exercise_muscle::table.filter(exercise_muscle::exercise_id.eq(id)))
+                .execute(conn)?;
+            diesel::delete(exercise::table.filter(exercise::id.eq(id))).execute(conn)
+        })
+    }
+
+    /// What refers to the exercise, if anything: workouts, including blocks planned from a
+    /// template without sets yet, templates, or workouts in the trash, which may still be
+    /// restored.
+    pub fn usage(conn: &mut SqliteConnection, id: i32) -> QueryResult<Option<&'static str>> {
+        // Whether each workout referring to it is live, deduplicated
+        let in_workouts: Vec<bool> = workout_exercise::table
+            .inner_join(workout_session::table)
+            .filter(workout_exercise::exercise_id.eq(id))
+            .select(workout_session::deleted_at.is_null())
+            .distinct()
+            .load(conn)?;
+        if in_workouts.contains(&true) {
+            return Ok(Some("workouts"));
+        }
+        let in_templates: i64 = workout_template_exercise::table
+            .filter(workout_template_exercise::exercise_id.eq(id))
+            .count()
+            .get_result(conn)?;
+        if in_templates > 0 {
+            return Ok(Some("templates"));
+        }
+        if !in_workouts.is_empty() {
+            return Ok(Some("workouts in the trash"));
+        }
+        Ok(None)
+    }
+
+    /// Another exercise of that name (ignoring case), if any
+    fn find_by_name(
+        conn: &mut SqliteConnection,
+        name: &str,
+        except_id: Option<i32>,
+    ) -> QueryResult<Option<Self>> {
+        exercise::table
+            .filter(exercise::id.ne(except_id.unwrap_or(0)))
+            .filter(lower(exercise::name).eq(lower(name.trim())))
+            .first(conn)
+            .optional()
+    }
+}
+
+impl ExerciseMuscle {
+    fn insert_all(
+        conn: &mut SqliteConnection,
+        exercise_id: i32,
+        muscles: &[ExerciseMuscleInput],
+    ) -> QueryResult<usize> {
+        let rows: Vec<ExerciseMuscle> = muscles
+            .iter()
+            .map(|m| ExerciseMuscle {
+                exercise_id,
+                muscle: m.muscle.clone(),
+                role: m.role.clone(),
+            })
+            .collect();
+        diesel::insert_into(exercise_muscle::table)
+            .values(&rows)
+            .execute(conn)
+    }
+}
+
+impl NewExercise {
+    fn from_input(input: &ExerciseInput) -> Self {
+        NewExercise {
+            name: input.name.trim().to_string(),
+            category: input.category.clone(),
+            default_rest_seconds: input.default_rest_seconds,
+            workout_type: input.workout_type.clone(),
+            custom: true,
+        }
+    }
+}
+
+impl ExerciseCategory {
+    pub fn all(conn: &mut SqliteConnection) -> QueryResult<Vec<Self>> {
+        exercise_category::table
+            .order(exercise_category::longvalue.asc())
+            .load(conn)
+    }
+}
+
+impl Muscle {
+    pub fn all(conn: &mut SqliteConnection) -> QueryResult<Vec<Self>> {
+        muscle::table.order(muscle::longvalue.asc()).load(conn)
+    }
+}
+
+// ============================================================================
+// COMMANDS (Tauri)
+// ============================================================================
+
+/// Validate the input and that its lookups exist and its name is not taken.
+fn validate_input(
+    conn: &mut SqliteConnection,
+    input: &ExerciseInput,
+    except_id: Option<i32>,
+) -> Result<(), String> {
+    if let Err(e) = input.validate() {
+        return Err(format!("Validation failed: {:?}", e));
+    }
+    let categories: i64 = exercise_category::table
+        .filter(exercise_category::shortvalue.eq(&input.category))
+        .count()
+        .get_result(conn)
+        .map_err(handle_error)?;
+    if categories == 0 {
+        return Err(format!("Unknown exercise category '{}'", input.category));
+    }
+    let workout_types: i64 = workout_type::table
+        .filter(workout_type::shortvalue.eq(&input.workout_type))
+        .count()
+        .get_result(conn)
+        .map_err(handle_error)?;
+    if workout_types == 0 {
+        return Err(format!("Unknown workout type '{}'", input.workout_type));
+    }
+    for m in &input.muscles {
+        let muscles: i64 = muscle::table
+            .filter(muscle::shortvalue.eq(&m.muscle))
+            .count()
+            .get_result(conn)
+            .map_err(handle_error)?;
+        if muscles == 0 {
+            return Err(format!("Unknown muscle '{}'", m.muscle));
+        }
+    }
+    if let Some(existing) =
+        Exercise::find_by_name(conn, &input.name, except_id).map_err(handle_error)?
+    {
+        return Err(format!(
+            "An exercise named {} already exists",
+            existing.name
+        ));
+    }
+    Ok(())
+}
+
+/// Load a custom exercise; seeded exercises are read-only.
+fn find_custom(conn: &mut SqliteConnection, id: i32) -> Result<Exercise, String> {
+    let found = Exercise::find(conn, id).map_err(handle_error)?;
+    if !found.custom {
+        return Err(format!(
+            "{} is part of the seeded library and cannot be changed",
+            found.name
+        ));
+    }
+    Ok(found)
+}
+
+/// Add a custom exercise with its muscles (`[WO-043]`).
+#[command]
+pub fn create_exercise(
+    pool: State<DbPool>,
+    input: ExerciseInput,
+) -> Result<ExerciseDetail, String> {
+    log::debug!(">>> create_exercise name={:?}", input.name);
+
+    let mut conn = pool
+        .get()
+        .map_err(|e| format!("Failed to get connection: {}", e))?;
+    validate_input(&mut conn, &input, None)?;
+    let created = Exercise::create_custom(&mut conn, &input).map_err(handle_error)?;
+    created.with_muscles(&mut conn).map_err(handle_error)
+}
+
+/// Edit a custom exercise and replace its muscles (`[WO-043]`). Its workout type is fixed
+/// once a workout or template refers to it.
+#[command]
+pub fn update_exercise(
+    pool: State<DbPool>,
+    exercise_id: i32,
+    input: ExerciseInput,
+) -> Result<ExerciseDetail, String> {
+    log::debug!(">>> update_exercise exercise_id={}", exercise_id);
+
+    let mut conn = pool
+        .get()
+        .map_err(|e| format!("Failed to get connection: {}", e))?;
+    let current = find_custom(&mut conn, exercise_id)?;
+    validate_input(&mut conn, &input, Some(exercise_id))?;
+    if current.workout_type != input.workout_type {
+        if let Some(usage) = Exercise::usage(&mut conn, exercise_id).map_err(handle_error)? {
+            return Err(format!(
+                "{} is used in {}; its workout type cannot change",
+                current.name, usage
+            ));
+        }
+    }
+    let updated = Exercise::update_custom(&mut conn, exercise_id, &input).map_err(handle_error)?;
+    updated.with_muscles(&mut conn).map_err(handle_error)
+}
+
+/// Archive or restore a custom exercise (`[WO-044]`).
+#[command]
+pub fn archive_exercise(
+    pool: State<DbPool>,
+    exercise_id: i32,
+    archived: bool,
+) -> Result<ExerciseDetail, String> {
+    log::debug!(
+        ">>> archive_exercise exercise_id={} archived={}",
+        exercise_id,
+        archived
+    );
+
+    let mut conn = pool
+        .get()
+        .map_err(|e| format!("Failed to get connection: {}", e))?;
+    find_custom(&mut conn, exercise_id)?;
+    let updated = Exercise::set_archived(&mut conn, exercise_id, archived).map_err(handle_error)?;
+    updated.with_muscles(&mut conn).map_err(handle_error)
+}
+
+/// Delete a custom exercise no workout or template refers to; archive it otherwise
+/// (`[WO-044]`).
+#[command]
+pub fn delete_exercise(pool: State<DbPool>, exercise_id: i32) -> Result<(), String> {
+    log::debug!(">>> delete_exercise exercise_id={}", exercise_id);
+
+    let mut conn = pool
+        .get()
+        .map_err(|e| format!("Failed to get connection: {}", e))?;
+    let found = find_custom(&mut conn, exercise_id)?;
+    if let Some(usage) = Exercise::usage(&mut conn, exercise_id).map_err(handle_error)? {
+        return Err(format!(
+            "{} is used in {}; archive it instead",
+            found.name, usage
+        ));
+    }
+    Exercise::delete(&mut conn, exercise_id).map_err(handle_error)?;
+    Ok(())
+}
+
+/// The seeded exercise categories
+#[command]
+pub fn get_exercise_categories(pool: State<DbPool>) -> Result<Vec<ExerciseCategory>, String> {
+    pool.execute(ExerciseCategory::all)
+}
+
+/// The seeded muscles
+#[command]
+pub fn get_muscles(pool: State<DbPool>) -> Result<Vec<Muscle>, String> {
+    pool.execute(Muscle::all)
+}
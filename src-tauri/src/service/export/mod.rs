@@ -1,5 +1,6 @@
 pub mod csv;
 pub mod raw;
+pub mod report;
 
 use serde::{Deserialize, Serialize};
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -7,6 +8,7 @@ use std::sync::Arc;
 use tauri::{command, ipc::Channel, State};
 
 use crate::db::connection::DbPool;
+use crate::service::report::{PeriodReport, ReportPeriod};
 
 // ============================================================================
 // CANCELLATION STATE
@@ -170,3 +172,25 @@ pub fn cancel_export(cancellation: State<'_, ExportCancellation>) {
     log::debug!(">>> Export cancellation requested");
     cancellation.cancel();
 }
+
+/// Export the weekly or monthly report for the period containing the given date as CSV
+#[command]
+pub fn export_period_report(
+    pool: State<'_, DbPool>,
+    period: ReportPeriod,
+    date_str: String,
+) -> Result<ExportResult, String> {
+    log::debug!(
+        ">>> export_period_report period={:?} date_str={:?}",
+        period,
+        date_str
+    );
+
+    let mut conn = pool
+        .get()
+        .map_err(|e| format!("Failed to get connection: {}", e))?;
+
+    let report = PeriodReport::build_for_date(&mut conn, period, &date_str)?;
+
+    report::export_report_csv(&report)
+}
@@ -0,0 +1,94 @@
+use crate::service::report::{PeriodReport, ReportPeriod};
+
+use super::ExportResult;
+
+/// Export a period report as a single CSV file: one row per metric with the current and
+/// previous period's value and the change.
+pub fn export_report_csv(report: &PeriodReport) -> Result<ExportResult, String> {
+    let mut wtr = csv::Writer::from_writer(vec![]);
+
+    wtr.write_record(["metric", "current", "previous", "change"])
+        .map_err(|e| format!("Failed to write CSV header: {}", e))?;
+
+    let current = &report.current;
+    let previous = &report.previous;
+    let change = &report.change;
+
+    let optional = |value: Option<f32>| value.map(|v| format!("{:.1}", v)).unwrap_or_default();
+
+    let rows: [[String; 4]; 9] = [
+        [
+            "date_from".into(),
+            current.date_from.clone(),
+            previous.date_from.clone(),
+            String::new(),
+        ],
+        [
+            "date_to".into(),
+            current.date_to.clone(),
+            previous.date_to.clone(),
+            String::new(),
+        ],
+        [
+            "days_logged".into(),
+            current.days_logged.to_string(),
+            previous.days_logged.to_string(),
+            (current.days_logged - previous.days_logged).to_string(),
+        ],
+        [
+            "calories_total".into(),
+            current.calories_total.to_string(),
+            previous.calories_total.to_string(),
+            change.calories_total.to_string(),
+        ],
+        [
+            "calories_average".into(),
+            format!("{:.1}", current.calories_average),
+            format!("{:.1}", previous.calories_average),
+            format!("{:.1}", change.calories_average),
+        ],
+        [
+            "days_over_maximum".into(),
+            current.days_over_maximum.to_string(),
+            previous.days_over_maximum.to_string(),
+            change.days_over_maximum.to_string(),
+        ],
+        [
+            "weight_change".into(),
+            optional(current.weight_change),
+            optional(previous.weight_change),
+            optional(change.weight_change),
+        ],
+        [
+            "workouts_completed".into(),
+            current.workouts_completed.to_string(),
+            previous.workouts_completed.to_string(),
+            change.workouts_completed.to_string(),
+        ],
+        [
+            "lifting_volume".into(),
+            format!("{:.1}", current.lifting_volume),
+            format!("{:.1}", previous.lifting_volume),
+            format!("{:.1}", change.lifting_volume),
+        ],
+    ];
+
+    for row in rows {
+        wtr.write_record(&row)
+            .map_err(|e| format!("Failed to write CSV record: {}", e))?;
+    }
+
+    let bytes = wtr
+        .into_inner()
+        .map_err(|e| format!("Failed to finalize CSV: {}", e))?;
+
+    let period = match report.period {
+        ReportPeriod::Week => "week",
+        ReportPeriod::Month => "month",
+    };
+
+    Ok(ExportResult {
+        bytes,
+        file_path: format!("librefit_report_{}_{}.csv", period, current.date_from),
+    })
+}
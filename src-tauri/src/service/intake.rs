@@ -1,6 +1,9 @@
 use crate::db::connection::DbPool;
 use crate::db::schema::{food_category, intake, intake_target};
 use crate::db::DbExecutor;
+use crate::service::adherence::Milestone;
+use crate::service::journal::{JournalEntry, Operation, RemovedRows};
+use crate::service::trash::now_ts;
 use chrono::NaiveDate;
 use diesel::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -45,6 +48,8 @@ pub struct Intake {
     pub description: Option<String>,
     #[validate(custom(function = "validate_time_format"))]
     pub time: String,
+    /// When the entry was moved to the trash (`[HI-029]`)
+    pub deleted_at: Option<String>,
 }
 
 /// For creation of a new [Intake] entry.
@@ -230,12 +235,14 @@ impl Intake {
             .get_result(conn)
     }
 
-    /// Retrieve all intake entries
+    /// Retrieve all intake entries outside the trash
     pub fn all(conn: &mut SqliteConnection) -> QueryResult<Vec<Self>> {
-        intake::table.load::<Self>(conn)
+        intake::table
+            .filter(intake::deleted_at.is_null())
+            .load::<Self>(conn)
     }
 
-    /// Update an intake entry by ID
+    /// Update an intake entry by ID, unless it is in the trash
     pub fn update(
         conn: &mut SqliteConnection,
         tracker_id: i32,
@@ -250,21 +257,32 @@ impl Intake {
             time: updated_entry.time.clone().or_else(default_time),
         };
 
-        diesel::update(intake::table.filter(intake::id.eq(tracker_id)))
-            .set(&entry_with_time)
-            .returning(Self::as_returning())
-            .get_result(conn)
+        diesel::update(
+            intake::table
+                .filter(intake::id.eq(tracker_id))
+                .filter(intake::deleted_at.is_null()),
+        )
+        .set(&entry_with_time)
+        .returning(Self::as_returning())
+        .get_result(conn)
     }
 
-    /// Delete an intake entry by ID
+    /// Move an intake entry to the trash by ID
     pub fn delete(conn: &mut SqliteConnection, tracker_id: &i32) -> QueryResult<usize> {
-        diesel::delete(intake::table.filter(intake::id.eq(tracker_id))).execute(conn)
+        diesel::update(
+            intake::table
+                .filter(intake::id.eq(tracker_id))
+                .filter(intake::deleted_at.is_null()),
+        )
+        .set(intake::deleted_at.eq(now_ts()))
+        .execute(conn)
     }
 
     /// Find intake entries by date
     pub fn find_by_date(conn: &mut SqliteConnection, date: &String) -> QueryResult<Vec<Self>> {
         intake::table
             .filter(intake::added.eq(date))
+            .filter(intake::deleted_at.is_null())
             .load::<Self>(conn)
     }
 
@@ -276,6 +294,7 @@ impl Intake {
     ) -> QueryResult<Vec<Self>> {
         intake::table
             .filter(intake::added.between(date_from, date_to))
+            .filter(intake::deleted_at.is_null())
             .order(intake::added.desc())
             .load::<Self>(conn)
     }
@@ -353,7 +372,13 @@ pub fn create_intake(pool: State<DbPool>, new_entry: NewIntake) -> Result<Intake
 
     log::debug!("Creating new intake tracker entry: {:?}", new_entry);
 
-    pool.execute(|conn| Intake::create(conn, &new_entry))
+    pool.execute(|conn| {
+        conn.transaction(|conn| {
+            let created = Intake::create(conn, &new_entry)?;
+            Milestone::refresh(conn)?;
+            Ok(created)
+        })
+    })
 }
 
 /// Update intake by ID and return it
@@ -373,13 +398,29 @@ pub fn update_intake(
         updated_entry
     );
 
-    pool.execute(|conn| Intake::update(conn, tracker_id, &updated_entry))
+    pool.execute(|conn| {
+        conn.transaction(|conn| {
+            let updated = Intake::update(conn, tracker_id, &updated_entry)?;
+            Milestone::refresh(conn)?;
+            Ok(updated)
+        })
+    })
 }
 
-/// Delete intake by ID and return the deleted row count
+/// Move intake to the trash by ID and return the deleted row count
 #[command]
 pub fn delete_intake(pool: State<DbPool>, tracker_id: i32) -> Result<usize, String> {
-    pool.execute(|conn| Intake::delete(conn, &tracker_id))
+    pool.execute(|conn| {
+        conn.transaction(|conn| {
+            let deleted = Intake::delete(conn, &tracker_id)?;
+            if deleted > 0 {
+                let rows = RemovedRows::Intake { id: tracker_id };
+                JournalEntry::record(conn, Operation::DeleteIntake, &rows)?;
+                Milestone::refresh(conn)?;
+            }
+            Ok(deleted)
+        })
+    })
 }
 
 #[command]
@@ -0,0 +1,438 @@
+//! Journal of destructive operations, so the latest one can be undone and an undone one
+//! redone (`[HI-027]`, `[HI-032]`).
+//! Deleted entries and workouts go to the trash and are referred to by id. A deleted set
+//! is removed for good, so its row is kept as JSON: sets aren't listed in the trash, and
+//! marking them deleted instead would leave every query over a workout's sets (volume,
+//! records, progression) to filter them out. Undone entries are kept until the next
+//! operation is recorded. Entries expire after [JOURNAL_RETENTION_DAYS], whatever the
+//! trash retention: an entry whose trashed rows were purged earlier is skipped like any
+//! other that can't be undone (`[HI-028]`).
+
+use crate::db::connection::DbPool;
+use crate::db::schema::{operation_journal, workout_session};
+use crate::service::trash::{self, now_ts, TrashKind};
+use crate::service::workout::{refresh_records, WorkoutExercise, WorkoutSession, WorkoutSet};
+use crate::util::error_handler::handle_error;
+use chrono::{Days, SecondsFormat, Utc};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use tauri::{command, State};
+
+/// Days after which an operation can no longer be undone (`[HI-028]`)
+pub const JOURNAL_RETENTION_DAYS: u64 = 30;
+
+// ============================================================================
+// MODELS
+// ============================================================================
+
+/// A destructive operation with the rows it removed as JSON ([RemovedRows]).
+#[derive(Queryable, Selectable, Serialize, Deserialize, Debug, Clone)]
+#[diesel(table_name = operation_journal)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+#[serde(rename_all = "camelCase")]
+pub struct JournalEntry {
+    pub id: i32,
+    pub operation: String,
+    pub payload: String,
+    pub performed_at: String,
+    pub undone_at: Option<String>,
+}
+
+/// For recording a new [JournalEntry].
+#[derive(Insertable, Serialize, Deserialize, Debug)]
+#[diesel(table_name = operation_journal)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+#[serde(rename_all = "camelCase")]
+pub struct NewJournalEntry {
+    pub operation: String,
+    pub payload: String,
+    pub performed_at: String,
+}
+
+// ============================================================================
+// COMPOSITION MODEL
+// ============================================================================
+
+/// The destructive operations that can be undone
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum Operation {
+    DeleteIntake,
+    DeleteWeight,
+    DeleteWorkout,
+    DeleteWorkoutSet,
+    DiscardWorkout,
+}
+
+impl Operation {
+    pub fn key(&self) -> &'static str {
+        match self {
+            Operation::DeleteIntake => "delete_intake",
+            Operation::DeleteWeight => "delete_weight",
+            Operation::DeleteWorkout => "delete_workout",
+            Operation::DeleteWorkoutSet => "delete_workout_set",
+            Operation::DiscardWorkout => "discard_workout",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "delete_intake" => Some(Operation::DeleteIntake),
+            "delete_weight" => Some(Operation::DeleteWeight),
+            "delete_workout" => Some(Operation::DeleteWorkout),
+            "delete_workout_set" => Some(Operation::DeleteWorkoutSet),
+            "discard_workout" => Some(Operation::DiscardWorkout),
+            _ => None,
+        }
+    }
+}
+
+/// What an operation removed: trashed rows by id, a deleted set in full
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum RemovedRows {
+    Intake { id: i32 },
+    Weight { id: i32 },
+    Workout { session_id: i32 },
+    WorkoutSet { set: WorkoutSet },
+}
+
+/// An operation that can be undone, without its rows
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct JournalOperation {
+    pub id: i32,
+    pub operation: Operation,
+    pub performed_at: String,
+}
+
+// ============================================================================
+// REPOSITORY
+// ============================================================================
+
+/// Entries performed before this have expired
+fn retention_cutoff() -> String {
+    Utc::now()
+        .checked_sub_days(Days::new(JOURNAL_RETENTION_DAYS))
+        .unwrap_or_default()
+        .to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+fn to_payload(rows: &RemovedRows) -> QueryResult<String> {
+    serde_json::to_string(rows).map_err(|e| diesel::result::Error::SerializationError(Box::new(e)))
+}
+
+impl JournalEntry {
+    /// Record the rows an operation is about to remove, drop the undone entries, which
+    /// can't be redone over it, and purge expired entries. Call within the transaction
+    /// that removes them.
+    pub fn record(
+        conn: &mut SqliteConnection,
+        operation: Operation,
+        rows: &RemovedRows,
+    ) -> QueryResult<()> {
+        diesel::insert_into(operation_journal::table)
+            .values(&NewJournalEntry {
+                operation: operation.key().to_string(),
+                payload: to_payload(rows)?,
+                performed_at: now_ts(),
+            })
+            .execute(conn)?;
+        diesel::delete(operation_journal::table.filter(operation_journal::undone_at.is_not_null()))
+            .execute(conn)?;
+        Self::purge_expired(conn)?;
+        Ok(())
+    }
+
+    /// The unexpired entries that haven't been undone, most recent first
+    pub fn unexpired(conn: &mut SqliteConnection) -> QueryResult<Vec<Self>> {
+        let cutoff = retention_cutoff();
+        operation_journal::table
+            .filter(operation_journal::performed_at.ge(cutoff))
+            .filter(operation_journal::undone_at.is_null())
+            .order(operation_journal::id.desc())
+            .select(Self::as_select())
+            .load(conn)
+    }
+
+    /// The unexpired undone entries, the last undone first
+    pub fn undone(conn: &mut SqliteConnection) -> QueryResult<Vec<Self>> {
+        let cutoff = retention_cutoff();
+        operation_journal::table
+            .filter(operation_journal::performed_at.ge(cutoff))
+            .filter(operation_journal::undone_at.is_not_null())
+            .order(operation_journal::id.asc())
+            .select(Self::as_select())
+            .load(conn)
+    }
+
+    /// Delete the entries older than [JOURNAL_RETENTION_DAYS]
+    pub fn purge_expired(conn: &mut SqliteConnection) -> QueryResult<usize> {
+        let cutoff = retention_cutoff();
+        diesel::delete(operation_journal::table.filter(operation_journal::performed_at.lt(cutoff)))
+            .execute(conn)
+    }
+
+    /// Mark an entry undone with the rows it would remove again, and delete the entries
+    /// after it that could no longer be undone
+    fn mark_undone(conn: &mut SqliteConnection, id: i32, rows: &RemovedRows) -> QueryResult<()> {
+        diesel::delete(
+            operation_journal::table
+                .filter(operation_journal::id.gt(id))
+                .filter(operation_journal::undone_at.is_null()),
+        )
+        .execute(conn)?;
+        diesel::update(operation_journal::table.find(id))
+            .set((
+                operation_journal::payload.eq(to_payload(rows)?),
+                operation_journal::undone_at.eq(now_ts()),
+            ))
+            .execute(conn)?;
+        Ok(())
+    }
+
+    /// Mark an undone entry performed again, with the rows it removed this time
+    fn mark_redone(conn: &mut SqliteConnection, id: i32, rows: &RemovedRows) -> QueryResult<()> {
+        diesel::update(operation_journal::table.find(id))
+            .set((
+                operation_journal::payload.eq(to_payload(rows)?),
+                operation_journal::undone_at.eq(None::<String>),
+            ))
+            .execute(conn)?;
+        Ok(())
+    }
+
+    fn operation(&self) -> Result<JournalOperation, String> {
+        Ok(JournalOperation {
+            id: self.id,
+            operation: Operation::from_key(&self.operation)
+                .ok_or(format!("Unknown operation '{}'", self.operation))?,
+            performed_at: self.performed_at.clone(),
+        })
+    }
+
+    fn rows(&self) -> QueryResult<RemovedRows> {
+        serde_json::from_str(&self.payload)
+            .map_err(|e| diesel::result::Error::DeserializationError(Box::new(e)))
+    }
+}
+
+// ============================================================================
+// UNDO AND REDO
+// ============================================================================
+
+impl RemovedRows {
+    /// The trashed row the operation removed, if it was trashed
+    fn trashed(&self) -> Option<(TrashKind, i32)> {
+        match self {
+            RemovedRows::Intake { id } => Some((TrashKind::Intake, *id)),
+            RemovedRows::Weight { id } => Some((TrashKind::Weight, *id)),
+            RemovedRows::Workout { session_id } => Some((TrashKind::Workout, *session_id)),
+            RemovedRows::WorkoutSet { .. } => None,
+        }
+    }
+
+    /// Whether the rows can still be put back: trashed rows may have been restored or
+    /// purged since, and a deleted set needs its workout
+    fn restorable(&self, conn: &mut SqliteConnection) -> QueryResult<bool> {
+        if let RemovedRows::WorkoutSet { set } = self {
+            return Ok(WorkoutExercise::find(conn, set.workout_exercise_id)
+                .optional()?
+                .is_some());
+        }
+        match self.trashed() {
+            Some((kind, id)) => trash::is_trashed(conn, kind, id),
+            None => Ok(false),
+        }
+    }
+
+    /// Whether the rows can be removed again: restored rows may have been deleted since
+    fn redoable(&self, conn: &mut SqliteConnection) -> QueryResult<bool> {
+        if let RemovedRows::WorkoutSet { set } = self {
+            return Ok(WorkoutSet::find(conn, set.id).optional()?.is_some());
+        }
+        match self.trashed() {
+            Some((kind, id)) => trash::is_live(conn, kind, id),
+            None => Ok(false),
+        }
+    }
+}
+
+/// The most recent entry that can still be undone, skipping those that can't
+fn last_restorable(
+    conn: &mut SqliteConnection,
+) -> QueryResult<Option<(JournalEntry, RemovedRows)>> {
+    for entry in JournalEntry::unexpired(conn)? {
+        let rows = entry.rows()?;
+        if rows.restorable(conn)? {
+            return Ok(Some((entry, rows)));
+        }
+    }
+    Ok(None)
+}
+
+/// The last undone entry that can still be redone, skipping those that can't
+fn last_redoable(conn: &mut SqliteConnection) -> QueryResult<Option<(JournalEntry, RemovedRows)>> {
+    for entry in JournalEntry::undone(conn)? {
+        let rows = entry.rows()?;
+        if rows.redoable(conn)? {
+            return Ok(Some((entry, rows)));
+        }
+    }
+    Ok(None)
+}
+
+/// Why an undo or redo was rolled back: a refused restore or a failed query
+struct UndoFailed(String);
+
+impl From<String> for UndoFailed {
+    fn from(message: String) -> Self {
+        UndoFailed(message)
+    }
+}
+
+impl From<diesel::result::Error> for UndoFailed {
+    fn from(e: diesel::result::Error) -> Self {
+        UndoFailed(handle_error(e))
+    }
+}
+
+/// Recompute the records of a block's exercise, whose workout may be in the trash itself
+fn refresh_block_records(
+    conn: &mut SqliteConnection,
+    block: &WorkoutExercise,
+) -> Result<(), String> {
+    let session = workout_session::table
+        .find(block.session_id)
+        .select(WorkoutSession::as_select())
+        .first(conn)
+        .map_err(handle_error)?;
+    refresh_records(conn, &session, &[block.exercise_id])
+}
+
+/// Put a deleted set back into its block. Returns the set as logged again, under a new id.
+fn restore_set(conn: &mut SqliteConnection, set: &WorkoutSet) -> Result<WorkoutSet, String> {
+    let block = WorkoutExercise::find(conn, set.workout_exercise_id).map_err(handle_error)?;
+    let mut restored = WorkoutSet::log(
+        conn,
+        block.id,
+        set.metrics.clone(),
+        set.payload_ver,
+        set.logged_at.clone(),
+    )
+    .map_err(handle_error)?;
+    if set.rest_seconds.is_some() {
+        restored =
+            WorkoutSet::update_rest(conn, restored.id, set.rest_seconds).map_err(handle_error)?;
+    }
+    refresh_block_records(conn, &block)?;
+    Ok(restored)
+}
+
+/// Delete a restored set again. Returns it as it was deleted.
+fn delete_set(conn: &mut SqliteConnection, set: &WorkoutSet) -> Result<WorkoutSet, String> {
+    let current = WorkoutSet::find(conn, set.id).map_err(handle_error)?;
+    let block = WorkoutExercise::find(conn, current.workout_exercise_id).map_err(handle_error)?;
+    WorkoutSet::delete(conn, current.id).map_err(handle_error)?;
+    refresh_block_records(conn, &block)?;
+    Ok(current)
+}
+
+// ============================================================================
+// COMMANDS (Tauri)
+// ============================================================================
+
+/// Return the operation `undo_last_operation` would undo, if any (`[HI-027]`)
+#[command]
+pub fn get_last_operation(pool: State<DbPool>) -> Result<Option<JournalOperation>, String> {
+    log::debug!(">>> get_last_operation");
+
+    let mut conn = pool
+        .get()
+        .map_err(|e| format!("Failed to get connection: {}", e))?;
+
+    match last_restorable(&mut conn).map_err(handle_error)? {
+        Some((entry, _)) => entry.operation().map(Some),
+        None => Ok(None),
+    }
+}
+
+/// Restore what the most recent destructive operation removed and mark it undone, as one
+/// transaction. Returns the undone operation, or `None` if there is nothing to undo
+/// (`[HI-027]`).
+#[command]
+pub fn undo_last_operation(pool: State<DbPool>) -> Result<Option<JournalOperation>, String> {
+    log::debug!(">>> undo_last_operation");
+
+    let mut conn = pool
+        .get()
+        .map_err(|e| format!("Failed to get connection: {}", e))?;
+
+    conn.transaction(|conn| {
+        let Some((entry, rows)) = last_restorable(conn)? else {
+            return Ok(None);
+        };
+        let operation = entry.operation()?;
+        let rows = match rows {
+            RemovedRows::WorkoutSet { set } => RemovedRows::WorkoutSet {
+                set: restore_set(conn, &set)?,
+            },
+            rows => {
+                if let Some((kind, id)) = rows.trashed() {
+                    trash::restore(conn, kind, id)?;
+                }
+                rows
+            }
+        };
+        JournalEntry::mark_undone(conn, entry.id, &rows)?;
+        Ok(Some(operation))
+    })
+    .map_err(|UndoFailed(message)| message)
+}
+
+/// Return the operation `redo_last_operation` would redo, if any (`[HI-032]`)
+#[command]
+pub fn get_last_undone_operation(pool: State<DbPool>) -> Result<Option<JournalOperation>, String> {
+    log::debug!(">>> get_last_undone_operation");
+
+    let mut conn = pool
+        .get()
+        .map_err(|e| format!("Failed to get connection: {}", e))?;
+
+    match last_redoable(&mut conn).map_err(handle_error)? {
+        Some((entry, _)) => entry.operation().map(Some),
+        None => Ok(None),
+    }
+}
+
+/// Perform the last undone operation again, as one transaction. Returns the redone
+/// operation, or `None` if there is nothing to redo (`[HI-032]`).
+#[command]
+pub fn redo_last_operation(pool: State<DbPool>) -> Result<Option<JournalOperation>, String> {
+    log::debug!(">>> redo_last_operation");
+
+    let mut conn = pool
+        .get()
+        .map_err(|e| format!("Failed to get connection: {}", e))?;
+
+    conn.transaction(|conn| {
+        let Some((entry, rows)) = last_redoable(conn)? else {
+            return Ok(None);
+        };
+        let operation = entry.operation()?;
+        let rows = match rows {
+            RemovedRows::WorkoutSet { set } => RemovedRows::WorkoutSet {
+                set: delete_set(conn, &set)?,
+            },
+            rows => {
+                if let Some((kind, id)) = rows.trashed() {
+                    trash::move_to_trash(conn, kind, id)?;
+                }
+                rows
+            }
+        };
+        JournalEntry::mark_redone(conn, entry.id, &rows)?;
+        Ok(Some(operation))
+    })
+    .map_err(|UndoFailed(message)| message)
+}
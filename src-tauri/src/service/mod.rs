@@ -1,13 +1,27 @@
 // Service layer - Domain models with repository methods + Tauri commands
 // This layer combines business logic with data access
+pub mod adherence;
 pub mod body;
+pub mod calendar;
 pub mod dashboard;
+pub mod energy;
+pub mod exercise;
 pub mod export;
 pub mod import;
 pub mod intake;
+pub mod journal;
+pub mod muscle_volume;
+pub mod personal_record;
 pub mod progress;
+pub mod progression;
+pub mod report;
+pub mod settings;
+pub mod strength;
 pub mod tracker_history;
+pub mod trash;
 pub mod user;
 pub mod weight;
 pub mod wizard;
 pub mod workout;
+pub mod workout_summary;
+pub mod workout_template;
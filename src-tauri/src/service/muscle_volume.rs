@@ -0,0 +1,317 @@
+//! Training volume per muscle — hard sets and tonnage of completed weight-lifting workouts
+//! spread over the muscles each exercise works, compared against weekly set targets
+//! (`[PG-023]`, `[PG-024]`).
+
+use crate::db::connection::DbPool;
+use crate::db::schema::{
+    exercise_muscle, muscle, muscle_volume_target, workout_exercise, workout_session, workout_set,
+};
+use crate::service::workout::{
+    local_day_start, ExerciseMuscle, LiftingSetMetrics, Muscle, SetKind, WorkoutSet,
+};
+use crate::util::error_handler::handle_error;
+use crate::util::math_f32::floor_f32;
+use chrono::NaiveDate;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tauri::{command, State};
+use validator::{Validate, ValidationError};
+
+/// Share of a set credited to a muscle the exercise works as secondary mover
+pub const SECONDARY_MUSCLE_FACTOR: f64 = 0.5;
+
+// ============================================================================
+// MODELS
+// ============================================================================
+
+/// Weekly hard-set range of a muscle
+#[derive(Queryable, Selectable, Insertable, AsChangeset, Serialize, Deserialize, Debug, Clone)]
+#[diesel(table_name = muscle_volume_target)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+#[serde(rename_all = "camelCase")]
+pub struct MuscleVolumeTarget {
+    pub muscle: String,
+    pub min_weekly_sets: i32,
+    pub max_weekly_sets: i32,
+}
+
+// ============================================================================
+// INPUT (validated on update)
+// ============================================================================
+
+#[derive(Serialize, Deserialize, Validate, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+#[validate(schema(function = "validate_volume_target"))]
+pub struct MuscleVolumeTargetInput {
+    #[validate(range(min = 0, max = 50, message = "Sets must be between 0 and 50"))]
+    pub min_weekly_sets: i32,
+    #[validate(range(min = 0, max = 50, message = "Sets must be between 0 and 50"))]
+    pub max_weekly_sets: i32,
+}
+
+fn validate_volume_target(input: &MuscleVolumeTargetInput) -> Result<(), ValidationError> {
+    if input.min_weekly_sets > input.max_weekly_sets {
+        return Err(ValidationError::new(
+            "Minimum sets must not exceed maximum sets",
+        ));
+    }
+    Ok(())
+}
+
+// ============================================================================
+// COMPOSITION MODEL
+// ============================================================================
+
+/// Weekly hard sets of a muscle relative to its target range
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum VolumeStatus {
+    Below,
+    Within,
+    Above,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MuscleVolume {
+    pub muscle: String,
+    pub name: String,
+    /// Working sets, secondary muscles credited by [SECONDARY_MUSCLE_FACTOR]
+    pub hard_sets: f32,
+    /// Working volume (reps × kg), weighted like `hard_sets`
+    pub tonnage: f64,
+    /// `hard_sets` per week of the range
+    pub weekly_sets: f32,
+    pub min_weekly_sets: i32,
+    pub max_weekly_sets: i32,
+    pub status: VolumeStatus,
+}
+
+/// Volume of every muscle from `date_from` to `date_to` (both inclusive)
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MuscleVolumeReport {
+    pub date_from: String,
+    pub date_to: String,
+    pub weeks: f32,
+    pub muscles: Vec<MuscleVolume>,
+}
+
+// ============================================================================
+// REPOSITORY
+// ============================================================================
+
+impl MuscleVolumeTarget {
+    pub fn all(conn: &mut SqliteConnection) -> QueryResult<Vec<Self>> {
+        muscle_volume_target::table
+            .order(muscle_volume_target::muscle.asc())
+            .load(conn)
+    }
+
+    /// Set a muscle's range, creating it if the muscle has none yet.
+    pub fn upsert(conn: &mut SqliteConnection, target: &MuscleVolumeTarget) -> QueryResult<Self> {
+        diesel::insert_into(muscle_volume_target::table)
+            .values(target)
+            .on_conflict(muscle_volume_target::muscle)
+            .do_update()
+            .set(target)
+            .returning(Self::as_returning())
+            .get_result(conn)
+    }
+}
+
+// ============================================================================
+// COMPOSITION LOGIC
+// ============================================================================
+
+impl VolumeStatus {
+    fn of(weekly_sets: f32, target: &MuscleVolumeTarget) -> Self {
+        if weekly_sets < target.min_weekly_sets as f32 {
+            VolumeStatus::Below
+        } else if weekly_sets > target.max_weekly_sets as f32 {
+            VolumeStatus::Above
+        } else {
+            VolumeStatus::Within
+        }
+    }
+}
+
+impl MuscleVolumeReport {
+    /// Credit every working set of the completed weight-lifting workouts started in the
+    /// range to the muscles of its exercise: fully to primary, by
+    /// [SECONDARY_MUSCLE_FACTOR] to secondary muscles. Warm-ups don't count.
+    pub fn build_for_range(
+        conn: &mut SqliteConnection,
+        date_from: &str,
+        date_to: &str,
+    ) -> Result<Self, String> {
+        let from = NaiveDate::parse_from_str(date_from, "%Y-%m-%d")
+            .map_err(|_| "Invalid date format".to_string())?;
+        let to = NaiveDate::parse_from_str(date_to, "%Y-%m-%d")
+            .map_err(|_| "Invalid date format".to_string())?;
+        if to < from {
+            return Err("The end date must not lie before the start date".to_string());
+        }
+        let from_ts = local_day_start(&from)?;
+        let to_ts = local_day_start(&to.succ_opt().ok_or("Failed to add day".to_string())?)?;
+
+        let sets: Vec<(i32, WorkoutSet)> = workout_set::table
+            .inner_join(workout_exercise::table.inner_join(workout_session::table))
+            .filter(workout_session::workout_type.eq("wl"))
+            .filter(workout_session::ended_at.is_not_null())
+            .filter(workout_session::deleted_at.is_null())
+            .filter(workout_session::started_at.ge(&from_ts))
+            .filter(workout_session::started_at.lt(&to_ts))
+            .select((workout_exercise::exercise_id, WorkoutSet::as_select()))
+            .load(conn)
+            .map_err(handle_error)?;
+
+        let exercise_ids: Vec<i32> = sets.iter().map(|(exercise_id, _)| *exercise_id).collect();
+        let mut muscles_by_exercise: HashMap<i32, Vec<ExerciseMuscle>> = HashMap::new();
+        for mapping in exercise_muscle::table
+            .filter(exercise_muscle::exercise_id.eq_any(&exercise_ids))
+            .load::<ExerciseMuscle>(conn)
+            .map_err(handle_error)?
+        {
+            muscles_by_exercise
+                .entry(mapping.exercise_id)
+                .or_default()
+                .push(mapping);
+        }
+
+        // muscle -> (hard sets, tonnage)
+        let mut totals: HashMap<String, (f64, f64)> = HashMap::new();
+        for (exercise_id, set) in sets {
+            let metrics = LiftingSetMetrics::from_stored(set.payload_ver, &set.metrics)?;
+            if metrics.kind == SetKind::Warmup {
+                continue;
+            }
+            for mapping in muscles_by_exercise.get(&exercise_id).into_iter().flatten() {
+                let factor = if mapping.role == "primary" {
+                    1.0
+                } else {
+                    SECONDARY_MUSCLE_FACTOR
+                };
+                let total = totals.entry(mapping.muscle.clone()).or_insert((0.0, 0.0));
+                total.0 += factor;
+                total.1 += factor * metrics.volume();
+            }
+        }
+
+        let weeks = ((to - from).num_days() + 1) as f64 / 7.0;
+        let targets: HashMap<String, MuscleVolumeTarget> = MuscleVolumeTarget::all(conn)
+            .map_err(handle_error)?
+            .into_iter()
+            .map(|target| (target.muscle.clone(), target))
+            .collect();
+        let all_muscles = muscle::table
+            .order(muscle::longvalue.asc())
+            .load::<Muscle>(conn)
+            .map_err(handle_error)?;
+
+        let muscles = all_muscles
+            .into_iter()
+            .map(|m| {
+                let (hard_sets, tonnage) = totals.get(&m.shortvalue).copied().unwrap_or_default();
+                let weekly_sets = floor_f32((hard_sets / weeks) as f32, 1);
+                let target = targets
+                    .get(&m.shortvalue)
+                    .cloned()
+                    .unwrap_or(MuscleVolumeTarget {
+                        muscle: m.shortvalue.clone(),
+                        min_weekly_sets: 0,
+                        max_weekly_sets: 0,
+                    });
+                MuscleVolume {
+                    status: VolumeStatus::of(weekly_sets, &target),
+                    muscle: m.shortvalue,
+                    name: m.longvalue,
+                    hard_sets: floor_f32(hard_sets as f32, 1),
+                    tonnage,
+                    weekly_sets,
+                    min_weekly_sets: target.min_weekly_sets,
+                    max_weekly_sets: target.max_weekly_sets,
+                }
+            })
+            .collect();
+
+        Ok(MuscleVolumeReport {
+            date_from: date_from.to_string(),
+            date_to: date_to.to_string(),
+            weeks: floor_f32(weeks as f32, 1),
+            muscles,
+        })
+    }
+}
+
+// ============================================================================
+// COMMANDS (Tauri)
+// ============================================================================
+
+/// Return hard sets and tonnage per muscle in the date range, compared against the weekly
+/// targets (`[PG-023]`)
+#[command]
+pub fn get_muscle_volume(
+    pool: State<DbPool>,
+    date_from: String,
+    date_to: String,
+) -> Result<MuscleVolumeReport, String> {
+    log::debug!(
+        ">>> get_muscle_volume date_from={:?} date_to={:?}",
+        date_from,
+        date_to
+    );
+
+    let mut conn = pool
+        .get()
+        .map_err(|e| format!("Failed to get connection: {}", e))?;
+
+    MuscleVolumeReport::build_for_range(&mut conn, &date_from, &date_to)
+}
+
+/// Return the weekly set targets of all muscles
+#[command]
+pub fn get_muscle_volume_targets(pool: State<DbPool>) -> Result<Vec<MuscleVolumeTarget>, String> {
+    let mut conn = pool
+        .get()
+        .map_err(|e| format!("Failed to get connection: {}", e))?;
+
+    MuscleVolumeTarget::all(&mut conn).map_err(handle_error)
+}
+
+/// Set the weekly set target of a muscle (`[PG-024]`)
+#[command]
+pub fn update_muscle_volume_target(
+    pool: State<DbPool>,
+    muscle: String,
+    input: MuscleVolumeTargetInput,
+) -> Result<MuscleVolumeTarget, String> {
+    log::debug!(">>> update_muscle_volume_target muscle={:?}", muscle);
+
+    if let Err(e) = input.validate() {
+        return Err(format!("Validation failed: {:?}", e));
+    }
+
+    let mut conn = pool
+        .get()
+        .map_err(|e| format!("Failed to get connection: {}", e))?;
+
+    let known: i64 = muscle::table
+        .filter(muscle::shortvalue.eq(&muscle))
+        .count()
+        .get_result(&mut conn)
+        .map_err(handle_error)?;
+    if known == 0 {
+        return Err(format!("Unknown muscle '{}'", muscle));
+    }
+
+    MuscleVolumeTarget::upsert(
+        &mut conn,
+        &MuscleVolumeTarget {
+            muscle,
+            min_weekly_sets: input.min_weekly_sets,
+            max_weekly_sets: input.max_weekly_sets,
+        },
+    )
+    .map_err(handle_error)
+}
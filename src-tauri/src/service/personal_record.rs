@@ -0,0 +1,391 @@
+//! Personal records (PRs) per weight-lifting exercise, derived from the set history.
+//!
+//! The history of an exercise is replayed in logging order; a set that beats every
+//! earlier set in a record kind holds a PR of that kind. The resulting PR events are
+//! cached in `personal_record` and rebuilt for an exercise whenever one of its sets is
+//! logged, edited, or deleted (`[WO-038]`, `[WO-039]`).
+
+use crate::db::connection::DbPool;
+use crate::db::schema::{personal_record, workout_exercise, workout_session, workout_set};
+use crate::service::workout::{LiftingSetMetrics, SetKind, WorkoutSet};
+use crate::util::error_handler::handle_error;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tauri::{command, State};
+
+/// Sets with more reps than this don't yield an estimated 1RM; both formulas lose
+/// accuracy quickly beyond it.
+pub const ESTIMATE_MAX_REPS: i32 = 12;
+
+// ============================================================================
+// MODELS
+// ============================================================================
+
+/// A set that held a record of `kind` when it was logged, with the record's value.
+#[derive(Queryable, Selectable, Serialize, Deserialize, Debug, Clone)]
+#[diesel(table_name = personal_record)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+#[serde(rename_all = "camelCase")]
+pub struct PersonalRecord {
+    pub id: i32,
+    pub exercise_id: i32,
+    pub set_id: i32,
+    pub kind: String,
+    pub value: f64,
+}
+
+/// For caching a new [PersonalRecord].
+#[derive(Insertable, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[diesel(table_name = personal_record)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+#[serde(rename_all = "camelCase")]
+pub struct NewPersonalRecord {
+    pub exercise_id: i32,
+    pub set_id: i32,
+    pub kind: String,
+    pub value: f64,
+}
+
+// ============================================================================
+// COMPOSITION MODEL
+// ============================================================================
+
+/// Kinds of personal record tracked per exercise
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum RecordKind {
+    /// Heaviest load lifted for at least one rep
+    HeaviestWeight,
+    /// Best estimated one-rep max by Epley: `load × (1 + reps / 30)`
+    EstimatedOneRmEpley,
+    /// Best estimated one-rep max by Brzycki: `load × 36 / (37 − reps)`
+    EstimatedOneRmBrzycki,
+    /// Highest reps × load in a single set
+    BestVolumeSet,
+    /// More reps than ever before at the same load
+    BestRepsAtWeight,
+}
+
+/// A set of an exercise's history, as replayed by [scan_records]
+#[derive(Debug, Clone)]
+pub struct RecordSet {
+    pub set_id: i32,
+    pub logged_at: String,
+    pub metrics: LiftingSetMetrics,
+}
+
+/// The current best of a record kind and the set that holds it
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordValue {
+    pub value: f64,
+    pub set_id: i32,
+    pub achieved_at: String,
+}
+
+/// Most reps done at a load
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RepsAtWeight {
+    pub weight_kg: f64,
+    pub reps: i32,
+    pub set_id: i32,
+    pub achieved_at: String,
+}
+
+/// Current personal records of an exercise
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ExerciseRecords {
+    pub exercise_id: i32,
+    pub heaviest_weight: Option<RecordValue>,
+    pub estimated_one_rm_epley: Option<RecordValue>,
+    pub estimated_one_rm_brzycki: Option<RecordValue>,
+    pub best_volume_set: Option<RecordValue>,
+    /// Lightest load first
+    pub best_reps_by_weight: Vec<RepsAtWeight>,
+}
+
+// ============================================================================
+// COMPOSITION LOGIC
+// ============================================================================
+
+impl RecordKind {
+    pub const ALL: [RecordKind; 5] = [
+        RecordKind::HeaviestWeight,
+        RecordKind::EstimatedOneRmEpley,
+        RecordKind::EstimatedOneRmBrzycki,
+        RecordKind::BestVolumeSet,
+        RecordKind::BestRepsAtWeight,
+    ];
+
+    /// Key stored in `personal_record.kind`
+    pub fn key(&self) -> &'static str {
+        match self {
+            RecordKind::HeaviestWeight => "heaviest_weight",
+            RecordKind::EstimatedOneRmEpley => "e1rm_epley",
+            RecordKind::EstimatedOneRmBrzycki => "e1rm_brzycki",
+            RecordKind::BestVolumeSet => "best_volume_set",
+            RecordKind::BestRepsAtWeight => "best_reps_at_weight",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.key() == key)
+    }
+}
+
+/// Estimated one-rep max by Epley; a single rep is the max itself.
+pub fn epley_one_rm(load_kg: f64, reps: i32) -> Option<f64> {
+    match reps {
+        1 => Some(load_kg),
+        2..=ESTIMATE_MAX_REPS => Some(load_kg * (1.0 + reps as f64 / 30.0)),
+        _ => None,
+    }
+}
+
+/// Estimated one-rep max by Brzycki; a single rep is the max itself.
+pub fn brzycki_one_rm(load_kg: f64, reps: i32) -> Option<f64> {
+    match reps {
+        1..=ESTIMATE_MAX_REPS => Some(load_kg * 36.0 / (37.0 - reps as f64)),
+        _ => None,
+    }
+}
+
+/// Load a set counts with: the external load, or the added (negative: assisting) load of
+/// a bodyweight set.
+pub fn record_load(metrics: &LiftingSetMetrics) -> f64 {
+    if metrics.bodyweight {
+        metrics.added_load_kg.unwrap_or(0.0)
+    } else {
+        metrics.weight_kg
+    }
+}
+
+/// Loads are compared in grams so float noise can't split one load into two.
+fn load_key(load_kg: f64) -> i64 {
+    (load_kg * 1000.0).round() as i64
+}
+
+/// Replay an exercise's history (in logging order) and return the PR events — each set
+/// that beat all earlier sets in a kind — together with the current records.
+///
+/// Warm-up sets and sets without reps (holds) are ignored. Weight, 1RM and volume records
+/// need a positive load; reps at a weight count for any load, including bodyweight and
+/// assisted sets, but only once the load was done before.
+pub fn scan_records(
+    exercise_id: i32,
+    history: &[RecordSet],
+) -> (Vec<NewPersonalRecord>, ExerciseRecords) {
+    let mut events = Vec::new();
+    let mut records = ExerciseRecords {
+        exercise_id,
+        ..Default::default()
+    };
+    let mut reps_by_load: HashMap<i64, RepsAtWeight> = HashMap::new();
+
+    for set in history {
+        let metrics = &set.metrics;
+        let reps = match metrics.reps {
+            Some(reps) if metrics.kind != SetKind::Warmup => reps,
+            _ => continue,
+        };
+        let load = record_load(metrics);
+
+        let mut beat = |kind: RecordKind, best: &mut Option<RecordValue>, value: Option<f64>| {
+            let Some(value) = value else { return };
+            if best.as_ref().is_none_or(|best| value > best.value) {
+                *best = Some(RecordValue {
+                    value,
+                    set_id: set.set_id,
+                    achieved_at: set.logged_at.clone(),
+                });
+                events.push(NewPersonalRecord {
+                    exercise_id,
+                    set_id: set.set_id,
+                    kind: kind.key().to_string(),
+                    value,
+                });
+            }
+        };
+
+        if load > 0.0 {
+            beat(
+                RecordKind::HeaviestWeight,
+                &mut records.heaviest_weight,
+                Some(load),
+            );
+            beat(
+                RecordKind::EstimatedOneRmEpley,
+                &mut records.estimated_one_rm_epley,
+                epley_one_rm(load, reps),
+            );
+            beat(
+                RecordKind::EstimatedOneRmBrzycki,
+                &mut records.estimated_one_rm_brzycki,
+                brzycki_one_rm(load, reps),
+            );
+            beat(
+                RecordKind::BestVolumeSet,
+                &mut records.best_volume_set,
+                Some(reps as f64 * load),
+            );
+        }
+
+        match reps_by_load.get_mut(&load_key(load)) {
+            Some(best) if reps > best.reps => {
+                *best = RepsAtWeight {
+                    weight_kg: load,
+                    reps,
+                    set_id: set.set_id,
+                    achieved_at: set.logged_at.clone(),
+                };
+                events.push(NewPersonalRecord {
+                    exercise_id,
+                    set_id: set.set_id,
+                    kind: RecordKind::BestRepsAtWeight.key().to_string(),
+                    value: reps as f64,
+                });
+            }
+            Some(_) => {}
+            None => {
+                reps_by_load.insert(
+                    load_key(load),
+                    RepsAtWeight {
+                        weight_kg: load,
+                        reps,
+                        set_id: set.set_id,
+                        achieved_at: set.logged_at.clone(),
+                    },
+                );
+            }
+        }
+    }
+
+    let mut best_reps_by_weight: Vec<RepsAtWeight> = reps_by_load.into_values().collect();
+    best_reps_by_weight.sort_by(|a, b| a.weight_kg.total_cmp(&b.weight_kg));
+    records.best_reps_by_weight = best_reps_by_weight;
+
+    (events, records)
+}
+
+// ============================================================================
+// REPOSITORY
+// ============================================================================
+
+impl PersonalRecord {
+    /// Weight-lifting sets of an exercise across all sessions, in logging order
+    pub fn history(
+        conn: &mut SqliteConnection,
+        exercise_id: i32,
+    ) -> Result<Vec<RecordSet>, String> {
+        let sets = workout_set::table
+            .inner_join(workout_exercise::table.inner_join(workout_session::table))
+            .filter(workout_exercise::exercise_id.eq(exercise_id))
+            .filter(workout_session::workout_type.eq("wl"))
+            .filter(workout_session::deleted_at.is_null())
+            .order((workout_set::logged_at.asc(), workout_set::id.asc()))
+            .select(WorkoutSet::as_select())
+            .load::<WorkoutSet>(conn)
+            .map_err(handle_error)?;
+
+        sets.into_iter()
+            .map(|set| {
+                Ok(RecordSet {
+                    set_id: set.id,
+                    metrics: LiftingSetMetrics::from_stored(set.payload_ver, &set.metrics)?,
+                    logged_at: set.logged_at,
+                })
+            })
+            .collect()
+    }
+
+    /// Rebuild the cached PR events of an exercise from its history
+    pub fn refresh(conn: &mut SqliteConnection, exercise_id: i32) -> Result<(), String> {
+        let history = Self::history(conn, exercise_id)?;
+        let (events, _) = scan_records(exercise_id, &history);
+
+        conn.transaction(|conn| {
+            diesel::delete(
+                personal_record::table.filter(personal_record::exercise_id.eq(exercise_id)),
+            )
+            .execute(conn)?;
+            diesel::insert_into(personal_record::table)
+                .values(&events)
+                .execute(conn)
+        })
+        .map_err(handle_error)?;
+        Ok(())
+    }
+
+    /// Rebuild the cache for every exercise with weight-lifting sets, e.g. after the
+    /// cache table was (re)created over an existing history.
+    pub fn refresh_all(conn: &mut SqliteConnection) -> Result<(), String> {
+        let exercise_ids: Vec<i32> = workout_exercise::table
+            .inner_join(workout_session::table)
+            .filter(workout_session::workout_type.eq("wl"))
+            .select(workout_exercise::exercise_id)
+            .distinct()
+            .load(conn)
+            .map_err(handle_error)?;
+
+        for exercise_id in exercise_ids {
+            Self::refresh(conn, exercise_id)?;
+        }
+        Ok(())
+    }
+
+    /// Fill the cache if it is empty while weight-lifting sets exist
+    pub fn backfill(conn: &mut SqliteConnection) -> Result<(), String> {
+        let cached: i64 = personal_record::table
+            .count()
+            .get_result(conn)
+            .map_err(handle_error)?;
+        if cached == 0 {
+            Self::refresh_all(conn)?;
+        }
+        Ok(())
+    }
+
+    /// Record kinds each of the given sets holds a PR in
+    pub fn kinds_by_set(
+        conn: &mut SqliteConnection,
+        set_ids: &[i32],
+    ) -> QueryResult<HashMap<i32, Vec<RecordKind>>> {
+        let rows: Vec<(i32, String)> = personal_record::table
+            .filter(personal_record::set_id.eq_any(set_ids))
+            .order(personal_record::id.asc())
+            .select((personal_record::set_id, personal_record::kind))
+            .load(conn)?;
+
+        let mut kinds: HashMap<i32, Vec<RecordKind>> = HashMap::new();
+        for (set_id, key) in rows {
+            if let Some(kind) = RecordKind::from_key(&key) {
+                kinds.entry(set_id).or_default().push(kind);
+            }
+        }
+        Ok(kinds)
+    }
+}
+
+// ============================================================================
+// COMMANDS (Tauri)
+// ============================================================================
+
+/// Return the current personal records of an exercise (`[WO-040]`)
+#[command]
+pub fn get_personal_records(
+    pool: State<DbPool>,
+    exercise_id: i32,
+) -> Result<ExerciseRecords, String> {
+    log::debug!(">>> get_personal_records exercise_id={:?}", exercise_id);
+
+    let mut conn = pool
+        .get()
+        .map_err(|e| format!("Failed to get connection: {}", e))?;
+
+    let history = PersonalRecord::history(&mut conn, exercise_id)?;
+    let (_, records) = scan_records(exercise_id, &history);
+    Ok(records)
+}
@@ -1,9 +1,15 @@
 use crate::db::connection::DbPool;
+use crate::db::schema::{workout_exercise, workout_session, workout_set};
 use crate::service::intake::{FoodCategory, Intake, IntakeTarget};
+use crate::service::personal_record::{epley_one_rm, record_load};
 use crate::service::weight::{WeightTarget, WeightTracker};
+use crate::service::workout::{
+    local_day_start, LiftingSetMetrics, SetKind, WorkoutSession, WorkoutSet,
+};
+use crate::util::error_handler::handle_error;
 use crate::util::math_f32;
 use chrono::{Days, NaiveDate};
-use diesel::SqliteConnection;
+use diesel::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 use tauri::{command, State};
@@ -45,6 +51,48 @@ pub struct Progress {
     pub weight_chart_data: WeightChartData,
 }
 
+/// Lifting progress of one exercise with one point per completed workout, in the shape of
+/// the weight chart (`[PG-021]`)
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ExerciseProgress {
+    pub exercise_id: i32,
+    /// Heaviest working set in kg (added load for bodyweight sets)
+    pub best_set: WeightChartData,
+    /// Best estimated one-rep max (Epley) in kg
+    pub estimated_one_rm: WeightChartData,
+    /// Working volume (reps × kg)
+    pub volume: WeightChartData,
+    /// Working reps
+    pub reps: WeightChartData,
+}
+
+/// Where the observed weight trend puts the user relative to the weight target's end date
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum ForecastStatus {
+    Ahead,
+    OnTrack,
+    Behind,
+    Reached,
+}
+
+/// Projection of the current weight target from the user's real weigh-ins
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct WeightForecast {
+    pub weight_target: WeightTarget,
+    /// Weight on the forecast date according to the fitted trend line
+    pub trend_weight: f32,
+    /// Observed change in kg per day (negative while losing)
+    pub daily_change: f32,
+    /// Date the trend reaches `target_weight`; `None` if it doesn't move towards it
+    pub projected_date: Option<String>,
+    pub status: ForecastStatus,
+    pub days_remaining: i32,
+    /// Daily kcal change needed to reach the target by its end date (negative: eat less)
+    pub required_daily_adjustment: i32,
+}
+
 // ============================================================================
 // COMPOSITION LOGIC
 // ============================================================================
@@ -115,10 +163,140 @@ impl Progress {
     }
 }
 
+/// Energy content of one kg of body weight, matching the wizard's rate calculations
+const KCAL_PER_KG: f32 = 7000.0;
+
+/// A projection within this many days of the end date counts as on track
+const ON_TRACK_TOLERANCE_DAYS: i64 = 7;
+
+impl WeightForecast {
+    /// Fit the weigh-ins since the last weight target's start date and project when the
+    /// target weight will be reached.
+    pub fn build_for_date(conn: &mut SqliteConnection, date_str: &str) -> Result<Self, String> {
+        let weight_target =
+            WeightTarget::find_last(conn).map_err(|_| "No weight target found".to_string())?;
+
+        let start_date = NaiveDate::parse_from_str(&weight_target.start_date, "%Y-%m-%d")
+            .map_err(|_| "Invalid date format".to_string())?;
+        let end_date = NaiveDate::parse_from_str(&weight_target.end_date, "%Y-%m-%d")
+            .map_err(|_| "Invalid date format".to_string())?;
+        let date = NaiveDate::parse_from_str(date_str, "%Y-%m-%d")
+            .map_err(|_| "Invalid date format".to_string())?;
+
+        let weight_tracker = WeightTracker::find_by_date_range(
+            conn,
+            &weight_target.start_date,
+            &date_str.to_string(),
+        )
+        .map_err(|e| format!("Failed to load weight tracker: {}", e))?;
+
+        let (intercept, slope) = fit_weight_trend(&weight_tracker, &start_date)
+            .ok_or("At least two days with weigh-ins are needed for a forecast".to_string())?;
+
+        let days_elapsed = date.signed_duration_since(start_date).num_days() as f32;
+        let trend_weight = intercept + slope * days_elapsed;
+        let days_remaining = end_date.signed_duration_since(date).num_days().max(0) as i32;
+
+        let losing = weight_target.target_weight < weight_target.initial_weight;
+        let remaining = weight_target.target_weight - trend_weight;
+
+        let reached = if losing {
+            remaining >= 0.0
+        } else {
+            remaining <= 0.0
+        };
+
+        let projected_date = if reached {
+            Some(date)
+        } else if slope != 0.0 && remaining.signum() == slope.signum() {
+            date.checked_add_days(Days::new((remaining / slope).ceil() as u64))
+        } else {
+            None
+        };
+
+        let status = match projected_date {
+            _ if reached => ForecastStatus::Reached,
+            None => ForecastStatus::Behind,
+            Some(projected) => {
+                let offset = projected.signed_duration_since(end_date).num_days();
+                if offset > ON_TRACK_TOLERANCE_DAYS {
+                    ForecastStatus::Behind
+                } else if offset < -ON_TRACK_TOLERANCE_DAYS {
+                    ForecastStatus::Ahead
+                } else {
+                    ForecastStatus::OnTrack
+                }
+            }
+        };
+
+        let required_daily_adjustment = if reached || days_remaining == 0 {
+            0
+        } else {
+            let required_slope = remaining / days_remaining as f32;
+            math_f32::floor_f32((required_slope - slope) * KCAL_PER_KG, 0) as i32
+        };
+
+        Ok(WeightForecast {
+            weight_target,
+            trend_weight: math_f32::floor_f32(trend_weight, 1),
+            daily_change: math_f32::floor_f32(slope, 3),
+            projected_date: projected_date.map(|d| d.format("%Y-%m-%d").to_string()),
+            status,
+            days_remaining,
+            required_daily_adjustment,
+        })
+    }
+}
+
 // ============================================================================
 // HELPER FUNCTIONS
 // ============================================================================
 
+/// Least squares fit of the daily average weight over days since `start_date`.
+/// Returns `(intercept, slope)`, or `None` with fewer than two distinct days.
+pub(crate) fn fit_weight_trend(
+    weight_tracker: &[WeightTracker],
+    start_date: &NaiveDate,
+) -> Option<(f32, f32)> {
+    let mut daily: BTreeMap<&str, (f32, u32)> = BTreeMap::new();
+
+    for weight in weight_tracker {
+        let entry = daily.entry(weight.added.as_str()).or_insert((0.0, 0));
+        entry.0 += weight.amount;
+        entry.1 += 1;
+    }
+
+    let points: Vec<(f32, f32)> = daily
+        .into_iter()
+        .filter_map(|(date, (sum, count))| {
+            NaiveDate::parse_from_str(date, "%Y-%m-%d").ok().map(|d| {
+                (
+                    d.signed_duration_since(*start_date).num_days() as f32,
+                    sum / count as f32,
+                )
+            })
+        })
+        .collect();
+
+    if points.len() < 2 {
+        return None;
+    }
+
+    let n = points.len() as f32;
+    let mean_x = points.iter().map(|(x, _)| x).sum::<f32>() / n;
+    let mean_y = points.iter().map(|(_, y)| y).sum::<f32>() / n;
+
+    let covariance: f32 = points
+        .iter()
+        .map(|(x, y)| (x - mean_x) * (y - mean_y))
+        .sum();
+    let variance: f32 = points.iter().map(|(x, _)| (x - mean_x).powi(2)).sum();
+
+    let slope = covariance / variance;
+
+    Some((mean_y - slope * mean_x, slope))
+}
+
 /// Process intake tracker to prepare client side graph rendering
 fn process_intake(
     conn: &mut SqliteConnection,
@@ -250,6 +428,128 @@ fn process_weight(weight_tracker: &[WeightTracker]) -> WeightChartData {
     }
 }
 
+/// Aggregated working sets of one workout
+struct WorkoutPoint {
+    session_id: i32,
+    legend: String,
+    best_set: f64,
+    estimated_one_rm: f64,
+    volume: f64,
+    reps: i32,
+}
+
+impl ExerciseProgress {
+    /// Aggregate the working sets of an exercise per completed workout started from
+    /// `date_from` to `date_to` (both inclusive, local days). Warm-ups and sets without reps
+    /// are left out, as are workouts with nothing else.
+    pub fn build_for_range(
+        conn: &mut SqliteConnection,
+        exercise_id: i32,
+        date_from: &str,
+        date_to: &str,
+    ) -> Result<Self, String> {
+        let from = NaiveDate::parse_from_str(date_from, "%Y-%m-%d")
+            .map_err(|_| "Invalid date format".to_string())?;
+        let to = NaiveDate::parse_from_str(date_to, "%Y-%m-%d")
+            .map_err(|_| "Invalid date format".to_string())?;
+        let from_ts = local_day_start(&from)?;
+        let to_ts = local_day_start(&to.succ_opt().ok_or("Failed to add day".to_string())?)?;
+
+        let rows: Vec<(WorkoutSession, WorkoutSet)> = workout_set::table
+            .inner_join(workout_exercise::table.inner_join(workout_session::table))
+            .filter(workout_exercise::exercise_id.eq(exercise_id))
+            .filter(workout_session::workout_type.eq("wl"))
+            .filter(workout_session::ended_at.is_not_null())
+            .filter(workout_session::deleted_at.is_null())
+            .filter(workout_session::started_at.ge(&from_ts))
+            .filter(workout_session::started_at.lt(&to_ts))
+            .order((
+                workout_session::started_at.asc(),
+                workout_session::id.asc(),
+                workout_set::id.asc(),
+            ))
+            .select((WorkoutSession::as_select(), WorkoutSet::as_select()))
+            .load(conn)
+            .map_err(handle_error)?;
+
+        let mut points: Vec<WorkoutPoint> = Vec::new();
+        for (session, set) in rows {
+            let metrics = LiftingSetMetrics::from_stored(set.payload_ver, &set.metrics)?;
+            let reps = match metrics.reps {
+                Some(reps) if metrics.kind != SetKind::Warmup => reps,
+                _ => continue,
+            };
+            let load = record_load(&metrics);
+            let estimated_one_rm = if load > 0.0 {
+                epley_one_rm(load, reps).unwrap_or(0.0)
+            } else {
+                0.0
+            };
+
+            match points.last_mut() {
+                Some(point) if point.session_id == session.id => {
+                    point.best_set = f64::max(point.best_set, load);
+                    point.estimated_one_rm = f64::max(point.estimated_one_rm, estimated_one_rm);
+                    point.volume += metrics.volume();
+                    point.reps += reps;
+                }
+                _ => points.push(WorkoutPoint {
+                    session_id: session.id,
+                    legend: chrono::DateTime::parse_from_rfc3339(&session.started_at)
+                        .map_err(|e| format!("Invalid timestamp: {}", e))?
+                        .with_timezone(&chrono::Local)
+                        .format("%d.%m")
+                        .to_string(),
+                    best_set: load,
+                    estimated_one_rm,
+                    volume: metrics.volume(),
+                    reps,
+                }),
+            }
+        }
+
+        let legend: Vec<String> = points.iter().map(|point| point.legend.clone()).collect();
+        let series = |value: fn(&WorkoutPoint) -> f64| {
+            series_chart_data(&legend, points.iter().map(value).collect())
+        };
+
+        Ok(ExerciseProgress {
+            exercise_id,
+            best_set: series(|point| point.best_set),
+            estimated_one_rm: series(|point| point.estimated_one_rm),
+            volume: series(|point| point.volume),
+            reps: series(|point| point.reps as f64),
+        })
+    }
+}
+
+/// Chart data of one value per legend entry, in the shape of the weight chart
+fn series_chart_data(legend: &[String], values: Vec<f64>) -> WeightChartData {
+    if values.is_empty() {
+        return WeightChartData {
+            avg: 0.,
+            min: 0.,
+            max: 0.,
+            legend: vec![],
+            values: vec![],
+        };
+    }
+
+    let values: Vec<f32> = values
+        .into_iter()
+        .map(|value| math_f32::floor_f32(value as f32, 1))
+        .collect();
+    let sum: f32 = values.iter().sum();
+
+    WeightChartData {
+        avg: math_f32::floor_f32(sum / values.len() as f32, 0),
+        min: values.iter().copied().fold(f32::MAX, f32::min),
+        max: values.iter().copied().fold(f32::MIN, f32::max),
+        legend: legend.to_vec(),
+        values,
+    }
+}
+
 // ============================================================================
 // COMMANDS (Tauri)
 // ============================================================================
@@ -264,3 +564,41 @@ pub fn get_tracker_progress(pool: State<DbPool>, date_str: String) -> Result<Pro
 
     Progress::build_for_date(&mut conn, &date_str)
 }
+
+/// Project when the current weight target is reached based on the observed weight trend
+#[command]
+pub fn get_weight_forecast(
+    pool: State<DbPool>,
+    date_str: String,
+) -> Result<WeightForecast, String> {
+    log::debug!(">>> get_weight_forecast date_str={:?}", date_str);
+
+    let mut conn = pool
+        .get()
+        .map_err(|e| format!("Failed to get connection: {}", e))?;
+
+    WeightForecast::build_for_date(&mut conn, &date_str)
+}
+
+/// Return an exercise's best set, estimated 1RM, volume and reps per completed workout
+/// in the date range (both inclusive)
+#[command]
+pub fn get_exercise_progress(
+    pool: State<DbPool>,
+    exercise_id: i32,
+    date_from: String,
+    date_to: String,
+) -> Result<ExerciseProgress, String> {
+    log::debug!(
+        ">>> get_exercise_progress exercise_id={:?} date_from={:?} date_to={:?}",
+        exercise_id,
+        date_from,
+        date_to
+    );
+
+    let mut conn = pool
+        .get()
+        .map_err(|e| format!("Failed to get connection: {}", e))?;
+
+    ExerciseProgress::build_for_range(&mut conn, exercise_id, &date_from, &date_to)
+}
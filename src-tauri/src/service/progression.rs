@@ -0,0 +1,154 @@
+//! Cross-session prefill ("ghosting"): the sets an exercise was done with in the previous
+//! completed workout, and the target suggested for the next one (`[WO-041]`, `[WO-042]`).
+
+use crate::db::connection::DbPool;
+use crate::service::workout::{
+    ExercisePlan, LiftingSetMetrics, SetKind, WorkoutExerciseView, WorkoutSession,
+};
+use crate::util::error_handler::handle_error;
+use diesel::SqliteConnection;
+use serde::{Deserialize, Serialize};
+use tauri::{command, State};
+
+/// Load added once every planned rep was hit last time
+pub const PROGRESSION_INCREMENT_KG: f64 = 2.5;
+
+// ============================================================================
+// COMPOSITION MODEL
+// ============================================================================
+
+/// Target for the next workout of an exercise
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SetSuggestion {
+    pub weight_kg: f64,
+    pub reps: i32,
+    pub sets: i32,
+    /// Whether the load went up because every rep was hit last time
+    pub increased: bool,
+}
+
+/// How an exercise went in the most recent completed workout that contained it
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PreviousPerformance {
+    pub session: WorkoutSession,
+    pub exercise: WorkoutExerciseView,
+    /// `None` unless the exercise was done with working sets of reps at an external load
+    pub suggestion: Option<SetSuggestion>,
+}
+
+// ============================================================================
+// COMPOSITION LOGIC
+// ============================================================================
+
+/// Suggest the next target from the working sets at the top load of the previous workout.
+///
+/// The reps to hit are the plan's upper rep bound, or without a plan the reps of the first
+/// set at the top load; the sets to do are the planned sets, or as many as were done at the
+/// top load. When every set was done with at least those reps, the load goes up by
+/// [PROGRESSION_INCREMENT_KG] (restarting at the lower rep bound of a plan); otherwise the
+/// same target is repeated.
+pub fn suggest_next(
+    sets: &[LiftingSetMetrics],
+    plan: Option<&ExercisePlan>,
+) -> Option<SetSuggestion> {
+    let working: Vec<(f64, i32)> = sets
+        .iter()
+        .filter(|set| set.kind != SetKind::Warmup && !set.bodyweight && set.weight_kg > 0.0)
+        .filter_map(|set| set.reps.map(|reps| (set.weight_kg, reps)))
+        .collect();
+
+    let top_load = working
+        .iter()
+        .map(|(weight_kg, _)| *weight_kg)
+        .max_by(f64::total_cmp)?;
+    let top_reps: Vec<i32> = working
+        .iter()
+        .filter(|(weight_kg, _)| *weight_kg == top_load)
+        .map(|(_, reps)| *reps)
+        .collect();
+
+    let target_reps = plan.map_or(top_reps[0], |plan| plan.target_reps_max);
+    let target_sets = plan.map_or(top_reps.len() as i32, |plan| plan.target_sets);
+    let all_hit =
+        top_reps.len() as i32 >= target_sets && top_reps.iter().all(|reps| *reps >= target_reps);
+
+    Some(if all_hit {
+        SetSuggestion {
+            weight_kg: top_load + PROGRESSION_INCREMENT_KG,
+            reps: plan.map_or(target_reps, |plan| plan.target_reps_min),
+            sets: target_sets,
+            increased: true,
+        }
+    } else {
+        SetSuggestion {
+            weight_kg: top_load,
+            reps: target_reps,
+            sets: target_sets,
+            increased: false,
+        }
+    })
+}
+
+impl PreviousPerformance {
+    /// The exercise in the most recent completed workout with sets of it, if any. Blocks
+    /// of the exercise in that workout are merged into the first one, their sets in the
+    /// order they were logged.
+    pub fn find(conn: &mut SqliteConnection, exercise_id: i32) -> Result<Option<Self>, String> {
+        // Sessions without sets of the exercise are skipped here
+        let session = match WorkoutSession::last_completed_with_exercise(conn, exercise_id)
+            .map_err(handle_error)?
+        {
+            Some(session) => session,
+            None => return Ok(None),
+        };
+
+        let detail = WorkoutSession::detail(conn, session)?;
+        let mut blocks = detail
+            .exercises
+            .into_iter()
+            .filter(|exercise| exercise.exercise_id == exercise_id && !exercise.sets.is_empty());
+        let Some(mut exercise) = blocks.next() else {
+            return Ok(None);
+        };
+        for block in blocks {
+            exercise.plan = exercise.plan.or(block.plan);
+            exercise.sets.extend(block.sets);
+        }
+        exercise.sets.sort_by(|a, b| a.logged_at.cmp(&b.logged_at));
+
+        let lifting: Vec<LiftingSetMetrics> = exercise
+            .sets
+            .iter()
+            .filter_map(|set| set.metrics.lifting().cloned())
+            .collect();
+        let suggestion = suggest_next(&lifting, exercise.plan.as_ref());
+
+        Ok(Some(PreviousPerformance {
+            session: detail.session,
+            exercise,
+            suggestion,
+        }))
+    }
+}
+
+// ============================================================================
+// COMMANDS (Tauri)
+// ============================================================================
+
+/// Return the sets of an exercise from the most recent completed workout that contained
+/// it, with a suggested next target; `None` if it was never done before (`[WO-041]`).
+#[command]
+pub fn get_previous_performance(
+    pool: State<DbPool>,
+    exercise_id: i32,
+) -> Result<Option<PreviousPerformance>, String> {
+    log::debug!(">>> get_previous_performance exercise_id={:?}", exercise_id);
+
+    let mut conn = pool
+        .get()
+        .map_err(|e| format!("Failed to get connection: {}", e))?;
+
+    PreviousPerformance::find(&mut conn, exercise_id)
+}
@@ -0,0 +1,247 @@
+use crate::db::connection::DbPool;
+use crate::db::schema::{workout_exercise, workout_session, workout_set};
+use crate::service::intake::{Intake, IntakeTarget};
+use crate::service::progress::fit_weight_trend;
+use crate::service::weight::WeightTracker;
+use crate::service::workout::{local_day_start, SetMetrics, WorkoutSession, WorkoutSet};
+use crate::util::error_handler::handle_error;
+use crate::util::math_f32::floor_f32;
+use chrono::{Datelike, Days, Months, NaiveDate};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use tauri::{command, State};
+
+// ============================================================================
+// COMPOSITION MODEL
+// ============================================================================
+
+/// Aggregation period of a report
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum ReportPeriod {
+    /// ISO week, Monday to Sunday
+    Week,
+    /// Calendar month
+    Month,
+}
+
+/// Aggregated numbers of one period
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PeriodSummary {
+    pub date_from: String,
+    pub date_to: String,
+    pub days_logged: i32,
+    pub calories_total: i32,
+    /// Average per logged day
+    pub calories_average: f32,
+    /// Days exceeding the `maximum_calories` of the intake target in effect that day
+    pub days_over_maximum: i32,
+    /// Change of the fitted weight trend across all days of the period; `None` with fewer
+    /// than two days of weigh-ins
+    pub weight_change: Option<f32>,
+    pub workouts_completed: i32,
+    /// Total lifting volume (reps × kg) of all completed workouts
+    pub lifting_volume: f64,
+}
+
+/// Difference of the current to the previous period (current minus previous)
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PeriodComparison {
+    pub calories_total: i32,
+    pub calories_average: f32,
+    pub days_over_maximum: i32,
+    pub weight_change: Option<f32>,
+    pub workouts_completed: i32,
+    pub lifting_volume: f64,
+}
+
+/// Weekly or monthly report with a comparison to the period before
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PeriodReport {
+    pub period: ReportPeriod,
+    pub current: PeriodSummary,
+    pub previous: PeriodSummary,
+    pub change: PeriodComparison,
+}
+
+// ============================================================================
+// COMPOSITION LOGIC
+// ============================================================================
+
+impl ReportPeriod {
+    /// First and last day of the period containing `date`
+    pub fn bounds(&self, date: &NaiveDate) -> Result<(NaiveDate, NaiveDate), String> {
+        let (from, to) = match self {
+            ReportPeriod::Week => {
+                let from =
+                    date.checked_sub_days(Days::new(date.weekday().num_days_from_monday() as u64));
+                (
+                    from,
+                    from.and_then(|from| from.checked_add_days(Days::new(6))),
+                )
+            }
+            ReportPeriod::Month => {
+                let from = date.with_day(1);
+                let to = from
+                    .and_then(|from| from.checked_add_months(Months::new(1)))
+                    .and_then(|next| next.pred_opt());
+                (from, to)
+            }
+        };
+
+        from.zip(to)
+            .ok_or_else(|| format!("Failed to determine report period of {}", date))
+    }
+}
+
+impl PeriodReport {
+    /// Build the report of the week or month containing the given date
+    pub fn build_for_date(
+        conn: &mut SqliteConnection,
+        period: ReportPeriod,
+        date_str: &str,
+    ) -> Result<Self, String> {
+        let date = NaiveDate::parse_from_str(date_str, "%Y-%m-%d")
+            .map_err(|_| "Invalid date format".to_string())?;
+
+        let (from, to) = period.bounds(&date)?;
+        // The day before the period lies in the previous one
+        let (previous_from, previous_to) = period.bounds(
+            &from
+                .pred_opt()
+                .ok_or("Failed to determine previous period".to_string())?,
+        )?;
+
+        let current = PeriodSummary::build(conn, &from, &to)?;
+        let previous = PeriodSummary::build(conn, &previous_from, &previous_to)?;
+
+        let change = PeriodComparison {
+            calories_total: current.calories_total - previous.calories_total,
+            calories_average: floor_f32(current.calories_average - previous.calories_average, 1),
+            days_over_maximum: current.days_over_maximum - previous.days_over_maximum,
+            weight_change: current
+                .weight_change
+                .zip(previous.weight_change)
+                .map(|(current, previous)| floor_f32(current - previous, 1)),
+            workouts_completed: current.workouts_completed - previous.workouts_completed,
+            lifting_volume: current.lifting_volume - previous.lifting_volume,
+        };
+
+        Ok(PeriodReport {
+            period,
+            current,
+            previous,
+            change,
+        })
+    }
+}
+
+impl PeriodSummary {
+    /// Aggregate trackers and workouts from `from` to `to` (both inclusive)
+    pub fn build(
+        conn: &mut SqliteConnection,
+        from: &NaiveDate,
+        to: &NaiveDate,
+    ) -> Result<Self, String> {
+        let date_from = from.format("%Y-%m-%d").to_string();
+        let date_to = to.format("%Y-%m-%d").to_string();
+
+        let intake = Intake::find_by_date_range(conn, &date_from, &date_to)
+            .map_err(|e| format!("Failed to get calorie tracker data: {}", e))?;
+        let intake_targets = IntakeTarget::all(conn).map_err(handle_error)?;
+
+        let mut daily_calories: BTreeMap<String, i32> = BTreeMap::new();
+        for entry in intake {
+            *daily_calories.entry(entry.added).or_insert(0) += entry.amount;
+        }
+
+        let days_logged = daily_calories.len() as i32;
+        let calories_total: i32 = daily_calories.values().sum();
+        let calories_average = if days_logged > 0 {
+            floor_f32(calories_total as f32 / days_logged as f32, 1)
+        } else {
+            0.0
+        };
+
+        let days_over_maximum = daily_calories
+            .iter()
+            .filter(|(date, sum)| {
+                intake_targets
+                    .iter()
+                    .rev()
+                    .find(|target| {
+                        target.start_date.as_str() <= date.as_str()
+                            && date.as_str() <= target.end_date.as_str()
+                    })
+                    .is_some_and(|target| **sum > target.maximum_calories)
+            })
+            .count() as i32;
+
+        let weight_tracker = WeightTracker::find_by_date_range(conn, &date_from, &date_to)
+            .map_err(|e| format!("Failed to get weight tracker data: {}", e))?;
+        // Both ends included: a week spans 7 days
+        let days_in_period = (to.signed_duration_since(*from).num_days() + 1) as f32;
+        let weight_change = fit_weight_trend(&weight_tracker, from)
+            .map(|(_, slope)| floor_f32(slope * days_in_period, 1));
+
+        let sessions = WorkoutSession::completed_in_range(
+            conn,
+            &local_day_start(from)?,
+            &local_day_start(&to.succ_opt().ok_or("Failed to add day".to_string())?)?,
+        )
+        .map_err(handle_error)?;
+
+        let workouts_completed = sessions.len() as i32;
+        let session_ids: Vec<i32> = sessions.iter().map(|session| session.id).collect();
+        let sets: Vec<(String, WorkoutSet)> = workout_set::table
+            .inner_join(workout_exercise::table.inner_join(workout_session::table))
+            .filter(workout_session::id.eq_any(&session_ids))
+            .select((workout_session::workout_type, WorkoutSet::as_select()))
+            .load(conn)
+            .map_err(handle_error)?;
+        let mut lifting_volume = 0.0;
+        for (workout_type, set) in sets {
+            lifting_volume +=
+                SetMetrics::from_stored(&workout_type, set.payload_ver, &set.metrics)?.volume();
+        }
+
+        Ok(PeriodSummary {
+            date_from,
+            date_to,
+            days_logged,
+            calories_total,
+            calories_average,
+            days_over_maximum,
+            weight_change,
+            workouts_completed,
+            lifting_volume,
+        })
+    }
+}
+
+// ============================================================================
+// COMMANDS (Tauri)
+// ============================================================================
+
+/// Return the weekly or monthly report for the period containing the given date
+#[command]
+pub fn get_period_report(
+    pool: State<DbPool>,
+    period: ReportPeriod,
+    date_str: String,
+) -> Result<PeriodReport, String> {
+    log::debug!(
+        ">>> get_period_report period={:?} date_str={:?}",
+        period,
+        date_str
+    );
+
+    let mut conn = pool
+        .get()
+        .map_err(|e| format!("Failed to get connection: {}", e))?;
+
+    PeriodReport::build_for_date(&mut conn, period, &date_str)
+}
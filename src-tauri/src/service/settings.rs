@@ -0,0 +1,91 @@
+use crate::db::connection::DbPool;
+use crate::db::schema::app_settings;
+use crate::db::DbExecutor;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use tauri::{command, State};
+use validator::Validate;
+
+// ============================================================================
+// MODELS
+// ============================================================================
+
+/// App-wide preferences. There is exactly one row, seeded by its migration.
+#[derive(Queryable, Selectable, Serialize, Deserialize, Debug, Clone)]
+#[diesel(table_name = app_settings)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+#[serde(rename_all = "camelCase")]
+pub struct AppSettings {
+    pub id: i32,
+    /// Add the calories burned by the day's workouts to its calorie budget (`[DH-023]`)
+    pub add_workout_calories: bool,
+    /// Hours without activity after which an active workout is auto-completed; `None`
+    /// never auto-completes (`[WO-052]`)
+    pub stale_session_hours: Option<i32>,
+    /// Days deleted entries and workouts stay in the trash (`[HI-031]`)
+    pub trash_retention_days: i32,
+}
+
+/// For updating the [AppSettings].
+#[derive(AsChangeset, Serialize, Deserialize, Debug, Clone, Validate)]
+#[diesel(table_name = app_settings)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+#[diesel(treat_none_as_null = true)]
+#[serde(rename_all = "camelCase")]
+pub struct AppSettingsInput {
+    pub add_workout_calories: bool,
+    #[validate(range(
+        min = 1,
+        max = 168,
+        message = "Auto-complete must be between 1 hour and 1 week"
+    ))]
+    pub stale_session_hours: Option<i32>,
+    #[validate(range(
+        min = 1,
+        max = 365,
+        message = "Trash retention must be between 1 day and 1 year"
+    ))]
+    pub trash_retention_days: i32,
+}
+
+// ============================================================================
+// REPOSITORY
+// ============================================================================
+
+impl AppSettings {
+    pub fn get(conn: &mut SqliteConnection) -> QueryResult<Self> {
+        app_settings::table.select(Self::as_select()).first(conn)
+    }
+
+    pub fn update(conn: &mut SqliteConnection, input: &AppSettingsInput) -> QueryResult<Self> {
+        diesel::update(app_settings::table)
+            .set(input)
+            .returning(Self::as_returning())
+            .get_result(conn)
+    }
+}
+
+// ============================================================================
+// COMMANDS (Tauri)
+// ============================================================================
+
+#[command]
+pub fn get_app_settings(pool: State<DbPool>) -> Result<AppSettings, String> {
+    log::debug!(">>> get_app_settings");
+
+    pool.execute(AppSettings::get)
+}
+
+#[command]
+pub fn update_app_settings(
+    pool: State<DbPool>,
+    input: AppSettingsInput,
+) -> Result<AppSettings, String> {
+    log::debug!(">>> update_app_settings: {:?}", input);
+
+    if let Err(e) = input.validate() {
+        return Err(format!("Validation failed: {:?}", e));
+    }
+
+    pool.execute(|conn| AppSettings::update(conn, &input))
+}
@@ -0,0 +1,396 @@
+//! Body-weight-relative strength — Wilks, DOTS and IPF GL points of the best squat, bench
+//! press and deadlift, so lifters can see what a cut or bulk does to their relative
+//! strength (`[PG-025]`). Exercises count as one of the three lifts by their tag
+//! (`[PG-026]`).
+
+use crate::db::connection::DbPool;
+use crate::db::schema::{exercise, workout_exercise, workout_session, workout_set};
+use crate::service::body::BodyData;
+use crate::service::personal_record::{epley_one_rm, record_load};
+use crate::service::weight::WeightTracker;
+use crate::service::wizard::CalculationSex;
+use crate::service::workout::{
+    local_day_start, Exercise, ExerciseDetail, LiftingSetMetrics, SetKind, WorkoutSet,
+};
+use crate::util::error_handler::handle_error;
+use chrono::{Duration, NaiveDate};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use tauri::{command, State};
+
+/// Days up to a workout whose sets make the best lifts of its point
+pub const STRENGTH_WINDOW_DAYS: i64 = 28;
+
+// ============================================================================
+// COMPOSITION MODEL
+// ============================================================================
+
+/// The three powerlifts
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum StrengthLift {
+    Squat,
+    Bench,
+    Deadlift,
+}
+
+/// Best lifts and scores as of one workout date. The scores need all three lifts and a
+/// weigh-in.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct StrengthPoint {
+    pub date: String,
+    /// Best estimated one-rep maxes of the window
+    pub squat_kg: Option<f64>,
+    pub bench_kg: Option<f64>,
+    pub deadlift_kg: Option<f64>,
+    pub total_kg: Option<f64>,
+    /// The weigh-in closest to the date
+    pub body_weight_kg: Option<f32>,
+    pub wilks: Option<f64>,
+    pub dots: Option<f64>,
+    pub ipf_gl: Option<f64>,
+}
+
+/// Strength points of the workouts from `date_from` to `date_to` (both inclusive)
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct StrengthScores {
+    pub date_from: String,
+    pub date_to: String,
+    pub sex: CalculationSex,
+    pub points: Vec<StrengthPoint>,
+}
+
+// ============================================================================
+// COMPOSITION LOGIC
+// ============================================================================
+
+impl StrengthLift {
+    pub fn key(&self) -> &'static str {
+        match self {
+            StrengthLift::Squat => "squat",
+            StrengthLift::Bench => "bench",
+            StrengthLift::Deadlift => "deadlift",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        [
+            StrengthLift::Squat,
+            StrengthLift::Bench,
+            StrengthLift::Deadlift,
+        ]
+        .into_iter()
+        .find(|lift| lift.key() == key)
+    }
+}
+
+fn round_to(value: f64, decimals: i32) -> f64 {
+    let factor = 10f64.powi(decimals);
+    (value * factor).round() / factor
+}
+
+/// Wilks points (the original 1994 coefficients). Body weight is clamped to the range
+/// the formula was fitted on.
+pub fn wilks(total_kg: f64, body_weight_kg: f64, sex: &CalculationSex) -> f64 {
+    let (coefficients, min, max) = match sex {
+        CalculationSex::MALE => (
+            [
+                -216.0475144,
+                16.2606339,
+                -0.002388645,
+                -0.00113732,
+                7.01863e-06,
+                -1.291e-08,
+            ],
+            40.0,
+            201.9,
+        ),
+        CalculationSex::FEMALE => (
+            [
+                594.31747775582,
+                -27.23842536447,
+                0.82112226871,
+                -0.00930733913,
+                4.731582e-05,
+                -9.054e-08,
+            ],
+            26.51,
+            154.53,
+        ),
+    };
+    let x = body_weight_kg.clamp(min, max);
+    let denominator: f64 = coefficients
+        .iter()
+        .enumerate()
+        .map(|(power, c)| c * x.powi(power as i32))
+        .sum();
+    total_kg * 500.0 / denominator
+}
+
+/// DOTS points. Body weight is clamped to the range the formula was fitted on.
+pub fn dots(total_kg: f64, body_weight_kg: f64, sex: &CalculationSex) -> f64 {
+    let (coefficients, max) = match sex {
+        CalculationSex::MALE => (
+            [
+                -307.75076,
+                24.0900756,
+                -0.1918759221,
+                0.0007391293,
+                -0.000001093,
+            ],
+            210.0,
+        ),
+        CalculationSex::FEMALE => (
+            [
+                -57.96288,
+                13.6175032,
+                -0.1126655495,
+                0.0005158568,
+                -0.0000010706,
+            ],
+            150.0,
+        ),
+    };
+    let x = body_weight_kg.clamp(40.0, max);
+    let denominator: f64 = coefficients
+        .iter()
+        .enumerate()
+        .map(|(power, c)| c * x.powi(power as i32))
+        .sum();
+    total_kg * 500.0 / denominator
+}
+
+/// IPF GL points for classic (raw) powerlifting
+pub fn ipf_gl(total_kg: f64, body_weight_kg: f64, sex: &CalculationSex) -> f64 {
+    let (a, b, c) = match sex {
+        CalculationSex::MALE => (1199.72839, 1025.18162, 0.00921),
+        CalculationSex::FEMALE => (610.32796, 1045.59282, 0.03048),
+    };
+    total_kg * 100.0 / (a - b * (-c * body_weight_kg).exp())
+}
+
+/// Estimated one-rep max of a tagged lift's working set on a date
+struct LiftSet {
+    date: NaiveDate,
+    lift: StrengthLift,
+    one_rm: f64,
+}
+
+fn parse_date(date: &str) -> Result<NaiveDate, String> {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|_| "Invalid date format".to_string())
+}
+
+impl StrengthScores {
+    /// One point per local day with a tagged lift from `date_from` to `date_to`. Each lift
+    /// counts with its best Epley estimate of the [STRENGTH_WINDOW_DAYS] up to that day,
+    /// the body weight with the weigh-in closest to it. Warm-ups don't count.
+    pub fn build_for_range(
+        conn: &mut SqliteConnection,
+        date_from: &str,
+        date_to: &str,
+    ) -> Result<Self, String> {
+        let from = parse_date(date_from)?;
+        let to = parse_date(date_to)?;
+        if to < from {
+            return Err("The end date must not lie before the start date".to_string());
+        }
+        let sex = match BodyData::get(conn).optional().map_err(handle_error)? {
+            Some(body_data) => CalculationSex::from_body_data(&body_data.sex),
+            None => return Err("Strength scores need the sex of the body data".to_string()),
+        };
+
+        let window_start = from - Duration::days(STRENGTH_WINDOW_DAYS - 1);
+        let from_ts = local_day_start(&window_start)?;
+        let to_ts = local_day_start(&to.succ_opt().ok_or("Failed to add day".to_string())?)?;
+        let rows: Vec<(String, String, WorkoutSet)> = workout_set::table
+            .inner_join(
+                workout_exercise::table
+                    .inner_join(workout_session::table)
+                    .inner_join(exercise::table),
+            )
+            .filter(exercise::strength_lift.is_not_null())
+            .filter(workout_session::workout_type.eq("wl"))
+            .filter(workout_session::ended_at.is_not_null())
+            .filter(workout_session::deleted_at.is_null())
+            .filter(workout_session::started_at.ge(&from_ts))
+            .filter(workout_session::started_at.lt(&to_ts))
+            .select((
+                exercise::strength_lift.assume_not_null(),
+                workout_session::started_at,
+                WorkoutSet::as_select(),
+            ))
+            .load(conn)
+            .map_err(handle_error)?;
+
+        let mut sets = Vec::with_capacity(rows.len());
+        for (lift, started_at, set) in rows {
+            let metrics = LiftingSetMetrics::from_stored(set.payload_ver, &set.metrics)?;
+            let load = record_load(&metrics);
+            let one_rm = match metrics.reps {
+                Some(reps) if metrics.kind != SetKind::Warmup && load > 0.0 => {
+                    epley_one_rm(load, reps)
+                }
+                _ => None,
+            };
+            let (Some(lift), Some(one_rm)) = (StrengthLift::from_key(&lift), one_rm) else {
+                continue;
+            };
+            let date = chrono::DateTime::parse_from_rfc3339(&started_at)
+                .map_err(|e| format!("Invalid timestamp: {}", e))?
+                .with_timezone(&chrono::Local)
+                .date_naive();
+            sets.push(LiftSet { date, lift, one_rm });
+        }
+
+        let weigh_ins = WeightTracker::find_by_date_range(
+            conn,
+            &(window_start - Duration::days(STRENGTH_WINDOW_DAYS))
+                .format("%Y-%m-%d")
+                .to_string(),
+            &(to + Duration::days(STRENGTH_WINDOW_DAYS))
+                .format("%Y-%m-%d")
+                .to_string(),
+        )
+        .map_err(handle_error)?;
+
+        let mut dates: Vec<NaiveDate> = sets
+            .iter()
+            .map(|set| set.date)
+            .filter(|date| *date >= from && *date <= to)
+            .collect();
+        dates.sort();
+        dates.dedup();
+
+        let mut points = Vec::with_capacity(dates.len());
+        for date in dates {
+            let best = |lift: StrengthLift| {
+                sets.iter()
+                    .filter(|set| set.lift == lift)
+                    .filter(|set| {
+                        set.date <= date && (date - set.date).num_days() < STRENGTH_WINDOW_DAYS
+                    })
+                    .map(|set| set.one_rm)
+                    .reduce(f64::max)
+                    .map(|one_rm| round_to(one_rm, 1))
+            };
+            let squat_kg = best(StrengthLift::Squat);
+            let bench_kg = best(StrengthLift::Bench);
+            let deadlift_kg = best(StrengthLift::Deadlift);
+            let total_kg = match (squat_kg, bench_kg, deadlift_kg) {
+                (Some(squat), Some(bench), Some(deadlift)) => {
+                    Some(round_to(squat + bench + deadlift, 1))
+                }
+                _ => None,
+            };
+
+            let mut body_weight_kg = None;
+            let mut closest = i64::MAX;
+            for weigh_in in &weigh_ins {
+                let days = (parse_date(&weigh_in.added)? - date).num_days().abs();
+                // Ties go to the earlier weigh-in; entries are newest first
+                if days <= closest {
+                    closest = days;
+                    body_weight_kg = Some(weigh_in.amount);
+                }
+            }
+
+            let score =
+                |formula: fn(f64, f64, &CalculationSex) -> f64| match (total_kg, body_weight_kg) {
+                    (Some(total), Some(weight)) => {
+                        Some(round_to(formula(total, weight as f64, &sex), 2))
+                    }
+                    _ => None,
+                };
+            points.push(StrengthPoint {
+                date: date.format("%Y-%m-%d").to_string(),
+                squat_kg,
+                bench_kg,
+                deadlift_kg,
+                total_kg,
+                body_weight_kg,
+                wilks: score(wilks),
+                dots: score(dots),
+                ipf_gl: score(ipf_gl),
+            });
+        }
+
+        Ok(StrengthScores {
+            date_from: date_from.to_string(),
+            date_to: date_to.to_string(),
+            sex,
+            points,
+        })
+    }
+}
+
+// ============================================================================
+// REPOSITORY
+// ============================================================================
+
+impl Exercise {
+    /// Tag or untag the powerlift an exercise counts as.
+    pub fn set_strength_lift(
+        conn: &mut SqliteConnection,
+        id: i32,
+        lift: Option<StrengthLift>,
+    ) -> QueryResult<Exercise> {
+        diesel::update(exercise::table.filter(exercise::id.eq(id)))
+            .set(exercise::strength_lift.eq(lift.map(|lift| lift.key())))
+            .returning(Exercise::as_returning())
+            .get_result(conn)
+    }
+}
+
+// ============================================================================
+// COMMANDS (Tauri)
+// ============================================================================
+
+/// Return the best lifts with their Wilks, DOTS and IPF GL points per workout date in the
+/// range (both inclusive) (`[PG-025]`)
+#[command]
+pub fn get_strength_scores(
+    pool: State<DbPool>,
+    date_from: String,
+    date_to: String,
+) -> Result<StrengthScores, String> {
+    log::debug!(
+        ">>> get_strength_scores date_from={:?} date_to={:?}",
+        date_from,
+        date_to
+    );
+
+    let mut conn = pool
+        .get()
+        .map_err(|e| format!("Failed to get connection: {}", e))?;
+
+    StrengthScores::build_for_range(&mut conn, &date_from, &date_to)
+}
+
+/// Tag a weight-lifting exercise as a squat, bench press or deadlift for strength scores,
+/// or remove its tag (`[PG-026]`). Seeded exercises can be tagged too.
+#[command]
+pub fn tag_strength_lift(
+    pool: State<DbPool>,
+    exercise_id: i32,
+    lift: Option<StrengthLift>,
+) -> Result<ExerciseDetail, String> {
+    log::debug!(
+        ">>> tag_strength_lift exercise_id={} lift={:?}",
+        exercise_id,
+        lift
+    );
+
+    let mut conn = pool
+        .get()
+        .map_err(|e| format!("Failed to get connection: {}", e))?;
+
+    let found = Exercise::find(&mut conn, exercise_id).map_err(handle_error)?;
+    if found.workout_type != "wl" {
+        return Err(format!("{} is not a weight-lifting exercise", found.name));
+    }
+    let updated =
+        Exercise::set_strength_lift(&mut conn, exercise_id, lift).map_err(handle_error)?;
+    updated.with_muscles(&mut conn).map_err(handle_error)
+}
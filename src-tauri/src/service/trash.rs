@@ -0,0 +1,299 @@
+//! The trash. Deleted intake and weight entries and deleted or discarded workouts are
+//! only marked as deleted; read and update queries leave them out. They can be restored
+//! until they are purged, on demand or on startup after the days set in
+//! `trash_retention_days` (`[HI-029]`, `[HI-030]`, `[HI-031]`).
+
+use crate::db::connection::DbPool;
+use crate::db::schema::{intake, weight_tracker, workout_session};
+use crate::service::adherence::Milestone;
+use crate::service::intake::Intake;
+use crate::service::settings::AppSettings;
+use crate::service::weight::WeightTracker;
+use crate::service::workout::{refresh_records, WorkoutDetail, WorkoutExercise, WorkoutSession};
+use crate::util::error_handler::handle_error;
+use chrono::{Days, SecondsFormat, Utc};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use tauri::{command, State};
+
+// ============================================================================
+// COMPOSITION MODEL
+// ============================================================================
+
+/// What a trashed row is
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum TrashKind {
+    Intake,
+    Weight,
+    Workout,
+}
+
+/// Everything in the trash, most recently deleted first
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Trash {
+    pub intake: Vec<Intake>,
+    pub weight: Vec<WeightTracker>,
+    pub workouts: Vec<WorkoutDetail>,
+}
+
+// ============================================================================
+// REPOSITORY
+// ============================================================================
+
+/// Deletion timestamp, stored like the workout timestamps
+pub(crate) fn now_ts() -> String {
+    Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+/// Start of the retention window; rows trashed before it are purged
+fn retention_cutoff(conn: &mut SqliteConnection) -> QueryResult<String> {
+    let days = AppSettings::get(conn)?.trash_retention_days.max(0) as u64;
+    Ok(Utc::now()
+        .checked_sub_days(Days::new(days))
+        .unwrap_or_default()
+        .to_rfc3339_opts(SecondsFormat::Millis, true))
+}
+
+fn trashed_session(conn: &mut SqliteConnection, id: i32) -> QueryResult<Option<WorkoutSession>> {
+    workout_session::table
+        .filter(workout_session::id.eq(id))
+        .filter(workout_session::deleted_at.is_not_null())
+        .select(WorkoutSession::as_select())
+        .first(conn)
+        .optional()
+}
+
+/// When the row was trashed: `None` if it doesn't exist, `Some(None)` if it is live
+fn deleted_at(
+    conn: &mut SqliteConnection,
+    kind: TrashKind,
+    id: i32,
+) -> QueryResult<Option<Option<String>>> {
+    match kind {
+        TrashKind::Intake => intake::table
+            .find(id)
+            .select(intake::deleted_at)
+            .first(conn)
+            .optional(),
+        TrashKind::Weight => weight_tracker::table
+            .find(id)
+            .select(weight_tracker::deleted_at)
+            .first(conn)
+            .optional(),
+        TrashKind::Workout => workout_session::table
+            .find(id)
+            .select(workout_session::deleted_at)
+            .first(conn)
+            .optional(),
+    }
+}
+
+/// Whether the row is in the trash
+pub fn is_trashed(conn: &mut SqliteConnection, kind: TrashKind, id: i32) -> QueryResult<bool> {
+    Ok(matches!(deleted_at(conn, kind, id)?, Some(Some(_))))
+}
+
+/// Whether the row exists and is not in the trash
+pub fn is_live(conn: &mut SqliteConnection, kind: TrashKind, id: i32) -> QueryResult<bool> {
+    Ok(matches!(deleted_at(conn, kind, id)?, Some(None)))
+}
+
+/// The contents of the trash
+pub fn trash(conn: &mut SqliteConnection) -> Result<Trash, String> {
+    let intake = intake::table
+        .filter(intake::deleted_at.is_not_null())
+        .order((intake::deleted_at.desc(), intake::id.desc()))
+        .select(Intake::as_select())
+        .load(conn)
+        .map_err(handle_error)?;
+    let weight = weight_tracker::table
+        .filter(weight_tracker::deleted_at.is_not_null())
+        .order((weight_tracker::deleted_at.desc(), weight_tracker::id.desc()))
+        .select(WeightTracker::as_select())
+        .load(conn)
+        .map_err(handle_error)?;
+    let sessions: Vec<WorkoutSession> = workout_session::table
+        .filter(workout_session::deleted_at.is_not_null())
+        .order((
+            workout_session::deleted_at.desc(),
+            workout_session::id.desc(),
+        ))
+        .select(WorkoutSession::as_select())
+        .load(conn)
+        .map_err(handle_error)?;
+    let workouts = sessions
+        .into_iter()
+        .map(|session| WorkoutSession::detail(conn, session))
+        .collect::<Result<_, _>>()?;
+    Ok(Trash {
+        intake,
+        weight,
+        workouts,
+    })
+}
+
+/// Take a row out of the trash, bringing the milestones in line. A discarded workout is only restored while no other
+/// workout is active.
+pub fn restore(conn: &mut SqliteConnection, kind: TrashKind, id: i32) -> Result<(), String> {
+    let restored = match kind {
+        TrashKind::Intake => conn
+            .transaction(|conn| {
+                let restored = diesel::update(
+                    intake::table
+                        .filter(intake::id.eq(id))
+                        .filter(intake::deleted_at.is_not_null()),
+                )
+                .set(intake::deleted_at.eq(None::<String>))
+                .execute(conn)?;
+                Milestone::refresh(conn)?;
+                Ok(restored)
+            })
+            .map_err(handle_error)?,
+        TrashKind::Weight => conn
+            .transaction(|conn| {
+                let restored = diesel::update(
+                    weight_tracker::table
+                        .filter(weight_tracker::id.eq(id))
+                        .filter(weight_tracker::deleted_at.is_not_null()),
+                )
+                .set(weight_tracker::deleted_at.eq(None::<String>))
+                .execute(conn)?;
+                Milestone::refresh(conn)?;
+                Ok(restored)
+            })
+            .map_err(handle_error)?,
+        TrashKind::Workout => {
+            let Some(session) = trashed_session(conn, id).map_err(handle_error)? else {
+                return Err("The workout is not in the trash".to_string());
+            };
+            if session.ended_at.is_none()
+                && WorkoutSession::active(conn)
+                    .map_err(handle_error)?
+                    .is_some()
+            {
+                return Err("End the active workout before restoring the discarded one".to_string());
+            }
+            diesel::update(workout_session::table.filter(workout_session::id.eq(id)))
+                .set(workout_session::deleted_at.eq(None::<String>))
+                .execute(conn)
+                .map_err(handle_error)?;
+            let exercise_ids = WorkoutExercise::exercise_ids(conn, id).map_err(handle_error)?;
+            refresh_records(conn, &session, &exercise_ids)?;
+            1
+        }
+    };
+    if restored == 0 {
+        return Err("The entry is not in the trash".to_string());
+    }
+    Ok(())
+}
+
+/// Move a live row to the trash, recomputing the milestones or the records of a workout's
+/// exercises
+pub fn move_to_trash(conn: &mut SqliteConnection, kind: TrashKind, id: i32) -> Result<(), String> {
+    let moved = match kind {
+        TrashKind::Intake => conn
+            .transaction(|conn| {
+                let moved = Intake::delete(conn, &id)?;
+                Milestone::refresh(conn)?;
+                Ok(moved)
+            })
+            .map_err(handle_error)?,
+        TrashKind::Weight => conn
+            .transaction(|conn| {
+                let moved = WeightTracker::delete(conn, id)?;
+                Milestone::refresh(conn)?;
+                Ok(moved)
+            })
+            .map_err(handle_error)?,
+        TrashKind::Workout => {
+            let session = WorkoutSession::find(conn, id).map_err(handle_error)?;
+            let moved = WorkoutSession::discard(conn, id).map_err(handle_error)?;
+            let exercise_ids = WorkoutExercise::exercise_ids(conn, id).map_err(handle_error)?;
+            refresh_records(conn, &session, &exercise_ids)?;
+            moved
+        }
+    };
+    if moved == 0 {
+        return Err("The entry is already in the trash".to_string());
+    }
+    Ok(())
+}
+
+/// Delete the rows trashed up to `until` for good. Returns the number of purged entries
+/// and workouts.
+pub fn purge(conn: &mut SqliteConnection, until: &str) -> QueryResult<usize> {
+    conn.transaction(|conn| {
+        let mut purged = diesel::delete(
+            intake::table
+                .filter(intake::deleted_at.is_not_null())
+                .filter(intake::deleted_at.le(until)),
+        )
+        .execute(conn)?;
+        purged += diesel::delete(
+            weight_tracker::table
+                .filter(weight_tracker::deleted_at.is_not_null())
+                .filter(weight_tracker::deleted_at.le(until)),
+        )
+        .execute(conn)?;
+        let session_ids: Vec<i32> = workout_session::table
+            .filter(workout_session::deleted_at.is_not_null())
+            .filter(workout_session::deleted_at.le(until))
+            .select(workout_session::id)
+            .load(conn)?;
+        for id in session_ids {
+            purged += WorkoutSession::purge(conn, id)?;
+        }
+        Ok(purged)
+    })
+}
+
+/// Purge what was trashed before the retention window
+pub fn purge_expired(conn: &mut SqliteConnection) -> QueryResult<usize> {
+    let cutoff = retention_cutoff(conn)?;
+    purge(conn, &cutoff)
+}
+
+// ============================================================================
+// COMMANDS (Tauri)
+// ============================================================================
+
+/// Return the deleted entries and workouts that can still be restored (`[HI-029]`). Rows
+/// past the retention are purged on startup, not here.
+#[command]
+pub fn get_trash(pool: State<DbPool>) -> Result<Trash, String> {
+    log::debug!(">>> get_trash");
+
+    let mut conn = pool
+        .get()
+        .map_err(|e| format!("Failed to get connection: {}", e))?;
+
+    trash(&mut conn)
+}
+
+/// Restore an entry or workout from the trash (`[HI-030]`)
+#[command]
+pub fn restore_from_trash(pool: State<DbPool>, kind: TrashKind, id: i32) -> Result<(), String> {
+    log::debug!(">>> restore_from_trash kind={:?} id={}", kind, id);
+
+    let mut conn = pool
+        .get()
+        .map_err(|e| format!("Failed to get connection: {}", e))?;
+
+    restore(&mut conn, kind, id)
+}
+
+/// Purge everything in the trash and return the number of purged entries and workouts
+/// (`[HI-031]`)
+#[command]
+pub fn empty_trash(pool: State<DbPool>) -> Result<usize, String> {
+    log::debug!(">>> empty_trash");
+
+    let mut conn = pool
+        .get()
+        .map_err(|e| format!("Failed to get connection: {}", e))?;
+
+    purge(&mut conn, &now_ts()).map_err(handle_error)
+}
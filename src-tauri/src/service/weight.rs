@@ -1,6 +1,9 @@
 use crate::db::connection::DbPool;
 use crate::db::schema::{weight_target, weight_tracker};
 use crate::db::DbExecutor;
+use crate::service::adherence::Milestone;
+use crate::service::journal::{JournalEntry, Operation, RemovedRows};
+use crate::service::trash::now_ts;
 use chrono::NaiveDate;
 use diesel::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -27,6 +30,8 @@ pub struct WeightTracker {
     pub amount: f32,
     #[validate(custom(function = "validate_time_format"))]
     pub time: String,
+    /// When the entry was moved to the trash (`[HI-029]`)
+    pub deleted_at: Option<String>,
 }
 
 /// For creation of a new [WeightTracker] entry.
@@ -201,12 +206,14 @@ impl WeightTracker {
             .get_result(conn)
     }
 
-    /// Retrieve all weight tracker entries
+    /// Retrieve all weight tracker entries outside the trash
     pub fn all(conn: &mut SqliteConnection) -> QueryResult<Vec<Self>> {
-        weight_tracker::table.load::<Self>(conn)
+        weight_tracker::table
+            .filter(weight_tracker::deleted_at.is_null())
+            .load::<Self>(conn)
     }
 
-    /// Update a weight tracker entry by ID
+    /// Update a weight tracker entry by ID, unless it is in the trash
     pub fn update(
         conn: &mut SqliteConnection,
         tracker_id: &i32,
@@ -219,22 +226,32 @@ impl WeightTracker {
             time: updated_entry.time.clone().or_else(default_time),
         };
 
-        diesel::update(weight_tracker::table.filter(weight_tracker::id.eq(tracker_id)))
-            .set(&entry_with_time)
-            .returning(Self::as_returning())
-            .get_result(conn)
+        diesel::update(
+            weight_tracker::table
+                .filter(weight_tracker::id.eq(tracker_id))
+                .filter(weight_tracker::deleted_at.is_null()),
+        )
+        .set(&entry_with_time)
+        .returning(Self::as_returning())
+        .get_result(conn)
     }
 
-    /// Delete a weight tracker entry by ID
+    /// Move a weight tracker entry to the trash by ID
     pub fn delete(conn: &mut SqliteConnection, tracker_id: i32) -> QueryResult<usize> {
-        diesel::delete(weight_tracker::table.filter(weight_tracker::id.eq(tracker_id)))
-            .execute(conn)
+        diesel::update(
+            weight_tracker::table
+                .filter(weight_tracker::id.eq(tracker_id))
+                .filter(weight_tracker::deleted_at.is_null()),
+        )
+        .set(weight_tracker::deleted_at.eq(now_ts()))
+        .execute(conn)
     }
 
     /// Find weight tracker entries by date
     pub fn find_by_date(conn: &mut SqliteConnection, date: &String) -> QueryResult<Vec<Self>> {
         weight_tracker::table
             .filter(weight_tracker::added.eq(date))
+            .filter(weight_tracker::deleted_at.is_null())
             .load::<Self>(conn)
     }
 
@@ -246,6 +263,7 @@ impl WeightTracker {
     ) -> QueryResult<Vec<Self>> {
         weight_tracker::table
             .filter(weight_tracker::added.between(date_from, date_to))
+            .filter(weight_tracker::deleted_at.is_null())
             .order(weight_tracker::added.desc())
             .load::<Self>(conn)
     }
@@ -253,6 +271,7 @@ impl WeightTracker {
     /// Find most recent weight tracker entry
     pub fn get_latest(conn: &mut SqliteConnection) -> QueryResult<Self> {
         weight_tracker::table
+            .filter(weight_tracker::deleted_at.is_null())
             .order(weight_tracker::added.desc())
             .first::<Self>(conn)
     }
@@ -369,7 +388,13 @@ pub fn create_weight_tracker_entry(
 
     log::debug!("Creating new weight tracker entry: {:?}", new_entry);
 
-    pool.execute(|conn| WeightTracker::create(conn, &new_entry))
+    pool.execute(|conn| {
+        conn.transaction(|conn| {
+            let created = WeightTracker::create(conn, &new_entry)?;
+            Milestone::refresh(conn)?;
+            Ok(created)
+        })
+    })
 }
 
 /// Update a weight tracker entry by ID and return tracker data for that day
@@ -389,13 +414,29 @@ pub fn update_weight_tracker_entry(
         updated_entry
     );
 
-    pool.execute(|conn| WeightTracker::update(conn, &tracker_id, &updated_entry))
+    pool.execute(|conn| {
+        conn.transaction(|conn| {
+            let updated = WeightTracker::update(conn, &tracker_id, &updated_entry)?;
+            Milestone::refresh(conn)?;
+            Ok(updated)
+        })
+    })
 }
 
 /// Delete a weight tracker entry by ID and return tracker data for that day
 #[command]
 pub fn delete_weight_tracker_entry(pool: State<DbPool>, tracker_id: i32) -> Result<usize, String> {
-    pool.execute(|conn| WeightTracker::delete(conn, tracker_id))
+    pool.execute(|conn| {
+        conn.transaction(|conn| {
+            let deleted = WeightTracker::delete(conn, tracker_id)?;
+            if deleted > 0 {
+                let rows = RemovedRows::Weight { id: tracker_id };
+                JournalEntry::record(conn, Operation::DeleteWeight, &rows)?;
+                Milestone::refresh(conn)?;
+            }
+            Ok(deleted)
+        })
+    })
 }
 
 #[command]
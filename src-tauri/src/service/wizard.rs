@@ -1,9 +1,11 @@
 use crate::db::connection::DbPool;
+use crate::db::DbExecutor;
 use crate::i18n::localize;
+use crate::service::body::{validate_age_on, BodyData};
 use crate::service::intake::{IntakeTarget, NewIntakeTarget};
 use crate::service::weight::{NewWeightTarget, NewWeightTracker, WeightTarget, WeightTracker};
 use crate::util::math_f32::floor_f32;
-use chrono::{Duration, NaiveDate};
+use chrono::{Duration, Local, NaiveDate};
 use diesel::Connection;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -25,8 +27,8 @@ pub struct Wizard {
 #[derive(Validate, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct WizardInput {
-    #[validate(range(min = 18, max = 99, code = "validation.wizard.age"))]
-    pub age: i32,
+    /// Validated with the age on the calculation date, see [validate_as_of]
+    pub birth_date: String,
     pub sex: CalculationSex,
     #[validate(range(min = 30.0, max = 300.0, code = "validation.wizard.weight"))]
     pub weight: f32,
@@ -42,6 +44,26 @@ pub struct WizardInput {
     pub calculation_goal: CalculationGoal,
 }
 
+impl WizardInput {
+    /// Build calculation input from a recorded body data version, so a calculation can be
+    /// reproduced with the profile that applied at the time.
+    pub fn from_body_data(
+        body_data: &BodyData,
+        weekly_difference: i32,
+        calculation_goal: CalculationGoal,
+    ) -> Self {
+        WizardInput {
+            birth_date: body_data.birth_date.clone(),
+            sex: CalculationSex::from_body_data(&body_data.sex),
+            weight: body_data.weight,
+            height: body_data.height,
+            activity_level: body_data.activity_level,
+            weekly_difference,
+            calculation_goal,
+        }
+    }
+}
+
 #[derive(Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct WizardResult {
@@ -89,8 +111,8 @@ impl WizardResult {
 #[derive(Validate, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct WizardTargetWeightInput {
-    #[validate(range(min = 18, max = 99, code = "validation.wizard.age"))]
-    pub age: i32,
+    /// Validated with the age on the start date, see [validate_as_of]
+    pub birth_date: String,
     pub sex: CalculationSex,
     #[validate(range(min = 30.0, max = 300.0, code = "validation.wizard.weight"))]
     pub current_weight: f32,
@@ -98,6 +120,7 @@ pub struct WizardTargetWeightInput {
     pub height: f32,
     #[validate(range(min = 30.0, max = 300.0, code = "validation.wizard.weight"))]
     pub target_weight: f32,
+    #[validate(custom(function = "validate_date", code = "validation.wizard.start_date"))]
     pub start_date: String,
 }
 
@@ -115,8 +138,8 @@ pub struct WizardTargetWeightResult {
 #[derive(Validate, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct WizardTargetDateInput {
-    #[validate(range(min = 18, max = 99, code = "validation.wizard.age"))]
-    pub age: i32,
+    /// Validated with the age on the start date, see [validate_as_of]
+    pub birth_date: String,
     pub sex: CalculationSex,
     #[validate(range(min = 30.0, max = 300.0, code = "validation.wizard.weight"))]
     pub current_weight: f32,
@@ -124,6 +147,7 @@ pub struct WizardTargetDateInput {
     pub height: f32,
     pub calculation_goal: CalculationGoal,
     pub target_date: String,
+    #[validate(custom(function = "validate_date", code = "validation.wizard.start_date"))]
     pub start_date: String,
 }
 
@@ -146,6 +170,18 @@ pub enum CalculationSex {
     FEMALE,
 }
 
+impl CalculationSex {
+    /// Map the sex stored in [BodyData] to the calculation enum (anything but female
+    /// calculates as male, matching the frontend's interpretation).
+    pub fn from_body_data(sex: &str) -> Self {
+        if sex.eq_ignore_ascii_case("female") {
+            CalculationSex::FEMALE
+        } else {
+            CalculationSex::MALE
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub enum BmiCategory {
     Underweight,
@@ -173,21 +209,60 @@ fn validate_activity_level(activity_level: f32) -> Result<(), ValidationError> {
     }
 }
 
+fn validate_date(date: &str) -> Result<(), ValidationError> {
+    match NaiveDate::parse_from_str(date, "%Y-%m-%d") {
+        Ok(_) => Ok(()),
+        Err(_) => Err(ValidationError::new("validation.wizard.start_date")),
+    }
+}
+
+/// Validate the input with the age on `date` rather than today's, and return that age
+fn validate_as_of<T: Validate>(
+    input: &T,
+    birth_date: &str,
+    date: &NaiveDate,
+) -> Result<i32, ValidationErrors> {
+    let mut errors = input.validate().err().unwrap_or_default();
+    let age = validate_age_on(birth_date, date);
+    if age.is_err() {
+        errors.add("birth_date", ValidationError::new("validation.wizard.age"));
+    }
+    match age {
+        Ok(age) if errors.is_empty() => Ok(age),
+        _ => Err(localize::localize_validation_errors(&errors)),
+    }
+}
+
+/// The date a target calculation starts on. An invalid start date is reported by the
+/// input's validation; the age is then checked as of today.
+fn start_naive_date(start_date: &str) -> NaiveDate {
+    NaiveDate::parse_from_str(start_date, "%Y-%m-%d").unwrap_or_else(|_| Local::now().date_naive())
+}
+
 // ============================================================================
 // CALCULATION FUNCTIONS
 // ============================================================================
 
+/// Calculate with the age the user has today
 pub fn calculate(wizard_input: WizardInput) -> Result<WizardResult, ValidationErrors> {
-    match wizard_input.validate() {
-        Err(e) => Err(localize::localize_validation_errors(&e)),
-        Ok(_) => {
-            let target_bmi_range = calculate_target_bmi(&wizard_input.age);
+    calculate_as_of(wizard_input, &Local::now().date_naive())
+}
+
+/// Calculate with the age the user had on `date`, so past calculations stay reproducible
+pub fn calculate_as_of(
+    wizard_input: WizardInput,
+    date: &NaiveDate,
+) -> Result<WizardResult, ValidationErrors> {
+    match validate_as_of(&wizard_input, &wizard_input.birth_date, date) {
+        Err(e) => Err(e),
+        Ok(age) => {
+            let target_bmi_range = calculate_target_bmi(&age);
 
             let bmr = calculate_bmr(
                 &wizard_input.sex,
                 &wizard_input.weight,
                 &wizard_input.height,
-                &wizard_input.age,
+                &age,
             );
 
             let tdee = calculate_tdee(&wizard_input.activity_level, &bmr);
@@ -238,11 +313,10 @@ pub fn calculate(wizard_input: WizardInput) -> Result<WizardResult, ValidationEr
 pub fn calculate_for_target_date(
     input: &WizardTargetDateInput,
 ) -> Result<WizardTargetDateResult, ValidationErrors> {
-    match input.validate() {
-        Err(e) => Err(localize::localize_validation_errors(&e)),
-        Ok(_) => {
-            let start_naive_date =
-                NaiveDate::parse_from_str(&input.start_date, "%Y-%m-%d").unwrap();
+    let start_naive_date = start_naive_date(&input.start_date);
+    match validate_as_of(input, &input.birth_date, &start_naive_date) {
+        Err(e) => Err(e),
+        Ok(age) => {
             let target_naive_date =
                 NaiveDate::parse_from_str(&input.target_date, "%Y-%m-%d").unwrap();
 
@@ -295,7 +369,7 @@ pub fn calculate_for_target_date(
 
             // calculate a custom rate to present at least one viable result
             if result_by_rate.is_empty() {
-                let target_bmi = calculate_target_bmi(&input.age);
+                let target_bmi = calculate_target_bmi(&age);
                 let target_weight = calculate_target_weight(&target_bmi, &input.height);
 
                 let difference = floor_f32(
@@ -339,8 +413,9 @@ pub fn calculate_for_target_date(
 pub fn calculate_for_target_weight(
     input: &WizardTargetWeightInput,
 ) -> Result<WizardTargetWeightResult, ValidationErrors> {
-    match input.validate() {
-        Err(e) => Err(localize::localize_validation_errors(&e)),
+    let start_naive_date = start_naive_date(&input.start_date);
+    match validate_as_of(input, &input.birth_date, &start_naive_date) {
+        Err(e) => Err(e),
         Ok(_) => {
             let current_bmi = calculate_bmi(&input.current_weight, &input.height);
             let target_bmi = calculate_bmi(&input.target_weight, &input.height);
@@ -398,9 +473,8 @@ pub fn calculate_for_target_weight(
 
                     let progress = floor_f32(rate as f32 * 7.0 / 7000.0, 2);
 
-                    let date = NaiveDate::parse_from_str(&input.start_date, "%Y-%m-%d").unwrap();
-
-                    date_by_rate.insert(rate, (date + Duration::days(days)).to_string());
+                    date_by_rate
+                        .insert(rate, (start_naive_date + Duration::days(days)).to_string());
                     progress_by_rate.insert(rate, progress);
                 }
             }
@@ -554,6 +628,34 @@ pub fn wizard_calculate_tdee(input: WizardInput) -> Result<WizardResult, Validat
     wizard_result
 }
 
+/// Calculate TDEE from the body data that applied on the given date instead of user input
+#[command]
+pub fn wizard_calculate_tdee_for_date(
+    pool: State<DbPool>,
+    date_str: String,
+    weekly_difference: i32,
+    calculation_goal: CalculationGoal,
+) -> Result<WizardResult, String> {
+    log::debug!(
+        ">>> wizard_calculate_tdee_for_date: date_str={:?} weekly_difference={:?} calculation_goal={:?}",
+        date_str,
+        weekly_difference,
+        calculation_goal
+    );
+
+    let date = NaiveDate::parse_from_str(&date_str, "%Y-%m-%d")
+        .map_err(|e| format!("Invalid date {:?}: {}", date_str, e))?;
+    let body_data = pool.execute(|conn| BodyData::as_of(conn, &date_str))?;
+    let input = WizardInput::from_body_data(&body_data, weekly_difference, calculation_goal);
+
+    let wizard_result =
+        calculate_as_of(input, &date).map_err(|e| format!("Validation failed: {:?}", e));
+
+    log::debug!(">>> result={:?}", wizard_result);
+
+    wizard_result
+}
+
 #[command]
 pub fn wizard_create_targets(pool: State<DbPool>, input: Wizard) -> Result<(), String> {
     log::debug!(">>> wizard_create_targets: {:?}", input);
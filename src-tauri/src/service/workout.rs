@@ -1,25 +1,34 @@
-//! Workout tracking domain — standalone (does not touch intake/weight).
+//! Workout tracking domain: sessions, their exercises and sets, the exercise library, and
+//! the Tauri commands to log, edit and end workouts.
 //!
 //! Hierarchy: `workout_session -> workout_exercise -> workout_set`. Only the
-//! type-varying set metrics live in `workout_set.metrics` (JSON); the relational
-//! skeleton is typed SQL. The typed metric payload (SSOT) + validation, the
-//! repository methods, and the Tauri commands are added in subsequent tasks
-//! (3.x, 4.x). This module currently defines the table-mapped models.
+//! type-varying set metrics live in `workout_set.metrics` (JSON, see [SetMetrics]); the
+//! relational skeleton is typed SQL. Workouts don't write to intake or weight, but the
+//! calories they burn are estimated from the latest weigh-in and may be added to the
+//! day's calorie budget (see `energy` and `dashboard`).
 
 use crate::db::connection::{DbPool, PooledConnection};
 use crate::db::schema::{
     exercise, exercise_category, exercise_muscle, muscle, workout_exercise, workout_pause,
     workout_session, workout_set, workout_type,
 };
+use crate::db::DbExecutor;
+use crate::service::journal::{JournalEntry, Operation, RemovedRows};
+use crate::service::personal_record::{PersonalRecord, RecordKind};
+use crate::service::settings::AppSettings;
+use crate::service::workout_summary::WorkoutSummary;
 use crate::util::error_handler::handle_error;
 use diesel::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use tauri::{command, State};
-use validator::Validate;
+use validator::{Validate, ValidationError};
 
-/// Auto-complete an active session after this much inactivity (no set logged, no
-/// pause/resume). Protects trends from "zombie" sessions. See `[WO-020]`.
-const STALE_AFTER_SECS: i64 = 4 * 60 * 60;
+/// Rest target when neither the plan, the set, nor the exercise define one (`[WO-017]`).
+pub const DEFAULT_REST_SECONDS: i32 = 90;
+
+/// Gap after the last set of a block for a set added to a completed session (`[WO-055]`).
+pub const ADDED_SET_SPACING_SECONDS: i64 = 60;
 
 // ============================================================================
 // LOOKUPS (seeded; follow the food_category precedent)
@@ -31,6 +40,8 @@ const STALE_AFTER_SECS: i64 = 4 * 60 * 60;
 pub struct WorkoutType {
     pub longvalue: String,
     pub shortvalue: String,
+    /// Metabolic equivalent used to estimate calories burned (`[WO-047]`)
+    pub met: f32,
 }
 
 #[derive(Queryable, Selectable, Serialize, Deserialize, Debug, Clone)]
@@ -62,9 +73,18 @@ pub struct Exercise {
     pub name: String,
     pub category: String,
     pub default_rest_seconds: Option<i32>,
+    /// The workout type this exercise is logged in (`[WO-032]`)
+    pub workout_type: String,
+    /// Added by the user; seeded exercises are read-only (`[WO-043]`)
+    pub custom: bool,
+    /// Hidden from the library and from logging, history stays intact (`[WO-044]`)
+    pub archived: bool,
+    /// The powerlift it counts as for strength scores: `squat`, `bench` or `deadlift`
+    /// (`[PG-026]`)
+    pub strength_lift: Option<String>,
 }
 
-#[derive(Queryable, Selectable, Serialize, Deserialize, Debug, Clone)]
+#[derive(Queryable, Selectable, Insertable, Serialize, Deserialize, Debug, Clone)]
 #[diesel(table_name = exercise_muscle)]
 #[diesel(check_for_backend(diesel::sqlite::Sqlite))]
 #[serde(rename_all = "camelCase")]
@@ -88,6 +108,12 @@ pub struct WorkoutSession {
     pub name: Option<String>,
     pub started_at: String,
     pub ended_at: Option<String>,
+    /// The template the session was started from, if any (`[WO-051]`)
+    pub template_id: Option<i32>,
+    /// Ended by the stale check rather than by the user (`[WO-053]`)
+    pub auto_completed: bool,
+    /// When the session was moved to the trash (`[HI-029]`)
+    pub deleted_at: Option<String>,
 }
 
 #[derive(Insertable, AsChangeset, Serialize, Deserialize, Debug)]
@@ -109,15 +135,33 @@ pub struct WorkoutExercise {
     pub id: i32,
     pub session_id: i32,
     pub exercise_id: i32,
+    /// Plan snapshot when started from a template (`[WO-030]`); all `None` otherwise.
+    pub target_sets: Option<i32>,
+    pub target_reps_min: Option<i32>,
+    pub target_reps_max: Option<i32>,
+    pub target_weight_kg: Option<f64>,
+    pub target_rpe: Option<f32>,
+    pub plan_rest_seconds: Option<i32>,
+    /// Place within the session; the same exercise may appear in several blocks (`[WO-045]`).
+    pub position: i32,
+    /// Blocks sharing a group form a superset or circuit (`[WO-046]`).
+    pub group_id: Option<i32>,
 }
 
-#[derive(Insertable, Serialize, Deserialize, Debug)]
+#[derive(Insertable, Serialize, Deserialize, Debug, Default)]
 #[diesel(table_name = workout_exercise)]
 #[diesel(check_for_backend(diesel::sqlite::Sqlite))]
 #[serde(rename_all = "camelCase")]
 pub struct NewWorkoutExercise {
     pub session_id: i32,
     pub exercise_id: i32,
+    pub target_sets: Option<i32>,
+    pub target_reps_min: Option<i32>,
+    pub target_reps_max: Option<i32>,
+    pub target_weight_kg: Option<f64>,
+    pub target_rpe: Option<f32>,
+    pub plan_rest_seconds: Option<i32>,
+    pub group_id: Option<i32>,
 }
 
 #[derive(Queryable, Selectable, Serialize, Deserialize, Debug, Clone)]
@@ -132,6 +176,8 @@ pub struct WorkoutSet {
     /// Raw JSON metric payload — typed/validated against the workout type's
     /// schema by the metric-payload layer (task 3.x), not by the DB.
     pub metrics: String,
+    /// Rest target after this set, overriding the exercise default (`[WO-048]`)
+    pub rest_seconds: Option<i32>,
 }
 
 #[derive(Insertable, AsChangeset, Serialize, Deserialize, Debug)]
@@ -166,28 +212,155 @@ pub struct NewWorkoutPause {
     pub resumed_at: Option<String>,
 }
 
+/// For correcting a session after the fact (`[WO-054]`). The pauses replace the session's.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkoutSessionInput {
+    pub name: Option<String>,
+    pub started_at: String,
+    /// Required once the session has ended; an active session is ended by ending it
+    pub ended_at: Option<String>,
+    pub pauses: Vec<WorkoutPauseInput>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkoutPauseInput {
+    pub paused_at: String,
+    /// Only the last pause of an active session may still be open
+    pub resumed_at: Option<String>,
+}
+
+/// How exercise search results are ordered (`[WO-057]`)
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum ExerciseOrder {
+    #[default]
+    Name,
+    /// Latest workout first, never used last
+    RecentlyUsed,
+    /// Most workouts first, never used last
+    MostUsed,
+}
+
+/// Filters of an exercise search; all are optional and combine (`[WO-056]`)
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ExerciseSearch {
+    /// Weight lifting unless given
+    pub workout_type: Option<String>,
+    /// Part of the name, ignoring case
+    pub name: Option<String>,
+    pub category: Option<String>,
+    pub muscle: Option<String>,
+    /// `primary` or `secondary`; together with `muscle` the muscle must have that role
+    pub role: Option<String>,
+    #[serde(default)]
+    pub order: ExerciseOrder,
+}
+
 // ============================================================================
 // METRIC PAYLOAD (compiled schema — SSOT in Rust; tauri-typegen emits TS + Zod)
 // ============================================================================
 
-/// Current payload version for the weight-lifting metric schema.
-pub const WL_PAYLOAD_VER: i32 = 1;
+/// Current payload version for the weight-lifting metric schema. v2 made reps optional and
+/// added a duration, the bodyweight flag and the added/assisting load (`[WO-034]`); v3
+/// added RPE/RIR, the set kind and a note (`[WO-036]`).
+pub const WL_PAYLOAD_VER: i32 = 3;
+
+/// Purpose of a lifting set. Warm-ups are excluded from volume (`[WO-037]`).
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum SetKind {
+    Warmup,
+    #[default]
+    Working,
+    Drop,
+    Failure,
+    Amrap,
+}
 
 /// Weight-lifting set metrics. Single source of truth for the `wl` payload schema:
 /// `tauri-typegen` generates the matching TS type + Zod validator from this struct, the
 /// frontend pre-validates, and the command re-validates on write. Stored serialized in
 /// `workout_set.metrics`; the DB never types the blob.
-#[derive(Serialize, Deserialize, Validate, Debug, Clone, PartialEq)]
+///
+/// A set has reps, a duration (isometric holds like a plank), or both. `weight_kg` is the
+/// external load; a bodyweight set keeps it at 0 and expresses extra load as
+/// `added_load_kg`: positive for added weight (weighted pull-up), negative for assistance
+/// (band or machine). Effort is rated as either RPE or RIR.
+#[derive(Serialize, Deserialize, Validate, Debug, Clone, PartialEq, Default)]
 #[serde(rename_all = "camelCase")]
+#[validate(schema(function = "validate_lifting_set"))]
 pub struct LiftingSetMetrics {
     #[validate(range(min = 1, max = 1000, message = "Reps must be between 1 and 1000"))]
-    pub reps: i32,
+    pub reps: Option<i32>,
+    #[validate(range(
+        min = 1,
+        max = 3600,
+        message = "Duration must be between 1 second and 1 hour"
+    ))]
+    pub duration_seconds: Option<i32>,
     #[validate(range(
         min = 0.0,
         max = 1000.0,
         message = "Weight must be between 0 and 1000 kg"
     ))]
     pub weight_kg: f64,
+    #[serde(default)]
+    pub bodyweight: bool,
+    #[validate(range(
+        min = -500.0,
+        max = 500.0,
+        message = "Added or assisting load must be between -500 and 500 kg"
+    ))]
+    pub added_load_kg: Option<f64>,
+    #[validate(range(min = 1.0, max = 10.0, message = "RPE must be between 1 and 10"))]
+    pub rpe: Option<f32>,
+    #[validate(range(min = 0, max = 10, message = "RIR must be between 0 and 10"))]
+    pub rir: Option<i32>,
+    #[serde(default)]
+    pub kind: SetKind,
+    #[validate(length(max = 500, message = "Note must be at most 500 characters"))]
+    pub note: Option<String>,
+}
+
+/// The v1 `wl` payload: reps and external load only.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LiftingSetMetricsV1 {
+    reps: i32,
+    weight_kg: f64,
+}
+
+impl From<LiftingSetMetricsV1> for LiftingSetMetrics {
+    fn from(v1: LiftingSetMetricsV1) -> Self {
+        LiftingSetMetrics {
+            reps: Some(v1.reps),
+            weight_kg: v1.weight_kg,
+            ..Default::default()
+        }
+    }
+}
+
+fn validate_lifting_set(metrics: &LiftingSetMetrics) -> Result<(), ValidationError> {
+    if metrics.reps.is_none() && metrics.duration_seconds.is_none() {
+        return Err(ValidationError::new("A set needs reps or a duration"));
+    }
+    if metrics.bodyweight && metrics.weight_kg != 0.0 {
+        return Err(ValidationError::new(
+            "Bodyweight sets carry extra weight as added load",
+        ));
+    }
+    if !metrics.bodyweight && metrics.added_load_kg.is_some() {
+        return Err(ValidationError::new(
+            "Added or assisting load applies to bodyweight sets only",
+        ));
+    }
+    if metrics.rpe.is_some() && metrics.rir.is_some() {
+        return Err(ValidationError::new("Rate a set by either RPE or RIR"));
+    }
+    Ok(())
 }
 
 impl LiftingSetMetrics {
@@ -197,16 +370,334 @@ impl LiftingSetMetrics {
     /// SQL migrations (`json_set`/`json_remove`) that bump `payload_ver`; only semantic
     /// changes need a programmatic upcast arm here. A worked JSON1 migration and the
     /// lenient-read behavior are verified in `tests/repo/test_workout_metrics.rs`.
+    ///
+    /// Stored v1 sets are upgraded by the `lifting_set_metrics_v2` migration, which also
+    /// flags sets of bodyweight exercises; the v1 arm covers payloads it never saw and
+    /// reads them as loaded (non-bodyweight) sets. v3 only added optional keys, so a v2
+    /// payload reads as a working set without annotations.
     pub fn from_stored(payload_ver: i32, metrics: &str) -> Result<Self, String> {
         match payload_ver {
-            1 => serde_json::from_str::<LiftingSetMetrics>(metrics)
+            1 => serde_json::from_str::<LiftingSetMetricsV1>(metrics)
+                .map(LiftingSetMetrics::from)
                 .map_err(|e| format!("Failed to parse set metrics (v1): {}", e)),
+            2 | 3 => serde_json::from_str::<LiftingSetMetrics>(metrics)
+                .map_err(|e| format!("Failed to parse set metrics (v{}): {}", payload_ver, e)),
             other => Err(format!("Unknown set metrics payload version {}", other)),
         }
     }
 
+    /// Working volume: [LiftingSetMetrics::total_volume] of every set except warm-ups
+    /// (`[WO-037]`). This is what summaries and reports count.
+    pub fn volume(&self) -> f64 {
+        if self.kind == SetKind::Warmup {
+            0.0
+        } else {
+            self.total_volume()
+        }
+    }
+
+    /// Reps × external load, warm-ups included. Bodyweight sets count only their added
+    /// load (neither the lifter's own weight nor assistance); time-only sets have no
+    /// volume.
+    pub fn total_volume(&self) -> f64 {
+        let load = if self.bodyweight {
+            self.added_load_kg.unwrap_or(0.0).max(0.0)
+        } else {
+            self.weight_kg
+        };
+        self.reps.unwrap_or(0) as f64 * load
+    }
+}
+
+/// Current payload versions of the endurance metric schemas.
+pub const CARDIO_PAYLOAD_VER: i32 = 1;
+pub const RUN_PAYLOAD_VER: i32 = 1;
+pub const CYCLE_PAYLOAD_VER: i32 = 1;
+pub const ROW_PAYLOAD_VER: i32 = 1;
+pub const SWIM_PAYLOAD_VER: i32 = 1;
+
+/// Generic cardio interval (`cardio`), e.g. elliptical or jump rope. Distance is optional
+/// since most machines in this category don't measure one.
+#[derive(Serialize, Deserialize, Validate, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CardioSetMetrics {
+    #[validate(range(
+        min = 1,
+        max = 86400,
+        message = "Duration must be between 1 second and 24 hours"
+    ))]
+    pub duration_seconds: i32,
+    #[validate(range(
+        min = 0.0,
+        max = 1000000.0,
+        message = "Distance must be between 0 and 1000 km"
+    ))]
+    pub distance_m: Option<f64>,
+    #[validate(range(
+        min = 30,
+        max = 250,
+        message = "Heart rate must be between 30 and 250 bpm"
+    ))]
+    pub avg_heart_rate: Option<i32>,
+}
+
+/// A run (`run`). Pace is derived, see [RunningSetMetrics::pace_seconds_per_km].
+#[derive(Serialize, Deserialize, Validate, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RunningSetMetrics {
+    #[validate(range(
+        min = 1,
+        max = 86400,
+        message = "Duration must be between 1 second and 24 hours"
+    ))]
+    pub duration_seconds: i32,
+    #[validate(range(
+        min = 1.0,
+        max = 1000000.0,
+        message = "Distance must be between 1 m and 1000 km"
+    ))]
+    pub distance_m: f64,
+    #[validate(range(
+        min = 30,
+        max = 250,
+        message = "Heart rate must be between 30 and 250 bpm"
+    ))]
+    pub avg_heart_rate: Option<i32>,
+    #[validate(range(
+        min = 0.0,
+        max = 10000.0,
+        message = "Elevation gain must be between 0 and 10000 m"
+    ))]
+    pub elevation_gain_m: Option<f64>,
+}
+
+/// A ride (`cycle`). Speed is derived, see [CyclingSetMetrics::speed_kmh].
+#[derive(Serialize, Deserialize, Validate, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CyclingSetMetrics {
+    #[validate(range(
+        min = 1,
+        max = 86400,
+        message = "Duration must be between 1 second and 24 hours"
+    ))]
+    pub duration_seconds: i32,
+    #[validate(range(
+        min = 1.0,
+        max = 1000000.0,
+        message = "Distance must be between 1 m and 1000 km"
+    ))]
+    pub distance_m: f64,
+    #[validate(range(
+        min = 30,
+        max = 250,
+        message = "Heart rate must be between 30 and 250 bpm"
+    ))]
+    pub avg_heart_rate: Option<i32>,
+    #[validate(range(
+        min = 0.0,
+        max = 10000.0,
+        message = "Elevation gain must be between 0 and 10000 m"
+    ))]
+    pub elevation_gain_m: Option<f64>,
+    #[validate(range(min = 0, max = 3000, message = "Power must be between 0 and 3000 W"))]
+    pub avg_power_watts: Option<i32>,
+}
+
+/// A rowing piece (`row`). Split is derived, see [RowingSetMetrics::pace_seconds_per_500m].
+#[derive(Serialize, Deserialize, Validate, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RowingSetMetrics {
+    #[validate(range(
+        min = 1,
+        max = 86400,
+        message = "Duration must be between 1 second and 24 hours"
+    ))]
+    pub duration_seconds: i32,
+    #[validate(range(
+        min = 1.0,
+        max = 100000.0,
+        message = "Distance must be between 1 m and 100 km"
+    ))]
+    pub distance_m: f64,
+    #[validate(range(
+        min = 30,
+        max = 250,
+        message = "Heart rate must be between 30 and 250 bpm"
+    ))]
+    pub avg_heart_rate: Option<i32>,
+    #[validate(range(
+        min = 10,
+        max = 60,
+        message = "Stroke rate must be between 10 and 60 strokes per minute"
+    ))]
+    pub stroke_rate_spm: Option<i32>,
+}
+
+/// A swim (`swim`). Pace is derived, see [SwimmingSetMetrics::pace_seconds_per_100m].
+#[derive(Serialize, Deserialize, Validate, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SwimmingSetMetrics {
+    #[validate(range(
+        min = 1,
+        max = 86400,
+        message = "Duration must be between 1 second and 24 hours"
+    ))]
+    pub duration_seconds: i32,
+    #[validate(range(
+        min = 1.0,
+        max = 100000.0,
+        message = "Distance must be between 1 m and 100 km"
+    ))]
+    pub distance_m: f64,
+    #[validate(range(
+        min = 30,
+        max = 250,
+        message = "Heart rate must be between 30 and 250 bpm"
+    ))]
+    pub avg_heart_rate: Option<i32>,
+    #[validate(range(
+        min = 10,
+        max = 100,
+        message = "Pool length must be between 10 and 100 m"
+    ))]
+    pub pool_length_m: Option<i32>,
+}
+
+impl RunningSetMetrics {
+    pub fn pace_seconds_per_km(&self) -> f64 {
+        self.duration_seconds as f64 / (self.distance_m / 1000.0)
+    }
+}
+
+impl CyclingSetMetrics {
+    pub fn speed_kmh(&self) -> f64 {
+        (self.distance_m / 1000.0) / (self.duration_seconds as f64 / 3600.0)
+    }
+}
+
+impl RowingSetMetrics {
+    pub fn pace_seconds_per_500m(&self) -> f64 {
+        self.duration_seconds as f64 / (self.distance_m / 500.0)
+    }
+}
+
+impl SwimmingSetMetrics {
+    pub fn pace_seconds_per_100m(&self) -> f64 {
+        self.duration_seconds as f64 / (self.distance_m / 100.0)
+    }
+}
+
+/// Set metrics of any workout type, tagged with the type's short value
+/// (`{"type": "wl", "data": {...}}`, the shape the generated bindings describe). Only the
+/// inner payload is stored; on read, the owning session's `workout_type` decides which
+/// schema parses it (`[WO-032]`).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", content = "data")]
+pub enum SetMetrics {
+    #[serde(rename = "wl")]
+    Lifting(LiftingSetMetrics),
+    #[serde(rename = "cardio")]
+    Cardio(CardioSetMetrics),
+    #[serde(rename = "run")]
+    Running(RunningSetMetrics),
+    #[serde(rename = "cycle")]
+    Cycling(CyclingSetMetrics),
+    #[serde(rename = "row")]
+    Rowing(RowingSetMetrics),
+    #[serde(rename = "swim")]
+    Swimming(SwimmingSetMetrics),
+}
+
+impl SetMetrics {
+    /// Parse a stored payload with the schema of the given workout type.
+    pub fn from_stored(
+        workout_type: &str,
+        payload_ver: i32,
+        metrics: &str,
+    ) -> Result<Self, String> {
+        match workout_type {
+            "wl" => LiftingSetMetrics::from_stored(payload_ver, metrics).map(SetMetrics::Lifting),
+            "cardio" => parse_endurance(payload_ver, metrics).map(SetMetrics::Cardio),
+            "run" => parse_endurance(payload_ver, metrics).map(SetMetrics::Running),
+            "cycle" => parse_endurance(payload_ver, metrics).map(SetMetrics::Cycling),
+            "row" => parse_endurance(payload_ver, metrics).map(SetMetrics::Rowing),
+            "swim" => parse_endurance(payload_ver, metrics).map(SetMetrics::Swimming),
+            other => Err(format!("Unknown workout type '{}'", other)),
+        }
+    }
+
+    /// Short value of the workout type this payload belongs to.
+    pub fn workout_type(&self) -> &'static str {
+        match self {
+            SetMetrics::Lifting(_) => "wl",
+            SetMetrics::Cardio(_) => "cardio",
+            SetMetrics::Running(_) => "run",
+            SetMetrics::Cycling(_) => "cycle",
+            SetMetrics::Rowing(_) => "row",
+            SetMetrics::Swimming(_) => "swim",
+        }
+    }
+
+    /// Current payload version of this payload's schema.
+    pub fn payload_ver(&self) -> i32 {
+        match self {
+            SetMetrics::Lifting(_) => WL_PAYLOAD_VER,
+            SetMetrics::Cardio(_) => CARDIO_PAYLOAD_VER,
+            SetMetrics::Running(_) => RUN_PAYLOAD_VER,
+            SetMetrics::Cycling(_) => CYCLE_PAYLOAD_VER,
+            SetMetrics::Rowing(_) => ROW_PAYLOAD_VER,
+            SetMetrics::Swimming(_) => SWIM_PAYLOAD_VER,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), validator::ValidationErrors> {
+        match self {
+            SetMetrics::Lifting(m) => m.validate(),
+            SetMetrics::Cardio(m) => m.validate(),
+            SetMetrics::Running(m) => m.validate(),
+            SetMetrics::Cycling(m) => m.validate(),
+            SetMetrics::Rowing(m) => m.validate(),
+            SetMetrics::Swimming(m) => m.validate(),
+        }
+    }
+
+    /// Serialize the inner payload (without the type tag) for `workout_set.metrics`.
+    pub fn to_stored(&self) -> Result<String, String> {
+        match self {
+            SetMetrics::Lifting(m) => serde_json::to_string(m),
+            SetMetrics::Cardio(m) => serde_json::to_string(m),
+            SetMetrics::Running(m) => serde_json::to_string(m),
+            SetMetrics::Cycling(m) => serde_json::to_string(m),
+            SetMetrics::Rowing(m) => serde_json::to_string(m),
+            SetMetrics::Swimming(m) => serde_json::to_string(m),
+        }
+        .map_err(|e| format!("Serialize failed: {}", e))
+    }
+
+    /// The lifting payload, `None` for endurance types.
+    pub fn lifting(&self) -> Option<&LiftingSetMetrics> {
+        match self {
+            SetMetrics::Lifting(m) => Some(m),
+            _ => None,
+        }
+    }
+
+    /// Working lifting volume (reps × kg, warm-ups excluded); endurance sets move no load
+    /// and count zero.
     pub fn volume(&self) -> f64 {
-        self.reps as f64 * self.weight_kg
+        self.lifting().map(LiftingSetMetrics::volume).unwrap_or(0.0)
+    }
+}
+
+/// All endurance schemas are still at their first version.
+fn parse_endurance<T: serde::de::DeserializeOwned>(
+    payload_ver: i32,
+    metrics: &str,
+) -> Result<T, String> {
+    match payload_ver {
+        1 => serde_json::from_str::<T>(metrics)
+            .map_err(|e| format!("Failed to parse set metrics (v1): {}", e)),
+        other => Err(format!("Unknown set metrics payload version {}", other)),
     }
 }
 
@@ -221,6 +712,10 @@ pub struct ExerciseDetail {
     pub name: String,
     pub category: String,
     pub default_rest_seconds: Option<i32>,
+    pub workout_type: String,
+    pub custom: bool,
+    pub archived: bool,
+    pub strength_lift: Option<String>,
     pub muscles: Vec<ExerciseMuscle>,
 }
 
@@ -229,7 +724,30 @@ pub struct ExerciseDetail {
 pub struct LoggedSet {
     pub id: i32,
     pub logged_at: String,
-    pub metrics: LiftingSetMetrics,
+    pub metrics: SetMetrics,
+    /// Record kinds this set beat all earlier sets of the exercise in (`[WO-038]`)
+    pub personal_records: Vec<RecordKind>,
+    /// Round of its superset or circuit, counted per block; `None` outside a group
+    /// (`[WO-046]`)
+    pub round: Option<i32>,
+    /// Per-set rest override, see [resolve_rest_seconds]
+    pub rest_seconds: Option<i32>,
+    /// Rest target after this set, resolved by precedence
+    pub rest_target_seconds: i32,
+    /// Rest taken until the next set of the session, without pauses (`[WO-049]`)
+    pub actual_rest_seconds: Option<i64>,
+}
+
+/// Targets an exercise was planned with, copied from the template the session started from.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ExercisePlan {
+    pub target_sets: i32,
+    pub target_reps_min: i32,
+    pub target_reps_max: i32,
+    pub target_weight_kg: Option<f64>,
+    pub target_rpe: Option<f32>,
+    pub rest_seconds: Option<i32>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -239,6 +757,13 @@ pub struct WorkoutExerciseView {
     pub exercise_id: i32,
     pub name: String,
     pub default_rest_seconds: Option<i32>,
+    pub position: i32,
+    pub group_id: Option<i32>,
+    pub plan: Option<ExercisePlan>,
+    /// Rest target resolved by precedence, see [resolve_rest_seconds]
+    pub rest_target_seconds: i32,
+    /// Mean rest actually taken after the sets that have one (`[WO-049]`)
+    pub average_rest_seconds: Option<i64>,
     pub sets: Vec<LoggedSet>,
 }
 
@@ -256,6 +781,9 @@ pub struct WorkoutDetail {
     pub session: WorkoutSession,
     pub exercises: Vec<WorkoutExerciseView>,
     pub pauses: Vec<WorkoutPauseView>,
+    /// Summary of an ended session, only filled in by the commands that show it:
+    /// `end_workout_session` and `list_workouts` (`[WO-050]`)
+    pub summary: Option<WorkoutSummary>,
 }
 
 // ============================================================================
@@ -270,6 +798,64 @@ fn parse_ts(s: &str) -> Result<chrono::DateTime<chrono::FixedOffset>, String> {
     chrono::DateTime::parse_from_rfc3339(s).map_err(|e| format!("Invalid timestamp '{}': {}", s, e))
 }
 
+/// Re-format a client timestamp as stored, so stored timestamps keep sorting by time.
+fn normalize_ts(s: &str) -> Result<String, String> {
+    Ok(parse_ts(s)?
+        .to_utc()
+        .to_rfc3339_opts(chrono::SecondsFormat::Millis, true))
+}
+
+/// Seconds of the pauses falling between `from` and `to`; a pause still open lasts
+/// until `to`.
+fn paused_seconds(
+    pauses: &[WorkoutPause],
+    from: chrono::DateTime<chrono::FixedOffset>,
+    to: chrono::DateTime<chrono::FixedOffset>,
+) -> Result<i64, String> {
+    let mut paused = 0;
+    for pause in pauses {
+        let paused_at = parse_ts(&pause.paused_at)?.max(from);
+        let resumed_at = match &pause.resumed_at {
+            Some(resumed_at) => parse_ts(resumed_at)?.min(to),
+            None => to,
+        };
+        if resumed_at > paused_at {
+            paused += (resumed_at - paused_at).num_seconds();
+        }
+    }
+    Ok(paused)
+}
+
+/// Start of a local calendar day as a stored timestamp, so tracker dates (YYYY-MM-DD) can
+/// bound session queries like `completed_in_range`.
+pub(crate) fn local_day_start(date: &chrono::NaiveDate) -> Result<String, String> {
+    date.and_hms_opt(0, 0, 0)
+        .and_then(|midnight| midnight.and_local_timezone(chrono::Local).earliest())
+        .map(|midnight| {
+            midnight
+                .to_utc()
+                .to_rfc3339_opts(chrono::SecondsFormat::Millis, true)
+        })
+        .ok_or_else(|| format!("Failed to resolve local midnight of {}", date))
+}
+
+// ============================================================================
+// REST TARGET
+// ============================================================================
+
+/// Resolve a rest target by precedence (`[WO-031]`): the plan the session was started
+/// from, then a per-set override, then the exercise's `default_rest_seconds`, then
+/// [DEFAULT_REST_SECONDS].
+pub fn resolve_rest_seconds(
+    plan: Option<i32>,
+    set_override: Option<i32>,
+    exercise_default: Option<i32>,
+) -> i32 {
+    plan.or(set_override)
+        .or(exercise_default)
+        .unwrap_or(DEFAULT_REST_SECONDS)
+}
+
 // ============================================================================
 // REPOSITORY
 // ============================================================================
@@ -279,6 +865,7 @@ impl WorkoutSession {
     pub fn active(conn: &mut SqliteConnection) -> QueryResult<Option<WorkoutSession>> {
         workout_session::table
             .filter(workout_session::ended_at.is_null())
+            .filter(workout_session::deleted_at.is_null())
             .order(workout_session::started_at.desc())
             .first::<Self>(conn)
             .optional()
@@ -323,10 +910,11 @@ impl WorkoutSession {
             .get_result(conn)
     }
 
-    /// Find a session by id.
+    /// Find a session by id, unless it is in the trash.
     pub fn find(conn: &mut SqliteConnection, id: i32) -> QueryResult<Self> {
         workout_session::table
             .filter(workout_session::id.eq(id))
+            .filter(workout_session::deleted_at.is_null())
             .first(conn)
     }
 
@@ -356,12 +944,72 @@ impl WorkoutSession {
     ) -> QueryResult<Vec<Self>> {
         workout_session::table
             .filter(workout_session::ended_at.is_not_null())
+            .filter(workout_session::deleted_at.is_null())
             .filter(workout_session::started_at.ge(from))
             .filter(workout_session::started_at.lt(to))
             .order(workout_session::started_at.desc())
             .load(conn)
     }
 
+    /// Active work time of a completed session: its duration minus the pauses, a pause
+    /// still open at the end lasting until then (`[WO-005]`). `None` while active.
+    pub fn active_seconds(
+        conn: &mut SqliteConnection,
+        session: &WorkoutSession,
+    ) -> Result<Option<i64>, String> {
+        let Some(ended_at) = &session.ended_at else {
+            return Ok(None);
+        };
+        let start = parse_ts(&session.started_at)?;
+        let end = parse_ts(ended_at)?;
+        let pauses = workout_pause::table
+            .filter(workout_pause::session_id.eq(session.id))
+            .load::<WorkoutPause>(conn)
+            .map_err(handle_error)?;
+        let paused = paused_seconds(&pauses, start, end)?;
+        Ok(Some(((end - start).num_seconds() - paused).max(0)))
+    }
+
+    /// Rest taken after each set: the time until the next set of the session was logged,
+    /// without pauses (`[WO-049]`). The last set has none, nor do sets logged at the same
+    /// time as the next, like those added to a past workout.
+    fn actual_rest_by_set(
+        conn: &mut SqliteConnection,
+        session_id: i32,
+        pauses: &[WorkoutPause],
+    ) -> Result<HashMap<i32, i64>, String> {
+        let logged: Vec<(i32, String)> = workout_set::table
+            .inner_join(workout_exercise::table)
+            .filter(workout_exercise::session_id.eq(session_id))
+            .order((workout_set::logged_at.asc(), workout_set::id.asc()))
+            .select((workout_set::id, workout_set::logged_at))
+            .load(conn)
+            .map_err(handle_error)?;
+
+        let mut rest = HashMap::new();
+        for pair in logged.windows(2) {
+            let from = parse_ts(&pair[0].1)?;
+            let to = parse_ts(&pair[1].1)?;
+            if to > from {
+                let seconds = (to - from).num_seconds() - paused_seconds(pauses, from, to)?;
+                rest.insert(pair[0].0, seconds.max(0));
+            }
+        }
+        Ok(rest)
+    }
+
+    /// Link the session to the template it was started from.
+    pub fn set_template(
+        conn: &mut SqliteConnection,
+        id: i32,
+        template_id: i32,
+    ) -> QueryResult<Self> {
+        diesel::update(workout_session::table.filter(workout_session::id.eq(id)))
+            .set(workout_session::template_id.eq(template_id))
+            .returning(Self::as_returning())
+            .get_result(conn)
+    }
+
     /// Record the end timestamp.
     pub fn end(conn: &mut SqliteConnection, id: i32, ended_at: &str) -> QueryResult<Self> {
         diesel::update(workout_session::table.filter(workout_session::id.eq(id)))
@@ -370,10 +1018,72 @@ impl WorkoutSession {
             .get_result(conn)
     }
 
-    /// Cascade-delete a session and all its exercises, sets, and pauses (`[WO-016]`).
+    /// Correct the name and timing of a session. A changed end is the user's own, so it
+    /// is no longer flagged as auto-completed.
+    pub fn update_timing(
+        conn: &mut SqliteConnection,
+        session: &WorkoutSession,
+        name: Option<String>,
+        started_at: &str,
+        ended_at: Option<&str>,
+    ) -> QueryResult<Self> {
+        let auto_completed = session.auto_completed && session.ended_at.as_deref() == ended_at;
+        diesel::update(workout_session::table.filter(workout_session::id.eq(session.id)))
+            .set((
+                workout_session::name.eq(name),
+                workout_session::started_at.eq(started_at),
+                workout_session::ended_at.eq(ended_at),
+                workout_session::auto_completed.eq(auto_completed),
+            ))
+            .returning(Self::as_returning())
+            .get_result(conn)
+    }
+
+    /// Record the end timestamp of a session ended by the stale check.
+    fn auto_complete(conn: &mut SqliteConnection, id: i32, ended_at: &str) -> QueryResult<Self> {
+        diesel::update(workout_session::table.filter(workout_session::id.eq(id)))
+            .set((
+                workout_session::ended_at.eq(ended_at),
+                workout_session::auto_completed.eq(true),
+            ))
+            .returning(Self::as_returning())
+            .get_result(conn)
+    }
+
+    /// The most recently started completed session with sets logged for the exercise
+    pub fn last_completed_with_exercise(
+        conn: &mut SqliteConnection,
+        exercise_id: i32,
+    ) -> QueryResult<Option<Self>> {
+        workout_session::table
+            .inner_join(workout_exercise::table.inner_join(workout_set::table))
+            .filter(workout_session::ended_at.is_not_null())
+            .filter(workout_session::deleted_at.is_null())
+            .filter(workout_exercise::exercise_id.eq(exercise_id))
+            .order((
+                workout_session::started_at.desc(),
+                workout_session::id.desc(),
+            ))
+            .select(Self::as_select())
+            .first(conn)
+            .optional()
+    }
+
+    /// Move a session with all its data to the trash (`[WO-016]`, `[HI-029]`).
+    pub fn discard(conn: &mut SqliteConnection, id: i32) -> QueryResult<usize> {
+        diesel::update(
+            workout_session::table
+                .filter(workout_session::id.eq(id))
+                .filter(workout_session::deleted_at.is_null()),
+        )
+        .set(workout_session::deleted_at.eq(now_ts()))
+        .execute(conn)
+    }
+
+    /// Cascade-delete a session and all its exercises, sets, and pauses (`[HI-031]`).
     /// The four deletes run in one transaction so a mid-cascade failure can't leave
     /// a half-deleted session (orphaned sets/pauses or a childless session row).
-    pub fn discard(conn: &mut SqliteConnection, id: i32) -> QueryResult<usize> {
+    pub fn purge(conn: &mut SqliteConnection, id: i32) -> QueryResult<usize> {
         conn.transaction(|conn| {
             let ex_ids: Vec<i32> = workout_exercise::table
                 .filter(workout_exercise::session_id.eq(id))
@@ -420,17 +1130,25 @@ impl WorkoutSession {
         Ok(last)
     }
 
-    /// Auto-complete the active session if it has been inactive past the threshold,
-    /// setting `ended_at` to the last activity time (`[WO-020]`).
+    /// Auto-complete the active session if it has been inactive past the configured
+    /// threshold, setting `ended_at` to the last activity time and flagging it
+    /// (`[WO-020]`, `[WO-052]`, `[WO-053]`). Protects trends from "zombie" sessions.
     pub fn auto_complete_if_stale(conn: &mut SqliteConnection) -> Result<(), String> {
+        let hours = match AppSettings::get(conn)
+            .map_err(handle_error)?
+            .stale_session_hours
+        {
+            Some(hours) => hours,
+            None => return Ok(()),
+        };
         let session = match Self::active(conn).map_err(handle_error)? {
             Some(s) => s,
             None => return Ok(()),
         };
         let last = Self::last_activity(conn, &session).map_err(handle_error)?;
         let elapsed = chrono::Utc::now().signed_duration_since(parse_ts(&last)?);
-        if elapsed.num_seconds() > STALE_AFTER_SECS {
-            Self::end(conn, session.id, &last).map_err(handle_error)?;
+        if elapsed.num_seconds() > i64::from(hours) * 60 * 60 {
+            Self::auto_complete(conn, session.id, &last).map_err(handle_error)?;
         }
         Ok(())
     }
@@ -440,11 +1158,13 @@ impl WorkoutSession {
         conn: &mut SqliteConnection,
         session: WorkoutSession,
     ) -> Result<WorkoutDetail, String> {
-        let wexs = workout_exercise::table
-            .filter(workout_exercise::session_id.eq(session.id))
-            .order(workout_exercise::id.asc())
-            .load::<WorkoutExercise>(conn)
+        let wexs = WorkoutExercise::for_session(conn, session.id).map_err(handle_error)?;
+        let pauses = workout_pause::table
+            .filter(workout_pause::session_id.eq(session.id))
+            .order(workout_pause::paused_at.asc())
+            .load::<WorkoutPause>(conn)
             .map_err(handle_error)?;
+        let mut actual_rest = Self::actual_rest_by_set(conn, session.id, &pauses)?;
 
         let mut exercises = Vec::with_capacity(wexs.len());
         for we in wexs {
@@ -454,29 +1174,51 @@ impl WorkoutSession {
                 .order(workout_set::id.asc())
                 .load::<WorkoutSet>(conn)
                 .map_err(handle_error)?;
+            let set_ids: Vec<i32> = raw_sets.iter().map(|s| s.id).collect();
+            let mut records = PersonalRecord::kinds_by_set(conn, &set_ids).map_err(handle_error)?;
             let mut sets = Vec::with_capacity(raw_sets.len());
-            for s in raw_sets {
-                let metrics = LiftingSetMetrics::from_stored(s.payload_ver, &s.metrics)?;
+            for (index, s) in raw_sets.into_iter().enumerate() {
+                let metrics =
+                    SetMetrics::from_stored(&session.workout_type, s.payload_ver, &s.metrics)?;
                 sets.push(LoggedSet {
                     id: s.id,
                     logged_at: s.logged_at,
                     metrics,
+                    personal_records: records.remove(&s.id).unwrap_or_default(),
+                    round: we.group_id.map(|_| index as i32 + 1),
+                    rest_seconds: s.rest_seconds,
+                    rest_target_seconds: resolve_rest_seconds(
+                        we.plan_rest_seconds,
+                        s.rest_seconds,
+                        ex.default_rest_seconds,
+                    ),
+                    actual_rest_seconds: actual_rest.remove(&s.id),
                 });
             }
+            let rests: Vec<i64> = sets.iter().filter_map(|s| s.actual_rest_seconds).collect();
+            let average_rest_seconds =
+                (!rests.is_empty()).then(|| rests.iter().sum::<i64>() / rests.len() as i64);
+            let plan = we.plan();
             exercises.push(WorkoutExerciseView {
                 id: we.id,
                 exercise_id: we.exercise_id,
                 name: ex.name,
                 default_rest_seconds: ex.default_rest_seconds,
+                position: we.position,
+                group_id: we.group_id,
+                // The countdown follows the latest set
+                rest_target_seconds: resolve_rest_seconds(
+                    we.plan_rest_seconds,
+                    sets.last().and_then(|s| s.rest_seconds),
+                    ex.default_rest_seconds,
+                ),
+                average_rest_seconds,
+                plan,
                 sets,
             });
         }
 
-        let pauses = workout_pause::table
-            .filter(workout_pause::session_id.eq(session.id))
-            .order(workout_pause::paused_at.asc())
-            .load::<WorkoutPause>(conn)
-            .map_err(handle_error)?
+        let pauses = pauses
             .into_iter()
             .map(|p| WorkoutPauseView {
                 paused_at: p.paused_at,
@@ -488,41 +1230,141 @@ impl WorkoutSession {
             session,
             exercises,
             pauses,
+            summary: None,
         })
     }
+
+    /// [Self::detail] with the summary of an ended session (`[WO-050]`)
+    pub fn summarized_detail(
+        conn: &mut SqliteConnection,
+        session: Self,
+    ) -> Result<WorkoutDetail, String> {
+        let mut detail = Self::detail(conn, session)?;
+        if detail.session.ended_at.is_some() {
+            detail.summary = Some(WorkoutSummary::build(
+                conn,
+                &detail.session,
+                &detail.exercises,
+            )?);
+        }
+        Ok(detail)
+    }
 }
 
 impl WorkoutExercise {
-    fn find_in_session(
-        conn: &mut SqliteConnection,
-        session_id: i32,
-        exercise_id: i32,
-    ) -> QueryResult<Option<Self>> {
-        workout_exercise::table
-            .filter(workout_exercise::session_id.eq(session_id))
-            .filter(workout_exercise::exercise_id.eq(exercise_id))
-            .first::<Self>(conn)
-            .optional()
+    /// The plan snapshot, if this exercise was pre-created from a template
+    pub fn plan(&self) -> Option<ExercisePlan> {
+        Some(ExercisePlan {
+            target_sets: self.target_sets?,
+            target_reps_min: self.target_reps_min?,
+            target_reps_max: self.target_reps_max?,
+            target_weight_kg: self.target_weight_kg,
+            target_rpe: self.target_rpe,
+            rest_seconds: self.plan_rest_seconds,
+        })
     }
 
-    /// Find the exercise within the session, or append it (id carries order).
-    pub fn add_or_get(
-        conn: &mut SqliteConnection,
+    pub fn find(conn: &mut SqliteConnection, id: i32) -> QueryResult<Self> {
+        workout_exercise::table
+            .find(id)
+            .select(Self::as_select())
+            .first(conn)
+    }
+
+    /// The exercises of a session in position order
+    pub fn for_session(conn: &mut SqliteConnection, session_id: i32) -> QueryResult<Vec<Self>> {
+        workout_exercise::table
+            .filter(workout_exercise::session_id.eq(session_id))
+            .order((workout_exercise::position.asc(), workout_exercise::id.asc()))
+            .load(conn)
+    }
+
+    /// The last block of an exercise within the session
+    fn last_in_session(
+        conn: &mut SqliteConnection,
+        session_id: i32,
+        exercise_id: i32,
+    ) -> QueryResult<Option<Self>> {
+        workout_exercise::table
+            .filter(workout_exercise::session_id.eq(session_id))
+            .filter(workout_exercise::exercise_id.eq(exercise_id))
+            .order((
+                workout_exercise::position.desc(),
+                workout_exercise::id.desc(),
+            ))
+            .first::<Self>(conn)
+            .optional()
+    }
+
+    /// Find the exercise's last block within the session, or append it.
+    pub fn add_or_get(
+        conn: &mut SqliteConnection,
         session_id: i32,
         exercise_id: i32,
     ) -> QueryResult<Self> {
-        if let Some(existing) = Self::find_in_session(conn, session_id, exercise_id)? {
+        if let Some(existing) = Self::last_in_session(conn, session_id, exercise_id)? {
             return Ok(existing);
         }
         let new = NewWorkoutExercise {
             session_id,
             exercise_id,
+            ..Default::default()
         };
+        Self::create(conn, &new)
+    }
+
+    /// Distinct exercises logged in a session
+    pub fn exercise_ids(conn: &mut SqliteConnection, session_id: i32) -> QueryResult<Vec<i32>> {
+        workout_exercise::table
+            .filter(workout_exercise::session_id.eq(session_id))
+            .select(workout_exercise::exercise_id)
+            .distinct()
+            .load(conn)
+    }
+
+    /// Append an exercise to a session, after its last block.
+    pub fn create(conn: &mut SqliteConnection, new: &NewWorkoutExercise) -> QueryResult<Self> {
+        let last: Option<i32> = workout_exercise::table
+            .filter(workout_exercise::session_id.eq(new.session_id))
+            .select(diesel::dsl::max(workout_exercise::position))
+            .first(conn)?;
         diesel::insert_into(workout_exercise::table)
-            .values(&new)
+            .values((
+                new,
+                workout_exercise::position.eq(last.map_or(0, |position| position + 1)),
+            ))
             .returning(Self::as_returning())
             .get_result(conn)
     }
+
+    /// Give the blocks the positions of their order in `ids`.
+    pub fn reorder(conn: &mut SqliteConnection, ids: &[i32]) -> QueryResult<()> {
+        for (position, id) in ids.iter().enumerate() {
+            diesel::update(workout_exercise::table.find(*id))
+                .set(workout_exercise::position.eq(position as i32))
+                .execute(conn)?;
+        }
+        Ok(())
+    }
+
+    /// An unused group id within the session
+    pub fn next_group_id(conn: &mut SqliteConnection, session_id: i32) -> QueryResult<i32> {
+        let last: Option<i32> = workout_exercise::table
+            .filter(workout_exercise::session_id.eq(session_id))
+            .select(diesel::dsl::max(workout_exercise::group_id))
+            .first(conn)?;
+        Ok(last.map_or(1, |group_id| group_id + 1))
+    }
+
+    pub fn set_group(
+        conn: &mut SqliteConnection,
+        ids: &[i32],
+        group_id: Option<i32>,
+    ) -> QueryResult<usize> {
+        diesel::update(workout_exercise::table.filter(workout_exercise::id.eq_any(ids)))
+            .set(workout_exercise::group_id.eq(group_id))
+            .execute(conn)
+    }
 }
 
 impl WorkoutSet {
@@ -546,17 +1388,74 @@ impl WorkoutSet {
             .get_result(conn)
     }
 
+    /// Replace a set's payload; it is re-written in the current schema version.
     pub fn update_metrics(
         conn: &mut SqliteConnection,
         id: i32,
         metrics_json: String,
+        payload_ver: i32,
+    ) -> QueryResult<Self> {
+        diesel::update(workout_set::table.filter(workout_set::id.eq(id)))
+            .set((
+                workout_set::metrics.eq(metrics_json),
+                workout_set::payload_ver.eq(payload_ver),
+            ))
+            .returning(Self::as_returning())
+            .get_result(conn)
+    }
+
+    /// Set or clear the rest target after a set.
+    pub fn update_rest(
+        conn: &mut SqliteConnection,
+        id: i32,
+        rest_seconds: Option<i32>,
     ) -> QueryResult<Self> {
         diesel::update(workout_set::table.filter(workout_set::id.eq(id)))
-            .set(workout_set::metrics.eq(metrics_json))
+            .set(workout_set::rest_seconds.eq(rest_seconds))
             .returning(Self::as_returning())
             .get_result(conn)
     }
 
+    /// Move a set to another time.
+    pub fn update_logged_at(
+        conn: &mut SqliteConnection,
+        id: i32,
+        logged_at: &str,
+    ) -> QueryResult<Self> {
+        diesel::update(workout_set::table.filter(workout_set::id.eq(id)))
+            .set(workout_set::logged_at.eq(logged_at))
+            .returning(Self::as_returning())
+            .get_result(conn)
+    }
+
+    /// When the last set of a block was logged
+    pub fn last_logged_at(
+        conn: &mut SqliteConnection,
+        workout_exercise_id: i32,
+    ) -> QueryResult<Option<String>> {
+        workout_set::table
+            .filter(workout_set::workout_exercise_id.eq(workout_exercise_id))
+            .select(diesel::dsl::max(workout_set::logged_at))
+            .first(conn)
+    }
+
+    /// Find a set by id, e.g. to journal it before it is deleted.
+    pub fn find(conn: &mut SqliteConnection, id: i32) -> QueryResult<Self> {
+        workout_set::table
+            .find(id)
+            .select(Self::as_select())
+            .first(conn)
+    }
+
+    /// The exercise a set was logged for
+    pub fn exercise_id(conn: &mut SqliteConnection, id: i32) -> QueryResult<i32> {
+        workout_set::table
+            .inner_join(workout_exercise::table)
+            .filter(workout_set::id.eq(id))
+            .select(workout_exercise::exercise_id)
+            .first(conn)
+    }
+
     pub fn delete(conn: &mut SqliteConnection, id: i32) -> QueryResult<usize> {
         diesel::delete(workout_set::table.filter(workout_set::id.eq(id))).execute(conn)
     }
@@ -580,6 +1479,20 @@ impl WorkoutPause {
             .get_result(conn)
     }
 
+    /// Replace all pauses of a session.
+    pub fn replace(
+        conn: &mut SqliteConnection,
+        session_id: i32,
+        pauses: &[NewWorkoutPause],
+    ) -> QueryResult<()> {
+        diesel::delete(workout_pause::table.filter(workout_pause::session_id.eq(session_id)))
+            .execute(conn)?;
+        diesel::insert_into(workout_pause::table)
+            .values(pauses)
+            .execute(conn)?;
+        Ok(())
+    }
+
     /// Close the currently-open pause (resumed_at IS NULL) for the session.
     pub fn close_open(
         conn: &mut SqliteConnection,
@@ -596,31 +1509,146 @@ impl WorkoutPause {
     }
 }
 
+impl WorkoutType {
+    pub fn all(conn: &mut SqliteConnection) -> QueryResult<Vec<Self>> {
+        workout_type::table.load::<Self>(conn)
+    }
+
+    pub fn find_by_key(conn: &mut SqliteConnection, key: &str) -> QueryResult<Self> {
+        workout_type::table
+            .filter(workout_type::shortvalue.eq(key))
+            .first(conn)
+    }
+}
+
 impl Exercise {
     pub fn find(conn: &mut SqliteConnection, id: i32) -> QueryResult<Exercise> {
         exercise::table.filter(exercise::id.eq(id)).first(conn)
     }
 
-    /// The seeded exercise library with each exercise's muscles (`[WO-012]`, `[WO-013]`).
-    pub fn library(conn: &mut SqliteConnection) -> QueryResult<Vec<ExerciseDetail>> {
-        let exercises = exercise::table
-            .order(exercise::name.asc())
-            .load::<Exercise>(conn)?;
-        let mut out = Vec::with_capacity(exercises.len());
-        for e in exercises {
-            let muscles = exercise_muscle::table
-                .filter(exercise_muscle::exercise_id.eq(e.id))
-                .load::<ExerciseMuscle>(conn)?;
-            out.push(ExerciseDetail {
-                id: e.id,
-                name: e.name,
-                category: e.category,
-                default_rest_seconds: e.default_rest_seconds,
-                muscles,
+    /// The exercise library of a workout type, seeded and custom exercises without the
+    /// archived ones, with each exercise's muscles (`[WO-012]`, `[WO-013]`, `[WO-032]`).
+    pub fn library(
+        conn: &mut SqliteConnection,
+        workout_type: &str,
+    ) -> QueryResult<Vec<ExerciseDetail>> {
+        Self::search(
+            conn,
+            &ExerciseSearch {
+                workout_type: Some(workout_type.to_string()),
+                ..Default::default()
+            },
+        )
+    }
+
+    /// The library exercises matching the filters, with their muscles (`[WO-056]`,
+    /// `[WO-057]`). Usage counts the workouts outside the trash an exercise was logged in.
+    pub fn search(
+        conn: &mut SqliteConnection,
+        search: &ExerciseSearch,
+    ) -> QueryResult<Vec<ExerciseDetail>> {
+        let mut matching = exercise::table
+            .filter(exercise::workout_type.eq(search.workout_type.as_deref().unwrap_or("wl")))
+            .filter(exercise::archived.eq(false))
+            .select(exercise::id)
+            .into_boxed();
+        if let Some(name) = search.name.as_deref().map(str::trim) {
+            let escaped = name
+                .replace('\\', "\\\\")
+                .replace('%', "\\%")
+                .replace('_', "\\_");
+            matching = matching.filter(exercise::name.like(format!("%{}%", escaped)).escape('\\'));
+        }
+        if let Some(category) = &search.category {
+            matching = matching.filter(exercise::category.eq(category));
+        }
+        if search.muscle.is_some() || search.role.is_some() {
+            let mut worked = exercise_muscle::table
+                .select(exercise_muscle::exercise_id)
+                .into_boxed();
+            if let Some(muscle) = &search.muscle {
+                worked = worked.filter(exercise_muscle::muscle.eq(muscle));
+            }
+            if let Some(role) = &search.role {
+                worked = worked.filter(exercise_muscle::role.eq(role));
+            }
+            matching = matching.filter(exercise::id.eq_any(worked));
+        }
+
+        // One joined query for the exercises and their muscles
+        let rows: Vec<(Exercise, Option<ExerciseMuscle>)> = exercise::table
+            .left_join(exercise_muscle::table)
+            .filter(exercise::id.eq_any(matching))
+            .order((
+                exercise::name.asc(),
+                exercise::id.asc(),
+                exercise_muscle::muscle.asc(),
+            ))
+            .select((Exercise::as_select(), Option::<ExerciseMuscle>::as_select()))
+            .load(conn)?;
+        let mut out: Vec<ExerciseDetail> = Vec::new();
+        for (exercise, muscle) in rows {
+            if out.last().map(|detail| detail.id) != Some(exercise.id) {
+                out.push(exercise.into_detail(Vec::new()));
+            }
+            if let (Some(detail), Some(muscle)) = (out.last_mut(), muscle) {
+                detail.muscles.push(muscle);
+            }
+        }
+
+        if search.order != ExerciseOrder::Name {
+            let ids: Vec<i32> = out.iter().map(|detail| detail.id).collect();
+            // exercise -> (workouts, last started)
+            let usage: HashMap<i32, (i64, Option<String>)> = workout_exercise::table
+                .inner_join(workout_session::table)
+                .filter(workout_session::deleted_at.is_null())
+                .filter(workout_exercise::exercise_id.eq_any(&ids))
+                .group_by(workout_exercise::exercise_id)
+                .select((
+                    workout_exercise::exercise_id,
+                    diesel::dsl::count(workout_exercise::session_id).aggregate_distinct(),
+                    diesel::dsl::max(workout_session::started_at),
+                ))
+                .load::<(i32, i64, Option<String>)>(conn)?
+                .into_iter()
+                .map(|(id, workouts, last)| (id, (workouts, last)))
+                .collect();
+            let never = (0, None);
+            // Stable sorts keep the name order among equals
+            out.sort_by(|a, b| {
+                let (a_count, a_last) = usage.get(&a.id).unwrap_or(&never);
+                let (b_count, b_last) = usage.get(&b.id).unwrap_or(&never);
+                match search.order {
+                    ExerciseOrder::MostUsed => b_count.cmp(a_count).then(b_last.cmp(a_last)),
+                    _ => b_last.cmp(a_last),
+                }
             });
         }
         Ok(out)
     }
+
+    /// The exercise with its muscles
+    pub fn with_muscles(self, conn: &mut SqliteConnection) -> QueryResult<ExerciseDetail> {
+        let muscles = exercise_muscle::table
+            .filter(exercise_muscle::exercise_id.eq(self.id))
+            .order(exercise_muscle::muscle.asc())
+            .load::<ExerciseMuscle>(conn)?;
+        Ok(self.into_detail(muscles))
+    }
+
+    fn into_detail(self, muscles: Vec<ExerciseMuscle>) -> ExerciseDetail {
+        ExerciseDetail {
+            id: self.id,
+            name: self.name,
+            category: self.category,
+            default_rest_seconds: self.default_rest_seconds,
+            workout_type: self.workout_type,
+            custom: self.custom,
+            archived: self.archived,
+            strength_lift: self.strength_lift,
+            muscles,
+        }
+    }
 }
 
 // ============================================================================
@@ -638,6 +1666,240 @@ fn require_active(conn: &mut SqliteConnection) -> Result<WorkoutSession, String>
         .ok_or_else(|| "No active workout session".to_string())
 }
 
+fn start_session(
+    conn: &mut SqliteConnection,
+    workout_type: &str,
+    name: Option<String>,
+) -> Result<WorkoutDetail, String> {
+    if WorkoutSession::active(conn)
+        .map_err(handle_error)?
+        .is_some()
+    {
+        return Err("A workout session is already active".to_string());
+    }
+    let session = WorkoutSession::start(conn, workout_type, name).map_err(handle_error)?;
+    WorkoutSession::detail(conn, session)
+}
+
+/// Metrics must use the schema of the session's workout type (`[WO-032]`).
+fn require_matching_type(session: &WorkoutSession, metrics: &SetMetrics) -> Result<(), String> {
+    if metrics.workout_type() != session.workout_type {
+        return Err(format!(
+            "Set metrics of type '{}' do not fit a '{}' workout",
+            metrics.workout_type(),
+            session.workout_type
+        ));
+    }
+    Ok(())
+}
+
+/// Rebuild the personal records of exercises whose sets changed; only weight-lifting
+/// sets carry records (`[WO-038]`, `[WO-039]`).
+pub(crate) fn refresh_records(
+    conn: &mut SqliteConnection,
+    session: &WorkoutSession,
+    exercise_ids: &[i32],
+) -> Result<(), String> {
+    if session.workout_type != "wl" {
+        return Ok(());
+    }
+    for exercise_id in exercise_ids {
+        PersonalRecord::refresh(conn, *exercise_id)?;
+    }
+    Ok(())
+}
+
+/// The exercise must not be archived and must belong to the session's workout type.
+fn require_loggable(
+    conn: &mut SqliteConnection,
+    session: &WorkoutSession,
+    exercise_id: i32,
+) -> Result<Exercise, String> {
+    let exercise = Exercise::find(conn, exercise_id).map_err(handle_error)?;
+    if exercise.archived {
+        return Err(format!("{} is archived", exercise.name));
+    }
+    if exercise.workout_type != session.workout_type {
+        return Err(format!(
+            "{} cannot be logged in a '{}' workout",
+            exercise.name, session.workout_type
+        ));
+    }
+    Ok(exercise)
+}
+
+/// Blocks of a superset or circuit must follow each other in `order` (`[WO-046]`).
+fn require_groups_together(blocks: &[WorkoutExercise], order: &[i32]) -> Result<(), String> {
+    let mut finished = std::collections::HashSet::new();
+    let mut current = None;
+    for id in order {
+        let group_id = blocks
+            .iter()
+            .find(|block| block.id == *id)
+            .and_then(|block| block.group_id);
+        if group_id != current {
+            if let Some(done) = current {
+                finished.insert(done);
+            }
+            if group_id.is_some_and(|group_id| finished.contains(&group_id)) {
+                return Err("Exercises of a superset must stay together".to_string());
+            }
+            current = group_id;
+        }
+    }
+    Ok(())
+}
+
+/// The sets of a session logged before `from` or after `to`, named by exercise and number
+/// within their block, e.g. "Bench Press set 2" (`[WO-054]`)
+fn sets_outside(detail: &WorkoutDetail, from: &str, to: Option<&str>) -> Vec<String> {
+    let mut outside = Vec::new();
+    for exercise in &detail.exercises {
+        for (index, set) in exercise.sets.iter().enumerate() {
+            let logged_at = set.logged_at.as_str();
+            if logged_at < from || to.is_some_and(|to| logged_at > to) {
+                outside.push(format!("{} set {}", exercise.name, index + 1));
+            }
+        }
+    }
+    outside
+}
+
+/// Pauses as stored, in order. They must lie within `[started_at, until]` without
+/// overlapping; only the last one may be open, and only while the session is active
+/// (`[WO-054]`).
+fn validated_pauses(
+    session_id: i32,
+    pauses: &[WorkoutPauseInput],
+    started_at: &str,
+    until: &str,
+    active: bool,
+) -> Result<Vec<NewWorkoutPause>, String> {
+    let mut validated = Vec::with_capacity(pauses.len());
+    for pause in pauses {
+        validated.push(NewWorkoutPause {
+            session_id,
+            paused_at: normalize_ts(&pause.paused_at)?,
+            resumed_at: pause.resumed_at.as_deref().map(normalize_ts).transpose()?,
+        });
+    }
+    validated.sort_by(|a, b| a.paused_at.cmp(&b.paused_at));
+
+    let mut previous_end = started_at.to_string();
+    for (index, pause) in validated.iter().enumerate() {
+        if pause.paused_at.as_str() > until {
+            return Err("Pauses must lie within the workout".to_string());
+        }
+        if pause.paused_at.as_str() < previous_end.as_str() {
+            return Err(if index == 0 {
+                "Pauses must lie within the workout".to_string()
+            } else {
+                "Pauses must not overlap".to_string()
+            });
+        }
+        match &pause.resumed_at {
+            Some(resumed_at) if resumed_at <= &pause.paused_at => {
+                return Err("A pause must end after it starts".to_string());
+            }
+            Some(resumed_at) if resumed_at.as_str() > until => {
+                return Err("Pauses must lie within the workout".to_string());
+            }
+            Some(resumed_at) => previous_end = resumed_at.clone(),
+            None if !active || index + 1 < validated.len() => {
+                return Err("Only the last pause of an active workout can be open".to_string());
+            }
+            None => {}
+        }
+    }
+    Ok(validated)
+}
+
+/// Validate and log a set under an exercise of the session (added to the workout if new).
+fn log_set(
+    conn: &mut SqliteConnection,
+    session: &WorkoutSession,
+    exercise_id: i32,
+    metrics: &SetMetrics,
+    logged_at: String,
+) -> Result<(), String> {
+    if let Err(e) = metrics.validate() {
+        return Err(format!("Validation failed: {:?}", e));
+    }
+    require_matching_type(session, metrics)?;
+    require_loggable(conn, session, exercise_id)?;
+    let json = metrics.to_stored()?;
+    // Adding the exercise (if new) and logging the set are one unit: a failed set
+    // insert must not strand a childless workout_exercise row.
+    conn.transaction(|conn| {
+        let we = WorkoutExercise::add_or_get(conn, session.id, exercise_id)?;
+        WorkoutSet::log(conn, we.id, json, metrics.payload_ver(), logged_at)
+    })
+    .map_err(handle_error)?;
+    refresh_records(conn, session, &[exercise_id])
+}
+
+/// A set must be logged between the start of its session and its end, or now while the
+/// session is active.
+fn require_within_session(session: &WorkoutSession, logged_at: &str) -> Result<(), String> {
+    let until = session.ended_at.clone().unwrap_or_else(now_ts);
+    if logged_at < normalize_ts(&session.started_at)?.as_str()
+        || logged_at > normalize_ts(&until)?.as_str()
+    {
+        return Err("A set must be logged within its workout".to_string());
+    }
+    Ok(())
+}
+
+/// When a set added to a session is logged: at the given time, which must lie within the
+/// session, or else now while it is active. Sets added to a completed session follow the
+/// last set of their block by `ADDED_SET_SPACING_SECONDS` (up to its end), so they keep
+/// their order and the rest between them (`[WO-055]`).
+fn added_set_logged_at(
+    conn: &mut SqliteConnection,
+    session: &WorkoutSession,
+    workout_exercise_id: Option<i32>,
+    logged_at: Option<String>,
+) -> Result<String, String> {
+    if let Some(logged_at) = logged_at {
+        let logged_at = normalize_ts(&logged_at)?;
+        require_within_session(session, &logged_at)?;
+        return Ok(logged_at);
+    }
+    let Some(ended_at) = &session.ended_at else {
+        return Ok(now_ts());
+    };
+    let last = match workout_exercise_id {
+        Some(id) => WorkoutSet::last_logged_at(conn, id).map_err(handle_error)?,
+        None => None,
+    };
+    let Some(last) = last else {
+        return Ok(session.started_at.clone());
+    };
+    let next = (parse_ts(&last)? + chrono::Duration::seconds(ADDED_SET_SPACING_SECONDS))
+        .min(parse_ts(ended_at)?);
+    Ok(next
+        .to_utc()
+        .to_rfc3339_opts(chrono::SecondsFormat::Millis, true))
+}
+
+/// Validate and replace a set's metrics; returns the owning session.
+fn update_set(
+    conn: &mut SqliteConnection,
+    set_id: i32,
+    metrics: &SetMetrics,
+) -> Result<WorkoutSession, String> {
+    if let Err(e) = metrics.validate() {
+        return Err(format!("Validation failed: {:?}", e));
+    }
+    let session = WorkoutSession::for_set(conn, set_id).map_err(handle_error)?;
+    require_matching_type(&session, metrics)?;
+    let json = metrics.to_stored()?;
+    WorkoutSet::update_metrics(conn, set_id, json, metrics.payload_ver()).map_err(handle_error)?;
+    let exercise_id = WorkoutSet::exercise_id(conn, set_id).map_err(handle_error)?;
+    refresh_records(conn, &session, &[exercise_id])?;
+    Ok(session)
+}
+
 /// Start a weight-lifting session. Refuses if one is already active (`[WO-001]`, `[WO-002]`).
 #[command]
 pub fn start_workout_session(
@@ -645,14 +1907,23 @@ pub fn start_workout_session(
     name: Option<String>,
 ) -> Result<WorkoutDetail, String> {
     let mut conn = conn_from(&pool)?;
-    if WorkoutSession::active(&mut conn)
+    start_session(&mut conn, "wl", name)
+}
+
+/// Start a session of any seeded workout type, e.g. `run` (`[WO-032]`). Refuses if one is
+/// already active (`[WO-002]`).
+#[command]
+pub fn start_activity_session(
+    pool: State<DbPool>,
+    workout_type: String,
+    name: Option<String>,
+) -> Result<WorkoutDetail, String> {
+    let mut conn = conn_from(&pool)?;
+    WorkoutType::find_by_key(&mut conn, &workout_type)
+        .optional()
         .map_err(handle_error)?
-        .is_some()
-    {
-        return Err("A workout session is already active".to_string());
-    }
-    let session = WorkoutSession::start(&mut conn, "wl", name).map_err(handle_error)?;
-    WorkoutSession::detail(&mut conn, session)
+        .ok_or_else(|| format!("Unknown workout type '{}'", workout_type))?;
+    start_session(&mut conn, &workout_type, name)
 }
 
 /// Log a set under an exercise (added to the workout if new). Validates metrics
@@ -663,19 +1934,29 @@ pub fn log_workout_set(
     exercise_id: i32,
     metrics: LiftingSetMetrics,
 ) -> Result<WorkoutDetail, String> {
-    if let Err(e) = metrics.validate() {
-        return Err(format!("Validation failed: {:?}", e));
-    }
     let mut conn = conn_from(&pool)?;
     let session = require_active(&mut conn)?;
-    let json = serde_json::to_string(&metrics).map_err(|e| format!("Serialize failed: {}", e))?;
-    // Adding the exercise (if new) and logging the set are one unit: a failed set
-    // insert must not strand a childless workout_exercise row.
-    conn.transaction(|conn| {
-        let we = WorkoutExercise::add_or_get(conn, session.id, exercise_id)?;
-        WorkoutSet::log(conn, we.id, json, WL_PAYLOAD_VER, now_ts())
-    })
-    .map_err(handle_error)?;
+    log_set(
+        &mut conn,
+        &session,
+        exercise_id,
+        &SetMetrics::Lifting(metrics),
+        now_ts(),
+    )?;
+    WorkoutSession::detail(&mut conn, session)
+}
+
+/// Log a set of any workout type into the active session. The metrics' type must match
+/// the session's, and the exercise must belong to it (`[WO-032]`, `[WO-033]`).
+#[command]
+pub fn log_activity_set(
+    pool: State<DbPool>,
+    exercise_id: i32,
+    metrics: SetMetrics,
+) -> Result<WorkoutDetail, String> {
+    let mut conn = conn_from(&pool)?;
+    let session = require_active(&mut conn)?;
+    log_set(&mut conn, &session, exercise_id, &metrics, now_ts())?;
     WorkoutSession::detail(&mut conn, session)
 }
 
@@ -688,13 +1969,55 @@ pub fn update_workout_set(
     set_id: i32,
     metrics: LiftingSetMetrics,
 ) -> Result<WorkoutDetail, String> {
-    if let Err(e) = metrics.validate() {
-        return Err(format!("Validation failed: {:?}", e));
+    let mut conn = conn_from(&pool)?;
+    let session = update_set(&mut conn, set_id, &SetMetrics::Lifting(metrics))?;
+    WorkoutSession::detail(&mut conn, session)
+}
+
+/// Edit a logged set of any workout type (`[WO-032]`).
+#[command]
+pub fn update_activity_set(
+    pool: State<DbPool>,
+    set_id: i32,
+    metrics: SetMetrics,
+) -> Result<WorkoutDetail, String> {
+    let mut conn = conn_from(&pool)?;
+    let session = update_set(&mut conn, set_id, &metrics)?;
+    WorkoutSession::detail(&mut conn, session)
+}
+
+/// Set or clear the rest target after a logged set, from 0 to 3600 seconds (`[WO-048]`).
+#[command]
+pub fn update_workout_set_rest(
+    pool: State<DbPool>,
+    set_id: i32,
+    rest_seconds: Option<i32>,
+) -> Result<WorkoutDetail, String> {
+    if rest_seconds.is_some_and(|rest| !(0..=3600).contains(&rest)) {
+        return Err("Rest must be between 0 and 3600 seconds".to_string());
     }
     let mut conn = conn_from(&pool)?;
-    let json = serde_json::to_string(&metrics).map_err(|e| format!("Serialize failed: {}", e))?;
-    WorkoutSet::update_metrics(&mut conn, set_id, json).map_err(handle_error)?;
     let session = WorkoutSession::for_set(&mut conn, set_id).map_err(handle_error)?;
+    WorkoutSet::update_rest(&mut conn, set_id, rest_seconds).map_err(handle_error)?;
+    WorkoutSession::detail(&mut conn, session)
+}
+
+/// Move a logged set to another time within its session (`[WO-055]`). Sets added to a
+/// past workout are logged at its start, so this spreads them out again; rest and
+/// personal records follow the new order.
+#[command]
+pub fn update_workout_set_logged_at(
+    pool: State<DbPool>,
+    set_id: i32,
+    logged_at: String,
+) -> Result<WorkoutDetail, String> {
+    let logged_at = normalize_ts(&logged_at)?;
+    let mut conn = conn_from(&pool)?;
+    let session = WorkoutSession::for_set(&mut conn, set_id).map_err(handle_error)?;
+    require_within_session(&session, &logged_at)?;
+    WorkoutSet::update_logged_at(&mut conn, set_id, &logged_at).map_err(handle_error)?;
+    let exercise_id = WorkoutSet::exercise_id(&mut conn, set_id).map_err(handle_error)?;
+    refresh_records(&mut conn, &session, &[exercise_id])?;
     WorkoutSession::detail(&mut conn, session)
 }
 
@@ -704,38 +2027,227 @@ pub fn update_workout_set(
 pub fn delete_workout_set(pool: State<DbPool>, set_id: i32) -> Result<WorkoutDetail, String> {
     let mut conn = conn_from(&pool)?;
     let session = WorkoutSession::for_set(&mut conn, set_id).map_err(handle_error)?;
-    WorkoutSet::delete(&mut conn, set_id).map_err(handle_error)?;
+    let exercise_id = WorkoutSet::exercise_id(&mut conn, set_id).map_err(handle_error)?;
+    conn.transaction(|conn| {
+        let set = WorkoutSet::find(conn, set_id)?;
+        JournalEntry::record(
+            conn,
+            Operation::DeleteWorkoutSet,
+            &RemovedRows::WorkoutSet { set },
+        )?;
+        WorkoutSet::delete(conn, set_id)
+    })
+    .map_err(handle_error)?;
+    refresh_records(&mut conn, &session, &[exercise_id])?;
     WorkoutSession::detail(&mut conn, session)
 }
 
 /// Add a set to a specific session (active or completed). Powers history/flat-CRUD where
-/// there is no active session; `logged_at` is anchored to the session's start so the
-/// set lands on the workout's date. Validates metrics before persisting (`[HI-020]`,
-/// `[HI-022]`).
+/// there is no active session; see `added_set_logged_at` for when the set is logged.
+/// Validates metrics before persisting (`[HI-020]`, `[HI-022]`, `[WO-055]`).
 #[command]
 pub fn add_workout_set(
     pool: State<DbPool>,
     session_id: i32,
     exercise_id: i32,
     metrics: LiftingSetMetrics,
+    logged_at: Option<String>,
+) -> Result<WorkoutDetail, String> {
+    let mut conn = conn_from(&pool)?;
+    let session = WorkoutSession::find(&mut conn, session_id).map_err(handle_error)?;
+    let block = WorkoutExercise::last_in_session(&mut conn, session_id, exercise_id)
+        .map_err(handle_error)?;
+    let logged_at = added_set_logged_at(&mut conn, &session, block.map(|b| b.id), logged_at)?;
+    log_set(
+        &mut conn,
+        &session,
+        exercise_id,
+        &SetMetrics::Lifting(metrics),
+        logged_at,
+    )?;
+    WorkoutSession::detail(&mut conn, session)
+}
+
+/// Append another block of an exercise to a session, e.g. a back-off block after the
+/// heavy one. Works on completed sessions too (`[WO-045]`).
+#[command]
+pub fn add_workout_exercise(
+    pool: State<DbPool>,
+    session_id: i32,
+    exercise_id: i32,
+) -> Result<WorkoutDetail, String> {
+    let mut conn = conn_from(&pool)?;
+    let session = WorkoutSession::find(&mut conn, session_id).map_err(handle_error)?;
+    require_loggable(&mut conn, &session, exercise_id)?;
+    WorkoutExercise::create(
+        &mut conn,
+        &NewWorkoutExercise {
+            session_id,
+            exercise_id,
+            ..Default::default()
+        },
+    )
+    .map_err(handle_error)?;
+    WorkoutSession::detail(&mut conn, session)
+}
+
+/// Log a set into a given block of a session (`[WO-045]`), at the time given or as
+/// `add_workout_set` would log it.
+#[command]
+pub fn log_workout_block_set(
+    pool: State<DbPool>,
+    workout_exercise_id: i32,
+    metrics: SetMetrics,
+    logged_at: Option<String>,
 ) -> Result<WorkoutDetail, String> {
+    let mut conn = conn_from(&pool)?;
+    let block = WorkoutExercise::find(&mut conn, workout_exercise_id).map_err(handle_error)?;
+    let session = WorkoutSession::find(&mut conn, block.session_id).map_err(handle_error)?;
     if let Err(e) = metrics.validate() {
         return Err(format!("Validation failed: {:?}", e));
     }
+    require_matching_type(&session, &metrics)?;
+    require_loggable(&mut conn, &session, block.exercise_id)?;
+    let logged_at = added_set_logged_at(&mut conn, &session, Some(block.id), logged_at)?;
+    let json = metrics.to_stored()?;
+    WorkoutSet::log(&mut conn, block.id, json, metrics.payload_ver(), logged_at)
+        .map_err(handle_error)?;
+    refresh_records(&mut conn, &session, &[block.exercise_id])?;
+    WorkoutSession::detail(&mut conn, session)
+}
+
+/// Put the exercises of a session in the given order. Every block must be listed once and
+/// supersets stay together (`[WO-045]`).
+#[command]
+pub fn reorder_workout_exercises(
+    pool: State<DbPool>,
+    session_id: i32,
+    workout_exercise_ids: Vec<i32>,
+) -> Result<WorkoutDetail, String> {
+    log::debug!(
+        ">>> reorder_workout_exercises session_id={} order={:?}",
+        session_id,
+        workout_exercise_ids
+    );
     let mut conn = conn_from(&pool)?;
     let session = WorkoutSession::find(&mut conn, session_id).map_err(handle_error)?;
-    let json = serde_json::to_string(&metrics).map_err(|e| format!("Serialize failed: {}", e))?;
-    let logged_at = session.started_at.clone();
+    let blocks = WorkoutExercise::for_session(&mut conn, session_id).map_err(handle_error)?;
+
+    let mut given = workout_exercise_ids.clone();
+    given.sort_unstable();
+    let mut existing: Vec<i32> = blocks.iter().map(|block| block.id).collect();
+    existing.sort_unstable();
+    if given != existing {
+        return Err("The order must list every exercise of the workout once".to_string());
+    }
+    require_groups_together(&blocks, &workout_exercise_ids)?;
+
+    conn.transaction(|conn| WorkoutExercise::reorder(conn, &workout_exercise_ids))
+        .map_err(handle_error)?;
+    WorkoutSession::detail(&mut conn, session)
+}
+
+/// Group blocks of a session into a superset (two) or circuit (more). They are performed
+/// in the given order, moved to where the first of them was (`[WO-046]`).
+#[command]
+pub fn group_workout_exercises(
+    pool: State<DbPool>,
+    session_id: i32,
+    workout_exercise_ids: Vec<i32>,
+) -> Result<WorkoutDetail, String> {
+    log::debug!(
+        ">>> group_workout_exercises session_id={} blocks={:?}",
+        session_id,
+        workout_exercise_ids
+    );
+    let mut conn = conn_from(&pool)?;
+    let session = WorkoutSession::find(&mut conn, session_id).map_err(handle_error)?;
+    let blocks = WorkoutExercise::for_session(&mut conn, session_id).map_err(handle_error)?;
+
+    if workout_exercise_ids.len() < 2 {
+        return Err("A superset needs at least two exercises".to_string());
+    }
+    let mut unique = workout_exercise_ids.clone();
+    unique.sort_unstable();
+    unique.dedup();
+    if unique.len() != workout_exercise_ids.len() {
+        return Err("A block can only appear once in a superset".to_string());
+    }
+    for id in &workout_exercise_ids {
+        let block = blocks
+            .iter()
+            .find(|block| block.id == *id)
+            .ok_or_else(|| format!("Block {} is not part of this workout", id))?;
+        if block.group_id.is_some() {
+            return Err(format!("Block {} is already in a superset", id));
+        }
+    }
+
+    let first = blocks
+        .iter()
+        .position(|block| workout_exercise_ids.contains(&block.id))
+        .unwrap_or(0);
+    let mut order: Vec<i32> = blocks
+        .iter()
+        .map(|block| block.id)
+        .filter(|id| !workout_exercise_ids.contains(id))
+        .collect();
+    order.splice(first..first, workout_exercise_ids.iter().copied());
+
     conn.transaction(|conn| {
-        let we = WorkoutExercise::add_or_get(conn, session.id, exercise_id)?;
-        WorkoutSet::log(conn, we.id, json, WL_PAYLOAD_VER, logged_at)
+        let group_id = WorkoutExercise::next_group_id(conn, session_id)?;
+        WorkoutExercise::set_group(conn, &workout_exercise_ids, Some(group_id))?;
+        WorkoutExercise::reorder(conn, &order)
+    })
+    .map_err(handle_error)?;
+    WorkoutSession::detail(&mut conn, session)
+}
+
+/// Take a block out of its superset or circuit; it follows the remaining blocks, and a
+/// single remaining block is ungrouped too (`[WO-046]`).
+#[command]
+pub fn ungroup_workout_exercise(
+    pool: State<DbPool>,
+    workout_exercise_id: i32,
+) -> Result<WorkoutDetail, String> {
+    let mut conn = conn_from(&pool)?;
+    let block = WorkoutExercise::find(&mut conn, workout_exercise_id).map_err(handle_error)?;
+    let group_id = block
+        .group_id
+        .ok_or_else(|| "The exercise is not in a superset".to_string())?;
+    let session = WorkoutSession::find(&mut conn, block.session_id).map_err(handle_error)?;
+    let blocks = WorkoutExercise::for_session(&mut conn, session.id).map_err(handle_error)?;
+
+    let remaining: Vec<i32> = blocks
+        .iter()
+        .filter(|other| other.group_id == Some(group_id) && other.id != block.id)
+        .map(|other| other.id)
+        .collect();
+    let mut order: Vec<i32> = blocks
+        .iter()
+        .map(|other| other.id)
+        .filter(|id| *id != block.id)
+        .collect();
+    let after = order
+        .iter()
+        .rposition(|id| remaining.contains(id))
+        .map_or(order.len(), |index| index + 1);
+    order.insert(after, block.id);
+
+    conn.transaction(|conn| {
+        WorkoutExercise::set_group(conn, &[block.id], None)?;
+        if remaining.len() < 2 {
+            WorkoutExercise::set_group(conn, &remaining, None)?;
+        }
+        WorkoutExercise::reorder(conn, &order)
     })
     .map_err(handle_error)?;
     WorkoutSession::detail(&mut conn, session)
 }
 
 /// List completed workouts whose start falls in `[from, to)` (RFC3339 UTC bounds), each
-/// with full detail, most recent first (`[HI-014]`–`[HI-018]`, `[DH-011]`, `[PG-012]`).
+/// with full detail and summary, most recent first (`[HI-014]`–`[HI-018]`, `[DH-011]`,
+/// `[PG-012]`, `[WO-050]`).
 #[command]
 pub fn list_workouts(
     pool: State<DbPool>,
@@ -747,12 +2259,12 @@ pub fn list_workouts(
         WorkoutSession::completed_in_range(&mut conn, &from, &to).map_err(handle_error)?;
     sessions
         .into_iter()
-        .map(|s| WorkoutSession::detail(&mut conn, s))
+        .map(|s| WorkoutSession::summarized_detail(&mut conn, s))
         .collect()
 }
 
-/// Delete a completed workout and all its data (`[HI-021]`). Refuses the active session —
-/// that path is `discard_workout_session`.
+/// Move a completed workout and all its data to the trash (`[HI-021]`, `[HI-029]`).
+/// Refuses the active session — that path is `discard_workout_session`.
 #[command]
 pub fn delete_workout(pool: State<DbPool>, session_id: i32) -> Result<(), String> {
     let mut conn = conn_from(&pool)?;
@@ -760,8 +2272,80 @@ pub fn delete_workout(pool: State<DbPool>, session_id: i32) -> Result<(), String
     if session.ended_at.is_none() {
         return Err("Cannot delete an active session; discard it instead".to_string());
     }
-    WorkoutSession::discard(&mut conn, session_id).map_err(handle_error)?;
-    Ok(())
+    let exercise_ids =
+        WorkoutExercise::exercise_ids(&mut conn, session_id).map_err(handle_error)?;
+    conn.transaction(|conn| {
+        let rows = RemovedRows::Workout { session_id };
+        JournalEntry::record(conn, Operation::DeleteWorkout, &rows)?;
+        WorkoutSession::discard(conn, session_id)
+    })
+    .map_err(handle_error)?;
+    refresh_records(&mut conn, &session, &exercise_ids)
+}
+
+/// Rename a session or correct its start, end and pauses after the fact (`[WO-054]`). A
+/// completed session must end after it starts and an active one cannot start in the future
+/// or be given an end. Sets logged outside the new bounds are rejected by name; move or
+/// delete them first.
+#[command]
+pub fn update_workout_session(
+    pool: State<DbPool>,
+    session_id: i32,
+    input: WorkoutSessionInput,
+) -> Result<WorkoutDetail, String> {
+    let mut conn = conn_from(&pool)?;
+    let session = WorkoutSession::find(&mut conn, session_id).map_err(handle_error)?;
+    let started_at = normalize_ts(&input.started_at)?;
+    let ended_at = match (&session.ended_at, &input.ended_at) {
+        (Some(_), Some(ended_at)) => Some(normalize_ts(ended_at)?),
+        (Some(_), None) => return Err("A completed workout needs an end".to_string()),
+        (None, Some(_)) => return Err("End the active workout instead".to_string()),
+        (None, None) => None,
+    };
+    let until = match &ended_at {
+        Some(ended_at) if *ended_at <= started_at => {
+            return Err("A workout must end after it starts".to_string());
+        }
+        Some(ended_at) => ended_at.clone(),
+        None if started_at > now_ts() => {
+            return Err("An active workout cannot start in the future".to_string());
+        }
+        None => now_ts(),
+    };
+    let pauses = validated_pauses(
+        session.id,
+        &input.pauses,
+        &started_at,
+        &until,
+        ended_at.is_none(),
+    )?;
+    let current = WorkoutSession::detail(&mut conn, session.clone())?;
+    let outside = sets_outside(&current, &started_at, ended_at.as_deref());
+    if !outside.is_empty() {
+        return Err(format!(
+            "Sets logged outside the new times: {}; move or delete them first",
+            outside.join(", ")
+        ));
+    }
+    let name = input.name.filter(|name| !name.trim().is_empty());
+
+    let updated = conn
+        .transaction(|conn| {
+            let updated = WorkoutSession::update_timing(
+                conn,
+                &session,
+                name,
+                &started_at,
+                ended_at.as_deref(),
+            )?;
+            WorkoutPause::replace(conn, session.id, &pauses)?;
+            Ok(updated)
+        })
+        .map_err(handle_error)?;
+    let exercise_ids =
+        WorkoutExercise::exercise_ids(&mut conn, session.id).map_err(handle_error)?;
+    refresh_records(&mut conn, &updated, &exercise_ids)?;
+    WorkoutSession::detail(&mut conn, updated)
 }
 
 /// Create a completed workout stamped to a past date (`[HI-022]`). Returns its detail so
@@ -811,16 +2395,25 @@ pub fn end_workout_session(pool: State<DbPool>) -> Result<WorkoutDetail, String>
             WorkoutSession::end(conn, session.id, &now_ts())
         })
         .map_err(handle_error)?;
-    WorkoutSession::detail(&mut conn, ended)
+    WorkoutSession::summarized_detail(&mut conn, ended)
 }
 
-/// Discard the active session and all its data (`[WO-016]`).
+/// Move the active session and all its data to the trash (`[WO-016]`).
 #[command]
 pub fn discard_workout_session(pool: State<DbPool>) -> Result<(), String> {
     let mut conn = conn_from(&pool)?;
     let session = require_active(&mut conn)?;
-    WorkoutSession::discard(&mut conn, session.id).map_err(handle_error)?;
-    Ok(())
+    let exercise_ids =
+        WorkoutExercise::exercise_ids(&mut conn, session.id).map_err(handle_error)?;
+    conn.transaction(|conn| {
+        let rows = RemovedRows::Workout {
+            session_id: session.id,
+        };
+        JournalEntry::record(conn, Operation::DiscardWorkout, &rows)?;
+        WorkoutSession::discard(conn, session.id)
+    })
+    .map_err(handle_error)?;
+    refresh_records(&mut conn, &session, &exercise_ids)
 }
 
 /// Fetch the active session detail, auto-completing it first if stale (`[WO-020]`).
@@ -834,9 +2427,36 @@ pub fn get_active_workout(pool: State<DbPool>) -> Result<Option<WorkoutDetail>,
     }
 }
 
-/// The seeded exercise library (`[WO-012]`, `[WO-013]`).
+/// The seeded exercise library of a workout type, weight lifting unless given
+/// (`[WO-012]`, `[WO-013]`, `[WO-032]`).
 #[command]
-pub fn get_exercise_library(pool: State<DbPool>) -> Result<Vec<ExerciseDetail>, String> {
+pub fn get_exercise_library(
+    pool: State<DbPool>,
+    workout_type: Option<String>,
+) -> Result<Vec<ExerciseDetail>, String> {
     let mut conn = conn_from(&pool)?;
-    Exercise::library(&mut conn).map_err(handle_error)
+    Exercise::library(&mut conn, workout_type.as_deref().unwrap_or("wl")).map_err(handle_error)
+}
+
+/// Search the exercise library of a workout type by name, category and worked muscle,
+/// ordered by name or by usage (`[WO-056]`, `[WO-057]`).
+#[command]
+pub fn search_exercises(
+    pool: State<DbPool>,
+    search: ExerciseSearch,
+) -> Result<Vec<ExerciseDetail>, String> {
+    log::debug!(">>> search_exercises search={:?}", search);
+    if let Some(role) = &search.role {
+        if role != "primary" && role != "secondary" {
+            return Err("A muscle's role must be primary or secondary".to_string());
+        }
+    }
+    let mut conn = conn_from(&pool)?;
+    Exercise::search(&mut conn, &search).map_err(handle_error)
+}
+
+/// The seeded workout types (`[WO-032]`).
+#[command]
+pub fn get_workout_types(pool: State<DbPool>) -> Result<Vec<WorkoutType>, String> {
+    pool.execute(WorkoutType::all)
 }
@@ -0,0 +1,224 @@
+//! Summary of a completed workout, computed here rather than in the UI so every screen
+//! shows the same numbers (`[WO-050]`), with a comparison against the previous session of
+//! the same template or exercises (`[WO-051]`).
+
+use crate::db::schema::{exercise_muscle, workout_exercise, workout_session, workout_set};
+use crate::service::personal_record::RecordKind;
+use crate::service::workout::{SetMetrics, WorkoutExerciseView, WorkoutSession, WorkoutSet};
+use crate::util::error_handler::handle_error;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+// ============================================================================
+// COMPOSITION MODEL
+// ============================================================================
+
+/// Countable numbers of a session. Volume leaves out warm-ups; sets and reps count all.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionTotals {
+    /// Duration without pauses
+    pub active_seconds: i64,
+    pub total_volume: f64,
+    pub sets: i32,
+    pub reps: i32,
+}
+
+/// A personal record set in the session
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SummaryRecord {
+    pub exercise_id: i32,
+    pub name: String,
+    pub set_id: i32,
+    pub kind: RecordKind,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SummaryComparison {
+    pub session_id: i32,
+    pub started_at: String,
+    pub totals: SessionTotals,
+    /// This session's totals minus the previous session's
+    pub change: SessionTotals,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkoutSummary {
+    pub totals: SessionTotals,
+    /// Primary and secondary muscles of the exercises with sets, by key
+    pub muscles: Vec<String>,
+    pub personal_records: Vec<SummaryRecord>,
+    pub previous: Option<SummaryComparison>,
+}
+
+// ============================================================================
+// COMPOSITION LOGIC
+// ============================================================================
+
+impl SessionTotals {
+    pub fn of(conn: &mut SqliteConnection, session: &WorkoutSession) -> Result<Self, String> {
+        let stored: Vec<WorkoutSet> = workout_set::table
+            .inner_join(workout_exercise::table)
+            .filter(workout_exercise::session_id.eq(session.id))
+            .select(WorkoutSet::as_select())
+            .load(conn)
+            .map_err(handle_error)?;
+
+        let mut totals = SessionTotals {
+            active_seconds: WorkoutSession::active_seconds(conn, session)?.unwrap_or(0),
+            total_volume: 0.0,
+            sets: 0,
+            reps: 0,
+        };
+        for set in stored {
+            let metrics =
+                SetMetrics::from_stored(&session.workout_type, set.payload_ver, &set.metrics)?;
+            totals.total_volume += metrics.volume();
+            totals.sets += 1;
+            totals.reps += metrics.lifting().and_then(|m| m.reps).unwrap_or(0);
+        }
+        Ok(totals)
+    }
+
+    fn minus(&self, other: &SessionTotals) -> SessionTotals {
+        SessionTotals {
+            active_seconds: self.active_seconds - other.active_seconds,
+            total_volume: self.total_volume - other.total_volume,
+            sets: self.sets - other.sets,
+            reps: self.reps - other.reps,
+        }
+    }
+}
+
+/// Exercises a session has sets of
+fn logged_exercises(conn: &mut SqliteConnection, session_id: i32) -> QueryResult<BTreeSet<i32>> {
+    let ids: Vec<i32> = workout_exercise::table
+        .inner_join(workout_set::table)
+        .filter(workout_exercise::session_id.eq(session_id))
+        .select(workout_exercise::exercise_id)
+        .distinct()
+        .load(conn)?;
+    Ok(ids.into_iter().collect())
+}
+
+/// The latest completed session of the same type started before this one: of the same
+/// template if it was started from one, otherwise with the same exercises.
+fn previous_session(
+    conn: &mut SqliteConnection,
+    session: &WorkoutSession,
+    exercises: &BTreeSet<i32>,
+) -> Result<Option<WorkoutSession>, String> {
+    if let Some(template_id) = session.template_id {
+        return workout_session::table
+            .filter(workout_session::template_id.eq(template_id))
+            .filter(workout_session::ended_at.is_not_null())
+            .filter(workout_session::deleted_at.is_null())
+            .filter(workout_session::started_at.lt(&session.started_at))
+            .order((
+                workout_session::started_at.desc(),
+                workout_session::id.desc(),
+            ))
+            .select(WorkoutSession::as_select())
+            .first(conn)
+            .optional()
+            .map_err(handle_error);
+    }
+    if exercises.is_empty() {
+        return Ok(None);
+    }
+
+    // Sessions with sets of exercises this one has none of
+    let (other_block, other_set) =
+        diesel::alias!(workout_exercise as other_block, workout_set as other_set);
+    let with_others = other_block
+        .inner_join(
+            other_set.on(other_set
+                .field(workout_set::workout_exercise_id)
+                .eq(other_block.field(workout_exercise::id))),
+        )
+        .filter(
+            other_block
+                .field(workout_exercise::exercise_id)
+                .ne_all(exercises),
+        )
+        .select(other_block.field(workout_exercise::session_id));
+    workout_session::table
+        .inner_join(workout_exercise::table.inner_join(workout_set::table))
+        .filter(workout_session::workout_type.eq(&session.workout_type))
+        .filter(workout_session::ended_at.is_not_null())
+        .filter(workout_session::deleted_at.is_null())
+        .filter(workout_session::started_at.lt(&session.started_at))
+        .filter(workout_session::id.ne_all(with_others))
+        .group_by(workout_session::id)
+        .having(
+            diesel::dsl::count(workout_exercise::exercise_id)
+                .aggregate_distinct()
+                .eq(exercises.len() as i64),
+        )
+        .select(WorkoutSession::as_select())
+        .order((
+            workout_session::started_at.desc(),
+            workout_session::id.desc(),
+        ))
+        .first(conn)
+        .optional()
+        .map_err(handle_error)
+}
+
+impl WorkoutSummary {
+    /// Summarize a completed session; `exercises` is its detail, carrying the record flags.
+    pub fn build(
+        conn: &mut SqliteConnection,
+        session: &WorkoutSession,
+        exercises: &[WorkoutExerciseView],
+    ) -> Result<Self, String> {
+        let totals = SessionTotals::of(conn, session)?;
+        let logged = logged_exercises(conn, session.id).map_err(handle_error)?;
+
+        let muscles: Vec<String> = exercise_muscle::table
+            .filter(exercise_muscle::exercise_id.eq_any(&logged))
+            .select(exercise_muscle::muscle)
+            .distinct()
+            .order(exercise_muscle::muscle.asc())
+            .load(conn)
+            .map_err(handle_error)?;
+
+        let personal_records = exercises
+            .iter()
+            .flat_map(|exercise| {
+                exercise.sets.iter().flat_map(move |set| {
+                    set.personal_records.iter().map(move |kind| SummaryRecord {
+                        exercise_id: exercise.exercise_id,
+                        name: exercise.name.clone(),
+                        set_id: set.id,
+                        kind: *kind,
+                    })
+                })
+            })
+            .collect();
+
+        let previous = match previous_session(conn, session, &logged)? {
+            Some(previous) => {
+                let previous_totals = SessionTotals::of(conn, &previous)?;
+                Some(SummaryComparison {
+                    session_id: previous.id,
+                    started_at: previous.started_at,
+                    change: totals.minus(&previous_totals),
+                    totals: previous_totals,
+                })
+            }
+            None => None,
+        };
+
+        Ok(WorkoutSummary {
+            totals,
+            muscles,
+            personal_records,
+            previous,
+        })
+    }
+}
@@ -0,0 +1,452 @@
+//! Workout templates — named routines of ordered exercises with per-exercise targets.
+//!
+//! A session started from a template pre-creates its `workout_exercise` rows with a
+//! snapshot of each exercise's plan (`[WO-030]`), so editing or deleting the template
+//! later leaves past workouts untouched.
+
+use crate::db::connection::DbPool;
+use crate::db::schema::{exercise, workout_template, workout_template_exercise};
+use crate::service::workout::{
+    Exercise, NewWorkoutExercise, WorkoutDetail, WorkoutExercise, WorkoutSession,
+};
+use crate::util::error_handler::handle_error;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use tauri::{command, State};
+use validator::{Validate, ValidationError};
+
+// ============================================================================
+// MODELS
+// ============================================================================
+
+#[derive(Queryable, Selectable, Serialize, Deserialize, Debug, Clone)]
+#[diesel(table_name = workout_template)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+#[serde(rename_all = "camelCase")]
+pub struct WorkoutTemplate {
+    pub id: i32,
+    pub name: String,
+    pub workout_type: String,
+}
+
+#[derive(Insertable, AsChangeset, Serialize, Deserialize, Debug)]
+#[diesel(table_name = workout_template)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+#[serde(rename_all = "camelCase")]
+pub struct NewWorkoutTemplate {
+    pub name: String,
+    pub workout_type: String,
+}
+
+#[derive(Queryable, Selectable, Serialize, Deserialize, Debug, Clone)]
+#[diesel(table_name = workout_template_exercise)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+#[serde(rename_all = "camelCase")]
+pub struct WorkoutTemplateExercise {
+    pub id: i32,
+    pub template_id: i32,
+    pub exercise_id: i32,
+    pub position: i32,
+    pub target_sets: i32,
+    pub target_reps_min: i32,
+    pub target_reps_max: i32,
+    pub target_weight_kg: Option<f64>,
+    pub target_rpe: Option<f32>,
+    pub rest_seconds: Option<i32>,
+}
+
+#[derive(Insertable, Serialize, Deserialize, Debug)]
+#[diesel(table_name = workout_template_exercise)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+#[serde(rename_all = "camelCase")]
+pub struct NewWorkoutTemplateExercise {
+    pub template_id: i32,
+    pub exercise_id: i32,
+    pub position: i32,
+    pub target_sets: i32,
+    pub target_reps_min: i32,
+    pub target_reps_max: i32,
+    pub target_weight_kg: Option<f64>,
+    pub target_rpe: Option<f32>,
+    pub rest_seconds: Option<i32>,
+}
+
+// ============================================================================
+// INPUT (validated on create/update; position is given by the list order)
+// ============================================================================
+
+/// One planned exercise of a template. A target is either a weight or an RPE, never
+/// both; neither is fine for open-ended sets.
+#[derive(Serialize, Deserialize, Validate, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+#[validate(schema(function = "validate_template_exercise"))]
+pub struct TemplateExerciseInput {
+    pub exercise_id: i32,
+    #[validate(range(min = 1, max = 20, message = "Target sets must be between 1 and 20"))]
+    pub target_sets: i32,
+    #[validate(range(min = 1, max = 1000, message = "Reps must be between 1 and 1000"))]
+    pub target_reps_min: i32,
+    #[validate(range(min = 1, max = 1000, message = "Reps must be between 1 and 1000"))]
+    pub target_reps_max: i32,
+    #[validate(range(
+        min = 0.0,
+        max = 1000.0,
+        message = "Weight must be between 0 and 1000 kg"
+    ))]
+    pub target_weight_kg: Option<f64>,
+    #[validate(range(min = 1.0, max = 10.0, message = "RPE must be between 1 and 10"))]
+    pub target_rpe: Option<f32>,
+    #[validate(range(
+        min = 0,
+        max = 3600,
+        message = "Rest must be between 0 and 3600 seconds"
+    ))]
+    pub rest_seconds: Option<i32>,
+}
+
+#[derive(Serialize, Deserialize, Validate, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkoutTemplateInput {
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "Name must be between 1 and 100 characters"
+    ))]
+    pub name: String,
+    #[validate(
+        length(min = 1, message = "A template needs at least one exercise"),
+        nested
+    )]
+    pub exercises: Vec<TemplateExerciseInput>,
+}
+
+fn validate_template_exercise(input: &TemplateExerciseInput) -> Result<(), ValidationError> {
+    if input.target_reps_min > input.target_reps_max {
+        return Err(ValidationError::new(
+            "Minimum reps must not exceed maximum reps",
+        ));
+    }
+    if input.target_weight_kg.is_some() && input.target_rpe.is_some() {
+        return Err(ValidationError::new(
+            "Set either a target weight or a target RPE, not both",
+        ));
+    }
+    Ok(())
+}
+
+// ============================================================================
+// VIEW DTOs
+// ============================================================================
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateExerciseView {
+    pub id: i32,
+    pub exercise_id: i32,
+    pub name: String,
+    pub position: i32,
+    pub target_sets: i32,
+    pub target_reps_min: i32,
+    pub target_reps_max: i32,
+    pub target_weight_kg: Option<f64>,
+    pub target_rpe: Option<f32>,
+    pub rest_seconds: Option<i32>,
+}
+
+/// A template with its exercises in position order.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkoutTemplateDetail {
+    pub template: WorkoutTemplate,
+    pub exercises: Vec<TemplateExerciseView>,
+}
+
+// ============================================================================
+// REPOSITORY
+// ============================================================================
+
+impl WorkoutTemplate {
+    pub fn find(conn: &mut SqliteConnection, id: i32) -> QueryResult<Self> {
+        workout_template::table
+            .filter(workout_template::id.eq(id))
+            .first(conn)
+    }
+
+    /// All templates, alphabetically.
+    pub fn all(conn: &mut SqliteConnection) -> QueryResult<Vec<Self>> {
+        workout_template::table
+            .order((workout_template::name.asc(), workout_template::id.asc()))
+            .load(conn)
+    }
+
+    /// Insert a template together with its exercises.
+    pub fn create(conn: &mut SqliteConnection, input: &WorkoutTemplateInput) -> QueryResult<Self> {
+        conn.transaction(|conn| {
+            let template = diesel::insert_into(workout_template::table)
+                .values(&NewWorkoutTemplate {
+                    name: input.name.clone(),
+                    workout_type: "wl".to_string(),
+                })
+                .returning(Self::as_returning())
+                .get_result(conn)?;
+            WorkoutTemplateExercise::insert_all(conn, template.id, &input.exercises)?;
+            Ok(template)
+        })
+    }
+
+    /// Rename a template and replace its exercises with the given list.
+    pub fn update(
+        conn: &mut SqliteConnection,
+        id: i32,
+        input: &WorkoutTemplateInput,
+    ) -> QueryResult<Self> {
+        conn.transaction(|conn| {
+            let template =
+                diesel::update(workout_template::table.filter(workout_template::id.eq(id)))
+                    .set(workout_template::name.eq(&input.name))
+                    .returning(Self::as_returning())
+                    .get_result(conn)?;
+            diesel::delete(
+                workout_template_exercise::table
+                    .filter(workout_template_exercise::template_id.eq(id)),
+            )
+            .execute(conn)?;
+            WorkoutTemplateExercise::insert_all(conn, id, &input.exercises)?;
+            Ok(template)
+        })
+    }
+
+    /// Delete a template and its exercises. Sessions started from it keep their plan
+    /// snapshot.
+    pub fn delete(conn: &mut SqliteConnection, id: i32) -> QueryResult<usize> {
+        conn.transaction(|conn| {
+            diesel::delete(
+                workout_template_exercise::table
+                    .filter(workout_template_exercise::template_id.eq(id)),
+            )
+            .execute(conn)?;
+            diesel::delete(workout_template::table.filter(workout_template::id.eq(id)))
+                .execute(conn)
+        })
+    }
+
+    /// The template with its exercises (and their names) in position order.
+    pub fn detail(
+        conn: &mut SqliteConnection,
+        template: WorkoutTemplate,
+    ) -> QueryResult<WorkoutTemplateDetail> {
+        let exercises = workout_template_exercise::table
+            .inner_join(exercise::table)
+            .filter(workout_template_exercise::template_id.eq(template.id))
+            .order(workout_template_exercise::position.asc())
+            .select((WorkoutTemplateExercise::as_select(), exercise::name))
+            .load::<(WorkoutTemplateExercise, String)>(conn)?
+            .into_iter()
+            .map(|(te, name)| TemplateExerciseView {
+                id: te.id,
+                exercise_id: te.exercise_id,
+                name,
+                position: te.position,
+                target_sets: te.target_sets,
+                target_reps_min: te.target_reps_min,
+                target_reps_max: te.target_reps_max,
+                target_weight_kg: te.target_weight_kg,
+                target_rpe: te.target_rpe,
+                rest_seconds: te.rest_seconds,
+            })
+            .collect();
+
+        Ok(WorkoutTemplateDetail {
+            template,
+            exercises,
+        })
+    }
+}
+
+impl WorkoutTemplateExercise {
+    /// Insert the exercises of a template; the list order becomes their position.
+    fn insert_all(
+        conn: &mut SqliteConnection,
+        template_id: i32,
+        exercises: &[TemplateExerciseInput],
+    ) -> QueryResult<usize> {
+        let rows: Vec<NewWorkoutTemplateExercise> = exercises
+            .iter()
+            .enumerate()
+            .map(|(position, input)| NewWorkoutTemplateExercise {
+                template_id,
+                exercise_id: input.exercise_id,
+                position: position as i32,
+                target_sets: input.target_sets,
+                target_reps_min: input.target_reps_min,
+                target_reps_max: input.target_reps_max,
+                target_weight_kg: input.target_weight_kg,
+                target_rpe: input.target_rpe,
+                rest_seconds: input.rest_seconds,
+            })
+            .collect();
+        diesel::insert_into(workout_template_exercise::table)
+            .values(&rows)
+            .execute(conn)
+    }
+
+    pub fn for_template(conn: &mut SqliteConnection, template_id: i32) -> QueryResult<Vec<Self>> {
+        workout_template_exercise::table
+            .filter(workout_template_exercise::template_id.eq(template_id))
+            .order(workout_template_exercise::position.asc())
+            .load(conn)
+    }
+}
+
+// ============================================================================
+// COMMANDS (Tauri)
+// ============================================================================
+
+fn validate_input(conn: &mut SqliteConnection, input: &WorkoutTemplateInput) -> Result<(), String> {
+    if let Err(e) = input.validate() {
+        return Err(format!("Validation failed: {:?}", e));
+    }
+    for planned in &input.exercises {
+        let exercise = Exercise::find(conn, planned.exercise_id)
+            .optional()
+            .map_err(handle_error)?
+            .ok_or_else(|| format!("Unknown exercise {}", planned.exercise_id))?;
+        if exercise.archived {
+            return Err(format!("{} is archived", exercise.name));
+        }
+        // Targets are sets × reps, so templates plan weight-lifting exercises only
+        if exercise.workout_type != "wl" {
+            return Err(format!("{} cannot be planned in a template", exercise.name));
+        }
+    }
+    Ok(())
+}
+
+/// Create a template from an ordered list of planned exercises (`[WO-028]`).
+#[command]
+pub fn create_workout_template(
+    pool: State<DbPool>,
+    input: WorkoutTemplateInput,
+) -> Result<WorkoutTemplateDetail, String> {
+    log::debug!(">>> create_workout_template name={:?}", input.name);
+
+    let mut conn = pool
+        .get()
+        .map_err(|e| format!("Failed to get connection: {}", e))?;
+    validate_input(&mut conn, &input)?;
+    let template = WorkoutTemplate::create(&mut conn, &input).map_err(handle_error)?;
+    WorkoutTemplate::detail(&mut conn, template).map_err(handle_error)
+}
+
+/// Rename a template and replace its exercises (`[WO-029]`).
+#[command]
+pub fn update_workout_template(
+    pool: State<DbPool>,
+    template_id: i32,
+    input: WorkoutTemplateInput,
+) -> Result<WorkoutTemplateDetail, String> {
+    log::debug!(">>> update_workout_template template_id={}", template_id);
+
+    let mut conn = pool
+        .get()
+        .map_err(|e| format!("Failed to get connection: {}", e))?;
+    validate_input(&mut conn, &input)?;
+    let template = WorkoutTemplate::update(&mut conn, template_id, &input).map_err(handle_error)?;
+    WorkoutTemplate::detail(&mut conn, template).map_err(handle_error)
+}
+
+/// Delete a template; workouts started from it keep their plan snapshot (`[WO-029]`).
+#[command]
+pub fn delete_workout_template(pool: State<DbPool>, template_id: i32) -> Result<(), String> {
+    log::debug!(">>> delete_workout_template template_id={}", template_id);
+
+    let mut conn = pool
+        .get()
+        .map_err(|e| format!("Failed to get connection: {}", e))?;
+    WorkoutTemplate::delete(&mut conn, template_id).map_err(handle_error)?;
+    Ok(())
+}
+
+/// All templates with their exercises, alphabetically (`[WO-028]`).
+#[command]
+pub fn list_workout_templates(pool: State<DbPool>) -> Result<Vec<WorkoutTemplateDetail>, String> {
+    log::debug!(">>> list_workout_templates");
+
+    let mut conn = pool
+        .get()
+        .map_err(|e| format!("Failed to get connection: {}", e))?;
+    let templates = WorkoutTemplate::all(&mut conn).map_err(handle_error)?;
+    templates
+        .into_iter()
+        .map(|template| WorkoutTemplate::detail(&mut conn, template).map_err(handle_error))
+        .collect()
+}
+
+#[command]
+pub fn get_workout_template(
+    pool: State<DbPool>,
+    template_id: i32,
+) -> Result<WorkoutTemplateDetail, String> {
+    log::debug!(">>> get_workout_template template_id={}", template_id);
+
+    let mut conn = pool
+        .get()
+        .map_err(|e| format!("Failed to get connection: {}", e))?;
+    let template = WorkoutTemplate::find(&mut conn, template_id).map_err(handle_error)?;
+    WorkoutTemplate::detail(&mut conn, template).map_err(handle_error)
+}
+
+/// Start a session from a template, pre-creating its exercises in template order with a
+/// snapshot of their plan (`[WO-030]`). The session is named after the template unless a
+/// name is given. Refuses if a session is already active (`[WO-002]`).
+#[command]
+pub fn start_workout_session_from_template(
+    pool: State<DbPool>,
+    template_id: i32,
+    name: Option<String>,
+) -> Result<WorkoutDetail, String> {
+    log::debug!(
+        ">>> start_workout_session_from_template template_id={}",
+        template_id
+    );
+
+    let mut conn = pool
+        .get()
+        .map_err(|e| format!("Failed to get connection: {}", e))?;
+    if WorkoutSession::active(&mut conn)
+        .map_err(handle_error)?
+        .is_some()
+    {
+        return Err("A workout session is already active".to_string());
+    }
+
+    let template = WorkoutTemplate::find(&mut conn, template_id).map_err(handle_error)?;
+    let session = conn
+        .transaction(|conn| {
+            let session = WorkoutSession::start(
+                conn,
+                &template.workout_type,
+                name.or(Some(template.name.clone())),
+            )?;
+            let session = WorkoutSession::set_template(conn, session.id, template.id)?;
+            for planned in WorkoutTemplateExercise::for_template(conn, template.id)? {
+                WorkoutExercise::create(
+                    conn,
+                    &NewWorkoutExercise {
+                        session_id: session.id,
+                        exercise_id: planned.exercise_id,
+                        target_sets: Some(planned.target_sets),
+                        target_reps_min: Some(planned.target_reps_min),
+                        target_reps_max: Some(planned.target_reps_max),
+                        target_weight_kg: planned.target_weight_kg,
+                        target_rpe: planned.target_rpe,
+                        plan_rest_seconds: planned.rest_seconds,
+                        group_id: None,
+                    },
+                )?;
+            }
+            Ok(session)
+        })
+        .map_err(handle_error)?;
+    WorkoutSession::detail(&mut conn, session)
+}
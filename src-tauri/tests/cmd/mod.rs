@@ -1,14 +1,32 @@
 // Command layer tests
 // These tests verify Tauri commands that orchestrate repository operations
 
+mod test_adherence_cmd;
 mod test_body_cmd;
+mod test_calendar_cmd;
 mod test_dashboard_cmd;
+mod test_energy_cmd;
+mod test_exercise_cmd;
+mod test_exercise_search_cmd;
 mod test_export_cmd;
 mod test_import_cmd;
 mod test_intake_cmd;
+mod test_journal_cmd;
+mod test_muscle_volume_cmd;
+mod test_personal_record_cmd;
 mod test_progress_cmd;
+mod test_progression_cmd;
+mod test_report_cmd;
+mod test_rest_cmd;
+mod test_session_edit_cmd;
+mod test_stale_session_cmd;
+mod test_strength_cmd;
 mod test_tracker_history_cmd;
+mod test_trash_cmd;
 mod test_user_cmd;
 mod test_weight_cmd;
 mod test_wizard_cmd;
 mod test_workout_cmd;
+mod test_workout_order_cmd;
+mod test_workout_summary_cmd;
+mod test_workout_template_cmd;
@@ -0,0 +1,176 @@
+use crate::helpers::{
+    create_test_intake_entry, create_test_intake_target, create_test_weight_entry,
+    create_test_weight_target, local_noon_timestamp, setup_test_pool, TestPool,
+};
+use librefit_lib::scenario;
+use librefit_lib::service::adherence::get_adherence;
+use librefit_lib::service::dashboard::daily_dashboard;
+use librefit_lib::service::trash::{restore_from_trash, TrashKind};
+use librefit_lib::service::weight::{
+    create_weight_tracker_entry, delete_weight_tracker_entry, update_weight_tracker_entry,
+    NewWeightTracker,
+};
+use librefit_lib::service::workout::WorkoutSession;
+use tauri::Manager;
+
+fn mock_app(pool: TestPool) -> tauri::App<tauri::test::MockRuntime> {
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+    app
+}
+
+/// Creates a completed workout started at local noon of the given date
+fn create_workout_on(pool: &TestPool, date: &str) {
+    let mut conn = pool.get().unwrap();
+    WorkoutSession::create_completed(&mut conn, "wl", None, local_noon_timestamp(date)).unwrap();
+}
+
+#[test]
+fn logging_streak_counts_intake_and_weight_days() {
+    scenario!("[DH-019]");
+    let pool = setup_test_pool();
+    for date in ["2026-01-01", "2026-01-02", "2026-01-03"] {
+        create_test_intake_entry(&pool, date, 500, "b", None);
+    }
+    create_test_weight_entry(&pool, "2026-01-04", 80.0);
+    create_test_weight_entry(&pool, "2026-01-05", 79.8);
+    // 2026-01-06 missed
+    create_test_intake_entry(&pool, "2026-01-07", 500, "b", None);
+    create_test_weight_entry(&pool, "2026-01-08", 79.5);
+    let app = mock_app(pool);
+
+    let on_day = get_adherence(app.state(), "2026-01-08".to_string()).unwrap();
+    assert_eq!(on_day.logging_streak, 2);
+    assert_eq!(on_day.longest_logging_streak, 5);
+
+    // Nothing logged yet today doesn't break the streak
+    let next_day = get_adherence(app.state(), "2026-01-09".to_string()).unwrap();
+    assert_eq!(next_day.logging_streak, 2);
+
+    let after_gap = get_adherence(app.state(), "2026-01-10".to_string()).unwrap();
+    assert_eq!(after_gap.logging_streak, 0);
+    assert_eq!(after_gap.longest_logging_streak, 5);
+}
+
+#[test]
+fn calorie_adherence_counts_days_within_target_and_maximum() {
+    scenario!("[DH-020]");
+    let pool = setup_test_pool();
+    create_test_intake_target(&pool, "2026-01-01", "2026-06-01", 2000, 2500);
+    create_test_intake_entry(&pool, "2026-01-01", 1800, "b", None);
+    create_test_intake_entry(&pool, "2026-01-02", 1500, "l", None);
+    create_test_intake_entry(&pool, "2026-01-02", 800, "d", None);
+    create_test_intake_entry(&pool, "2026-01-03", 2600, "d", None);
+    // Today is still in progress and not counted
+    create_test_intake_entry(&pool, "2026-01-05", 3000, "d", None);
+    let app = mock_app(pool);
+
+    let adherence = get_adherence(app.state(), "2026-01-05".to_string()).unwrap();
+
+    assert_eq!(adherence.days_elapsed, 4);
+    assert_eq!(adherence.days_logged, 3);
+    assert_eq!(adherence.days_within_target, 1);
+    assert_eq!(adherence.days_within_maximum, 2);
+}
+
+#[test]
+fn workout_frequency_covers_the_last_28_days() {
+    scenario!("[DH-021]");
+    let pool = setup_test_pool();
+    create_workout_on(&pool, "2025-12-01");
+    create_workout_on(&pool, "2026-01-02");
+    create_workout_on(&pool, "2026-01-10");
+    create_workout_on(&pool, "2026-01-20");
+    let app = mock_app(pool);
+
+    let adherence = get_adherence(app.state(), "2026-01-20".to_string()).unwrap();
+
+    assert_eq!(adherence.workouts_last_28_days, 3);
+    assert_eq!(adherence.workouts_per_week, 0.8);
+}
+
+#[test]
+fn milestones_are_recorded_once_when_first_reached() {
+    scenario!("[DH-022]");
+    let pool = setup_test_pool();
+    create_test_weight_target(&pool, "2026-01-01", "2026-06-01", 85.0, 75.0);
+    let app = mock_app(pool);
+    let weigh_in = |date: &str, amount: f32| {
+        create_weight_tracker_entry(app.state(), NewWeightTracker::new(date.to_string(), amount))
+            .unwrap()
+    };
+
+    for day in 1..=7 {
+        weigh_in(&format!("2026-01-{:02}", day), 84.0);
+    }
+    let early = get_adherence(app.state(), "2026-01-07".to_string()).unwrap();
+    let kinds: Vec<&str> = early.milestones.iter().map(|m| m.kind.as_str()).collect();
+    assert_eq!(kinds, vec!["streak_7"]);
+
+    // Logged late: the milestone is dated on the weigh-in, not on the day it was entered
+    weigh_in("2026-01-10", 79.5);
+    weigh_in("2026-01-12", 79.0);
+    let later = get_adherence(app.state(), "2026-01-20".to_string()).unwrap();
+    assert_eq!(later.milestones.len(), 2);
+    assert_eq!(later.milestones[0].kind, "streak_7");
+    assert_eq!(later.milestones[0].achieved_on, "2026-01-07");
+    assert_eq!(later.milestones[1].kind, "weight_lost_5");
+    assert_eq!(later.milestones[1].achieved_on, "2026-01-10");
+
+    // Looking at an earlier date neither records nor shows later milestones
+    let past = get_adherence(app.state(), "2026-01-05".to_string()).unwrap();
+    assert!(past.milestones.is_empty());
+    let again = get_adherence(app.state(), "2026-01-20".to_string()).unwrap();
+    assert_eq!(again.milestones.len(), 2);
+}
+
+#[test]
+fn milestones_are_revoked_when_no_longer_reached() {
+    scenario!("[DH-022]");
+    let pool = setup_test_pool();
+    create_test_weight_target(&pool, "2026-01-01", "2026-06-01", 85.0, 75.0);
+    let app = mock_app(pool);
+    let weigh_in = |date: &str, amount: f32| {
+        create_weight_tracker_entry(app.state(), NewWeightTracker::new(date.to_string(), amount))
+            .unwrap()
+    };
+    let milestones = || {
+        get_adherence(app.state(), "2026-01-20".to_string())
+            .unwrap()
+            .milestones
+            .into_iter()
+            .map(|m| (m.kind, m.achieved_on))
+            .collect::<Vec<_>>()
+    };
+    let lost_5 = |date: &str| vec![("weight_lost_5".to_string(), date.to_string())];
+
+    let first = weigh_in("2026-01-10", 79.5);
+    let second = weigh_in("2026-01-12", 79.0);
+    assert_eq!(milestones(), lost_5("2026-01-10"));
+
+    // The first weigh-in was a typo: the milestone moves to the second one
+    let corrected = NewWeightTracker::new("2026-01-10".to_string(), 81.0);
+    update_weight_tracker_entry(app.state(), first.id, corrected).unwrap();
+    assert_eq!(milestones(), lost_5("2026-01-12"));
+
+    delete_weight_tracker_entry(app.state(), second.id).unwrap();
+    assert!(milestones().is_empty());
+
+    restore_from_trash(app.state(), TrashKind::Weight, second.id).unwrap();
+    assert_eq!(milestones(), lost_5("2026-01-12"));
+}
+
+#[test]
+fn dashboard_includes_adherence() {
+    scenario!("[DH-019]");
+    let pool = setup_test_pool();
+    create_test_intake_target(&pool, "2026-01-01", "2026-06-01", 2000, 2500);
+    create_test_weight_target(&pool, "2026-01-01", "2026-06-01", 85.0, 75.0);
+    create_test_weight_entry(&pool, "2026-01-01", 84.5);
+    create_test_weight_entry(&pool, "2026-01-02", 84.0);
+    let app = mock_app(pool);
+
+    let dashboard = daily_dashboard(app.state(), "2026-01-02".to_string()).unwrap();
+
+    assert_eq!(dashboard.adherence.logging_streak, 2);
+}
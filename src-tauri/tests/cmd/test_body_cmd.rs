@@ -1,5 +1,8 @@
-use crate::helpers::setup_test_pool;
-use librefit_lib::service::body::{get_body_data, update_body_data};
+use crate::helpers::{birth_date_for_age, setup_test_pool};
+use librefit_lib::scenario;
+use librefit_lib::service::body::{
+    get_body_data, get_body_data_as_of, get_body_data_history, update_body_data,
+};
 use tauri::Manager;
 
 // ============================================================================
@@ -26,16 +29,17 @@ fn test_update_body_data_creates_new_data() {
 
     let result = update_body_data(
         app.state(),
-        25,              // age
-        170.0,           // height
-        70.0,            // weight
-        "m".to_string(), // sex
-        1.25,            // activityLevel
+        birth_date_for_age(25), // birthDate
+        170.0,                  // height
+        70.0,                   // weight
+        "m".to_string(),        // sex
+        1.25,                   // activityLevel
+        None,                   // effectiveDate
     );
 
     assert!(result.is_ok());
     let body_data = result.unwrap();
-    assert_eq!(body_data.age, 25);
+    assert_eq!(body_data.birth_date, birth_date_for_age(25));
     assert_eq!(body_data.height, 170.0);
     assert_eq!(body_data.weight, 70.0);
     assert_eq!(body_data.sex, "m");
@@ -49,14 +53,31 @@ fn test_update_body_data_updates_existing_data() {
     app.manage(pool);
 
     // Create initial body data
-    update_body_data(app.state(), 25, 170.0, 70.0, "m".to_string(), 1.25).unwrap();
+    update_body_data(
+        app.state(),
+        birth_date_for_age(25),
+        170.0,
+        70.0,
+        "m".to_string(),
+        1.25,
+        None,
+    )
+    .unwrap();
 
     // Update body data
-    let result = update_body_data(app.state(), 26, 175.0, 72.0, "f".to_string(), 1.5);
+    let result = update_body_data(
+        app.state(),
+        birth_date_for_age(26),
+        175.0,
+        72.0,
+        "f".to_string(),
+        1.5,
+        None,
+    );
 
     assert!(result.is_ok());
     let body_data = result.unwrap();
-    assert_eq!(body_data.age, 26);
+    assert_eq!(body_data.birth_date, birth_date_for_age(26));
     assert_eq!(body_data.height, 175.0);
     assert_eq!(body_data.weight, 72.0);
     assert_eq!(body_data.sex, "f");
@@ -70,14 +91,23 @@ fn test_get_body_data_returns_created_data() {
     app.manage(pool);
 
     // Create body data
-    update_body_data(app.state(), 25, 170.0, 70.0, "m".to_string(), 1.25).unwrap();
+    update_body_data(
+        app.state(),
+        birth_date_for_age(25),
+        170.0,
+        70.0,
+        "m".to_string(),
+        1.25,
+        None,
+    )
+    .unwrap();
 
     // Get body data
     let result = get_body_data(app.state());
 
     assert!(result.is_ok());
     let body_data = result.unwrap();
-    assert_eq!(body_data.age, 25);
+    assert_eq!(body_data.birth_date, birth_date_for_age(25));
     assert_eq!(body_data.height, 170.0);
     assert_eq!(body_data.weight, 70.0);
     assert_eq!(body_data.sex, "m");
@@ -92,11 +122,12 @@ fn test_update_body_data_validation_height_too_low() {
 
     let result = update_body_data(
         app.state(),
-        25,
+        birth_date_for_age(25),
         50.0, // Too low (min is 100)
         70.0,
         "m".to_string(),
         1.25,
+        None,
     );
 
     assert!(result.is_err());
@@ -111,11 +142,12 @@ fn test_update_body_data_validation_height_too_high() {
 
     let result = update_body_data(
         app.state(),
-        25,
+        birth_date_for_age(25),
         250.0, // Too high (max is 220)
         70.0,
         "m".to_string(),
         1.25,
+        None,
     );
 
     assert!(result.is_err());
@@ -130,11 +162,12 @@ fn test_update_body_data_validation_weight_too_low() {
 
     let result = update_body_data(
         app.state(),
-        25,
+        birth_date_for_age(25),
         170.0,
         20.0, // Too low (min is 30)
         "m".to_string(),
         1.25,
+        None,
     );
 
     assert!(result.is_err());
@@ -149,11 +182,12 @@ fn test_update_body_data_validation_weight_too_high() {
 
     let result = update_body_data(
         app.state(),
-        25,
+        birth_date_for_age(25),
         170.0,
         350.0, // Too high (max is 330)
         "m".to_string(),
         1.25,
+        None,
     );
 
     assert!(result.is_err());
@@ -162,17 +196,19 @@ fn test_update_body_data_validation_weight_too_high() {
 
 #[test]
 fn test_update_body_data_validation_age_too_low() {
+    scenario!("[PF-020]");
     let pool = setup_test_pool();
     let app = tauri::test::mock_app();
     app.manage(pool);
 
     let result = update_body_data(
         app.state(),
-        15, // Too low (min is 18)
+        birth_date_for_age(15), // Too low (min is 18)
         170.0,
         70.0,
         "m".to_string(),
         1.25,
+        None,
     );
 
     assert!(result.is_err());
@@ -187,11 +223,12 @@ fn test_update_body_data_validation_age_too_high() {
 
     let result = update_body_data(
         app.state(),
-        100, // Too high (max is 99)
+        birth_date_for_age(100), // Too high (max is 99)
         170.0,
         70.0,
         "m".to_string(),
         1.25,
+        None,
     );
 
     assert!(result.is_err());
@@ -207,16 +244,17 @@ fn test_update_body_data_valid_boundary_values() {
     // Test minimum valid values
     let result = update_body_data(
         app.state(),
-        18,    // Minimum age
-        100.0, // Minimum height
-        30.0,  // Minimum weight
+        birth_date_for_age(18), // Minimum age
+        100.0,                  // Minimum height
+        30.0,                   // Minimum weight
         "m".to_string(),
         1.25,
+        None,
     );
 
     assert!(result.is_ok());
     let body_data = result.unwrap();
-    assert_eq!(body_data.age, 18);
+    assert_eq!(body_data.birth_date, birth_date_for_age(18));
     assert_eq!(body_data.height, 100.0);
     assert_eq!(body_data.weight, 30.0);
 }
@@ -230,16 +268,17 @@ fn test_update_body_data_valid_maximum_values() {
     // Test maximum valid values
     let result = update_body_data(
         app.state(),
-        99,    // Maximum age
-        220.0, // Maximum height
-        330.0, // Maximum weight
+        birth_date_for_age(99), // Maximum age
+        220.0,                  // Maximum height
+        330.0,                  // Maximum weight
         "f".to_string(),
         1.25,
+        None,
     );
 
     assert!(result.is_ok());
     let body_data = result.unwrap();
-    assert_eq!(body_data.age, 99);
+    assert_eq!(body_data.birth_date, birth_date_for_age(99));
     assert_eq!(body_data.height, 220.0);
     assert_eq!(body_data.weight, 330.0);
 }
@@ -251,12 +290,28 @@ fn test_update_body_data_different_sexes() {
     app.manage(pool);
 
     // Test male
-    let result_m = update_body_data(app.state(), 25, 170.0, 70.0, "m".to_string(), 1.25);
+    let result_m = update_body_data(
+        app.state(),
+        birth_date_for_age(25),
+        170.0,
+        70.0,
+        "m".to_string(),
+        1.25,
+        None,
+    );
     assert!(result_m.is_ok());
     assert_eq!(result_m.unwrap().sex, "m");
 
     // Test female
-    let result_f = update_body_data(app.state(), 25, 170.0, 70.0, "f".to_string(), 1.25);
+    let result_f = update_body_data(
+        app.state(),
+        birth_date_for_age(25),
+        170.0,
+        70.0,
+        "f".to_string(),
+        1.25,
+        None,
+    );
     assert!(result_f.is_ok());
     assert_eq!(result_f.unwrap().sex, "f");
 }
@@ -267,10 +322,165 @@ fn test_update_body_data_with_empty_sex() {
     let app = tauri::test::mock_app();
     app.manage(pool);
 
-    let result = update_body_data(app.state(), 25, 170.0, 70.0, "".to_string(), 1.25);
+    let result = update_body_data(
+        app.state(),
+        birth_date_for_age(25),
+        170.0,
+        70.0,
+        "".to_string(),
+        1.25,
+        None,
+    );
 
     // Empty sex should be accepted
     assert!(result.is_ok());
     let body_data = result.unwrap();
     assert_eq!(body_data.sex, "");
 }
+
+// ============================================================================
+// BODY DATA HISTORY TESTS
+// ============================================================================
+
+#[test]
+fn update_with_effective_date_records_a_version_and_keeps_history() {
+    scenario!("[PF-016]", "[PF-017]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    update_body_data(
+        app.state(),
+        birth_date_for_age(30),
+        180.0,
+        85.0,
+        "MALE".to_string(),
+        1.25,
+        Some("2026-01-10".to_string()),
+    )
+    .unwrap();
+    update_body_data(
+        app.state(),
+        birth_date_for_age(30),
+        180.0,
+        82.0,
+        "MALE".to_string(),
+        1.75,
+        Some("2026-05-01".to_string()),
+    )
+    .unwrap();
+
+    let history = get_body_data_history(app.state()).unwrap();
+    assert_eq!(history.len(), 2);
+
+    let current = get_body_data(app.state()).unwrap();
+    assert_eq!(current.activity_level, 1.75);
+
+    let before = get_body_data_as_of(app.state(), "2026-02-01".to_string()).unwrap();
+    assert_eq!(before.activity_level, 1.25);
+    assert_eq!(before.weight, 85.0);
+}
+
+#[test]
+fn update_without_effective_date_applies_from_today() {
+    scenario!("[PF-016]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+    let result = update_body_data(
+        app.state(),
+        birth_date_for_age(25),
+        170.0,
+        70.0,
+        "m".to_string(),
+        1.25,
+        None,
+    );
+
+    assert_eq!(result.unwrap().effective_date, today);
+}
+
+#[test]
+fn update_with_future_or_malformed_effective_date_is_rejected() {
+    scenario!("[PF-019]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    let future = update_body_data(
+        app.state(),
+        birth_date_for_age(25),
+        170.0,
+        70.0,
+        "m".to_string(),
+        1.25,
+        Some("2999-01-01".to_string()),
+    );
+    assert!(future.unwrap_err().contains("Validation failed"));
+
+    let malformed = update_body_data(
+        app.state(),
+        birth_date_for_age(25),
+        170.0,
+        70.0,
+        "m".to_string(),
+        1.25,
+        Some("01.01.2026".to_string()),
+    );
+    assert!(malformed.unwrap_err().contains("Validation failed"));
+
+    assert!(get_body_data_history(app.state()).unwrap().is_empty());
+}
+
+// ============================================================================
+// BIRTH DATE TESTS
+// ============================================================================
+
+#[test]
+fn update_with_birth_date_derives_the_age() {
+    scenario!("[PF-020]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    let body_data = update_body_data(
+        app.state(),
+        "1990-06-15".to_string(),
+        170.0,
+        70.0,
+        "f".to_string(),
+        1.25,
+        Some("2026-06-14".to_string()),
+    )
+    .unwrap();
+
+    assert_eq!(body_data.birth_date, "1990-06-15");
+
+    let day_before_birthday = chrono::NaiveDate::from_ymd_opt(2026, 6, 14).unwrap();
+    let birthday = chrono::NaiveDate::from_ymd_opt(2026, 6, 15).unwrap();
+    assert_eq!(body_data.age_on(&day_before_birthday), Ok(35));
+    assert_eq!(body_data.age_on(&birthday), Ok(36));
+}
+
+#[test]
+fn update_with_malformed_birth_date_is_rejected() {
+    scenario!("[PF-020]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    let result = update_body_data(
+        app.state(),
+        "15.06.1990".to_string(),
+        170.0,
+        70.0,
+        "f".to_string(),
+        1.25,
+        None,
+    );
+
+    assert!(result.unwrap_err().contains("Validation failed"));
+    assert!(get_body_data_history(app.state()).unwrap().is_empty());
+}
@@ -0,0 +1,131 @@
+use crate::helpers::{
+    create_test_intake_entry, create_test_weight_entry, local_noon_timestamp, offset,
+    setup_test_pool, TestPool,
+};
+use diesel::prelude::*;
+use librefit_lib::db::schema::{workout_pause, workout_session};
+use librefit_lib::scenario;
+use librefit_lib::service::calendar::{
+    get_activity_dates_in_range, get_workout_dates_in_range, get_workout_frequency, ActivityDate,
+    WeeklyWorkouts,
+};
+use librefit_lib::service::workout::{
+    start_workout_session, NewWorkoutPause, NewWorkoutSession, WorkoutSession,
+};
+use tauri::Manager;
+
+/// Inserts a completed workout at local noon of `date` lasting `minutes`, of which
+/// `paused` minutes were paused
+fn insert_workout(pool: &TestPool, date: &str, minutes: i64, paused: i64) {
+    let started_at = local_noon_timestamp(date);
+    let mut conn = pool.get().unwrap();
+    let session: WorkoutSession = diesel::insert_into(workout_session::table)
+        .values(&NewWorkoutSession {
+            workout_type: "wl".to_string(),
+            name: None,
+            started_at: started_at.clone(),
+            ended_at: Some(offset(&started_at, minutes)),
+        })
+        .returning(WorkoutSession::as_returning())
+        .get_result(&mut conn)
+        .unwrap();
+    if paused > 0 {
+        diesel::insert_into(workout_pause::table)
+            .values(&NewWorkoutPause {
+                session_id: session.id,
+                paused_at: offset(&started_at, 1),
+                resumed_at: Some(offset(&started_at, 1 + paused)),
+            })
+            .execute(&mut conn)
+            .unwrap();
+    }
+}
+
+fn range(from: &str, to: &str) -> (String, String) {
+    (from.to_string(), to.to_string())
+}
+
+#[test]
+fn workout_dates_in_range() {
+    scenario!("[HI-024]");
+    let pool = setup_test_pool();
+    insert_workout(&pool, "2026-03-02", 60, 0);
+    insert_workout(&pool, "2026-03-02", 30, 0);
+    insert_workout(&pool, "2026-03-05", 40, 0);
+    insert_workout(&pool, "2026-04-01", 40, 0);
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+    // The active session is not a completed workout
+    start_workout_session(app.state(), None).unwrap();
+
+    let (from, to) = range("2026-03-01", "2026-03-31");
+    let dates = get_workout_dates_in_range(app.state(), from, to).unwrap();
+    assert_eq!(dates, vec!["2026-03-02", "2026-03-05"]);
+
+    let (from, to) = range("2026-03-31", "2026-03-01");
+    assert!(get_workout_dates_in_range(app.state(), from, to).is_err());
+}
+
+#[test]
+fn workout_frequency_per_week() {
+    scenario!("[HI-025]");
+    let pool = setup_test_pool();
+    // 2026-03-02 is a Monday; the range leaves out its first and last days
+    insert_workout(&pool, "2026-03-02", 60, 0);
+    insert_workout(&pool, "2026-03-05", 60, 15);
+    insert_workout(&pool, "2026-03-06", 30, 0);
+    insert_workout(&pool, "2026-03-18", 50, 0);
+    insert_workout(&pool, "2026-03-21", 50, 0);
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    let (from, to) = range("2026-03-03", "2026-03-20");
+    let weeks = get_workout_frequency(app.state(), from, to).unwrap();
+    let week = |week_start: &str, sessions: i32, active_minutes: i64| WeeklyWorkouts {
+        week_start: week_start.to_string(),
+        sessions,
+        active_minutes,
+    };
+    assert_eq!(
+        weeks,
+        vec![
+            week("2026-03-02", 2, 75),
+            week("2026-03-09", 0, 0),
+            week("2026-03-16", 1, 50),
+        ]
+    );
+}
+
+#[test]
+fn activity_dates_span_intake_weight_and_workouts() {
+    scenario!("[HI-026]");
+    let pool = setup_test_pool();
+    create_test_intake_entry(&pool, "2026-03-01", 500, "b", None);
+    create_test_intake_entry(&pool, "2026-03-02", 500, "b", None);
+    create_test_intake_entry(&pool, "2026-03-02", 700, "l", None);
+    create_test_weight_entry(&pool, "2026-03-02", 80.0);
+    create_test_weight_entry(&pool, "2026-03-04", 79.5);
+    insert_workout(&pool, "2026-03-02", 60, 0);
+    insert_workout(&pool, "2026-03-06", 60, 0);
+    create_test_intake_entry(&pool, "2026-03-08", 500, "b", None);
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    let (from, to) = range("2026-03-01", "2026-03-07");
+    let dates = get_activity_dates_in_range(app.state(), from, to).unwrap();
+    let day = |date: &str, intake: bool, weight: bool, workout: bool| ActivityDate {
+        date: date.to_string(),
+        intake,
+        weight,
+        workout,
+    };
+    assert_eq!(
+        dates,
+        vec![
+            day("2026-03-01", true, false, false),
+            day("2026-03-02", true, true, true),
+            day("2026-03-04", false, true, false),
+            day("2026-03-06", false, false, true),
+        ]
+    );
+}
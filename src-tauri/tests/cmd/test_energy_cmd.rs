@@ -0,0 +1,125 @@
+use crate::helpers::{
+    create_test_intake_target, create_test_weight_entry, create_test_weight_target,
+    local_noon_timestamp, offset, setup_test_pool, TestPool,
+};
+use diesel::prelude::*;
+use librefit_lib::db::schema::{workout_pause, workout_session};
+use librefit_lib::scenario;
+use librefit_lib::service::dashboard::daily_dashboard;
+use librefit_lib::service::energy::get_workout_energy;
+use librefit_lib::service::settings::{get_app_settings, update_app_settings, AppSettingsInput};
+use librefit_lib::service::workout::{
+    start_workout_session, NewWorkoutPause, NewWorkoutSession, WorkoutSession,
+};
+use tauri::Manager;
+
+/// Inserts a completed workout lasting `minutes`, with pauses given as minute offsets from
+/// its start (an open pause has no end)
+fn insert_workout(
+    pool: &TestPool,
+    workout_type: &str,
+    started_at: &str,
+    minutes: i64,
+    pauses: &[(i64, Option<i64>)],
+) -> WorkoutSession {
+    let mut conn = pool.get().unwrap();
+    let session: WorkoutSession = diesel::insert_into(workout_session::table)
+        .values(&NewWorkoutSession {
+            workout_type: workout_type.to_string(),
+            name: None,
+            started_at: started_at.to_string(),
+            ended_at: Some(offset(started_at, minutes)),
+        })
+        .returning(WorkoutSession::as_returning())
+        .get_result(&mut conn)
+        .unwrap();
+    for (paused, resumed) in pauses {
+        diesel::insert_into(workout_pause::table)
+            .values(&NewWorkoutPause {
+                session_id: session.id,
+                paused_at: offset(started_at, *paused),
+                resumed_at: resumed.map(|resumed| offset(started_at, resumed)),
+            })
+            .execute(&mut conn)
+            .unwrap();
+    }
+    session
+}
+
+#[test]
+fn calories_burned_follow_met_weight_and_active_time() {
+    scenario!("[WO-047]");
+    let pool = setup_test_pool();
+    // 60 minutes with a 10-minute break and a pause left open for the last 10 minutes
+    let lifting = insert_workout(
+        &pool,
+        "wl",
+        "2026-03-02T10:00:00.000Z",
+        60,
+        &[(20, Some(30)), (50, None)],
+    );
+    let run = insert_workout(&pool, "run", "2026-03-03T07:00:00.000Z", 30, &[]);
+    let app = tauri::test::mock_app();
+    app.manage(pool.clone());
+
+    // Without a weigh-in there is nothing to estimate from
+    assert!(get_workout_energy(app.state(), lifting.id)
+        .unwrap()
+        .is_none());
+
+    create_test_weight_entry(&pool, "2026-03-01", 80.0);
+
+    let energy = get_workout_energy(app.state(), lifting.id)
+        .unwrap()
+        .unwrap();
+    assert_eq!(energy.active_seconds, 40 * 60);
+    assert_eq!(energy.met, 5.0);
+    assert_eq!(energy.body_weight_kg, 80.0);
+    // 5.0 MET × 80 kg × 2/3 h
+    assert_eq!(energy.kcal, 267);
+
+    // 9.8 MET × 80 kg × 0.5 h
+    let energy = get_workout_energy(app.state(), run.id).unwrap().unwrap();
+    assert_eq!(energy.kcal, 392);
+
+    let active = start_workout_session(app.state(), None).unwrap();
+    assert!(get_workout_energy(app.state(), active.session.id)
+        .unwrap()
+        .is_none());
+}
+
+#[test]
+fn burned_calories_extend_the_budget_when_opted_in() {
+    scenario!("[DH-023]");
+    let pool = setup_test_pool();
+    create_test_intake_target(&pool, "2026-03-01", "2026-06-01", 2000, 2500);
+    create_test_weight_target(&pool, "2026-03-01", "2026-06-01", 85.0, 75.0);
+    create_test_weight_entry(&pool, "2026-03-01", 80.0);
+    insert_workout(&pool, "wl", &local_noon_timestamp("2026-03-02"), 60, &[]);
+    insert_workout(&pool, "wl", &local_noon_timestamp("2026-03-03"), 90, &[]);
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    assert!(!get_app_settings(app.state()).unwrap().add_workout_calories);
+    let dashboard = daily_dashboard(app.state(), "2026-03-02".to_string()).unwrap();
+    assert_eq!(dashboard.calories_burned, 400);
+    assert_eq!(dashboard.calorie_budget, 2000);
+
+    let settings = update_app_settings(
+        app.state(),
+        AppSettingsInput {
+            add_workout_calories: true,
+            stale_session_hours: Some(4),
+            trash_retention_days: 30,
+        },
+    )
+    .unwrap();
+    assert!(settings.add_workout_calories);
+
+    let dashboard = daily_dashboard(app.state(), "2026-03-02".to_string()).unwrap();
+    assert_eq!(dashboard.calorie_budget, 2400);
+    // Only the day's own workouts count
+    let rest_day = daily_dashboard(app.state(), "2026-03-04".to_string()).unwrap();
+    assert_eq!(rest_day.calories_burned, 0);
+    assert_eq!(rest_day.calorie_budget, 2000);
+}
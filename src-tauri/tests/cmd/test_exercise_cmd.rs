@@ -0,0 +1,263 @@
+use crate::helpers::{lifting_set, setup_test_pool, TestPool};
+use diesel::prelude::*;
+use librefit_lib::db::schema::{exercise, exercise_muscle};
+use librefit_lib::scenario;
+use librefit_lib::service::exercise::{
+    archive_exercise, create_exercise, delete_exercise, get_exercise_categories, get_muscles,
+    update_exercise, ExerciseInput, ExerciseMuscleInput,
+};
+use librefit_lib::service::workout::{
+    add_workout_set, create_workout_for_date, delete_workout, end_workout_session,
+    get_exercise_library, list_workouts, log_workout_set, start_workout_session,
+};
+use librefit_lib::service::workout_template::{
+    create_workout_template, TemplateExerciseInput, WorkoutTemplateInput,
+};
+use tauri::Manager;
+
+fn muscle(muscle: &str, role: &str) -> ExerciseMuscleInput {
+    ExerciseMuscleInput {
+        muscle: muscle.to_string(),
+        role: role.to_string(),
+    }
+}
+
+fn hack_squat() -> ExerciseInput {
+    ExerciseInput {
+        name: "Hack Squat".to_string(),
+        category: "machine".to_string(),
+        default_rest_seconds: Some(150),
+        workout_type: "wl".to_string(),
+        muscles: vec![
+            muscle("quadriceps", "primary"),
+            muscle("gluteal", "secondary"),
+        ],
+    }
+}
+
+fn library_names(app: &tauri::App<tauri::test::MockRuntime>) -> Vec<String> {
+    get_exercise_library(app.state(), None)
+        .unwrap()
+        .into_iter()
+        .map(|e| e.name)
+        .collect()
+}
+
+fn count_muscles(pool: &TestPool, exercise_id: i32) -> i64 {
+    let mut conn = pool.get().unwrap();
+    exercise_muscle::table
+        .filter(exercise_muscle::exercise_id.eq(exercise_id))
+        .count()
+        .get_result(&mut conn)
+        .unwrap()
+}
+
+#[test]
+fn custom_exercise_joins_the_library_and_can_be_logged() {
+    scenario!("[WO-043]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    let created = create_exercise(app.state(), hack_squat()).unwrap();
+
+    assert!(created.custom);
+    assert!(!created.archived);
+    assert_eq!(created.category, "machine");
+    assert_eq!(created.default_rest_seconds, Some(150));
+    assert_eq!(created.muscles.len(), 2);
+    assert!(library_names(&app).contains(&"Hack Squat".to_string()));
+
+    start_workout_session(app.state(), None).unwrap();
+    let detail = log_workout_set(app.state(), created.id, lifting_set(10, 120.0)).unwrap();
+    assert_eq!(detail.exercises[0].name, "Hack Squat");
+    assert_eq!(detail.exercises[0].rest_target_seconds, 150);
+
+    assert!(!get_exercise_categories(app.state()).unwrap().is_empty());
+    assert!(get_muscles(app.state())
+        .unwrap()
+        .iter()
+        .any(|m| m.shortvalue == "quadriceps"));
+}
+
+#[test]
+fn invalid_custom_exercises_are_rejected() {
+    scenario!("[WO-043]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool.clone());
+
+    let mut no_primary = hack_squat();
+    no_primary.muscles = vec![muscle("quadriceps", "secondary")];
+    let mut unknown_role = hack_squat();
+    unknown_role.muscles = vec![muscle("quadriceps", "main")];
+    let mut twice = hack_squat();
+    twice.muscles.push(muscle("quadriceps", "secondary"));
+    let mut unknown_muscle = hack_squat();
+    unknown_muscle.muscles = vec![muscle("tail", "primary")];
+    let mut unknown_category = hack_squat();
+    unknown_category.category = "kettlebell-machine".to_string();
+    let mut unknown_type = hack_squat();
+    unknown_type.workout_type = "climb".to_string();
+    let mut no_name = hack_squat();
+    no_name.name = String::new();
+    let mut taken_name = hack_squat();
+    taken_name.name = "bench press".to_string();
+
+    for input in [
+        no_primary,
+        unknown_role,
+        twice,
+        unknown_muscle,
+        unknown_category,
+        unknown_type,
+        no_name,
+        taken_name,
+    ] {
+        assert!(create_exercise(app.state(), input).is_err());
+    }
+
+    let mut conn = pool.get().unwrap();
+    let custom: i64 = exercise::table
+        .filter(exercise::custom.eq(true))
+        .count()
+        .get_result(&mut conn)
+        .unwrap();
+    assert_eq!(custom, 0);
+}
+
+#[test]
+fn custom_exercises_are_editable_and_seeded_ones_are_not() {
+    scenario!("[WO-043]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool.clone());
+
+    let created = create_exercise(app.state(), hack_squat()).unwrap();
+
+    let mut edited = hack_squat();
+    edited.name = "Hack Squat (Gym 2)".to_string();
+    edited.default_rest_seconds = None;
+    edited.muscles = vec![muscle("quadriceps", "primary")];
+    let updated = update_exercise(app.state(), created.id, edited).unwrap();
+
+    assert_eq!(updated.id, created.id);
+    assert_eq!(updated.name, "Hack Squat (Gym 2)");
+    assert_eq!(updated.default_rest_seconds, None);
+    assert_eq!(count_muscles(&pool, created.id), 1);
+    // Keeping its own name is fine
+    assert!(update_exercise(app.state(), created.id, hack_squat()).is_ok());
+
+    // Bench Press is seeded
+    let mut bench = hack_squat();
+    bench.name = "Bench Press".to_string();
+    assert!(update_exercise(app.state(), 1, bench).is_err());
+    assert!(archive_exercise(app.state(), 1, true).is_err());
+    assert!(delete_exercise(app.state(), 1).is_err());
+    assert!(library_names(&app).contains(&"Bench Press".to_string()));
+}
+
+#[test]
+fn archived_exercises_leave_the_library_but_keep_their_history() {
+    scenario!("[WO-044]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    let created = create_exercise(app.state(), hack_squat()).unwrap();
+    start_workout_session(app.state(), None).unwrap();
+    log_workout_set(app.state(), created.id, lifting_set(10, 120.0)).unwrap();
+    end_workout_session(app.state()).unwrap();
+
+    let archived = archive_exercise(app.state(), created.id, true).unwrap();
+    assert!(archived.archived);
+    assert!(!library_names(&app).contains(&"Hack Squat".to_string()));
+
+    let history = list_workouts(
+        app.state(),
+        "2000-01-01T00:00:00Z".to_string(),
+        "2100-01-01T00:00:00Z".to_string(),
+    )
+    .unwrap();
+    assert_eq!(history[0].exercises[0].name, "Hack Squat");
+
+    start_workout_session(app.state(), None).unwrap();
+    assert!(log_workout_set(app.state(), created.id, lifting_set(10, 120.0)).is_err());
+
+    archive_exercise(app.state(), created.id, false).unwrap();
+    assert!(library_names(&app).contains(&"Hack Squat".to_string()));
+    assert!(log_workout_set(app.state(), created.id, lifting_set(10, 120.0)).is_ok());
+}
+
+#[test]
+fn only_unused_custom_exercises_can_be_deleted() {
+    scenario!("[WO-044]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool.clone());
+
+    let logged = create_exercise(app.state(), hack_squat()).unwrap();
+    start_workout_session(app.state(), None).unwrap();
+    log_workout_set(app.state(), logged.id, lifting_set(10, 120.0)).unwrap();
+
+    let mut pendulum = hack_squat();
+    pendulum.name = "Pendulum Squat".to_string();
+    let planned = create_exercise(app.state(), pendulum).unwrap();
+    create_workout_template(
+        app.state(),
+        WorkoutTemplateInput {
+            name: "Legs".to_string(),
+            exercises: vec![TemplateExerciseInput {
+                exercise_id: planned.id,
+                target_sets: 3,
+                target_reps_min: 8,
+                target_reps_max: 12,
+                target_weight_kg: None,
+                target_rpe: None,
+                rest_seconds: None,
+            }],
+        },
+    )
+    .unwrap();
+
+    let mut belt = hack_squat();
+    belt.name = "Belt Squat".to_string();
+    let unused = create_exercise(app.state(), belt).unwrap();
+
+    // Only used in a workout that is in the trash, which may be restored
+    let mut sissy = hack_squat();
+    sissy.name = "Sissy Squat".to_string();
+    let trashed = create_exercise(app.state(), sissy).unwrap();
+    let workout =
+        create_workout_for_date(app.state(), "2026-03-02T10:00:00.000Z".to_string(), None).unwrap();
+    add_workout_set(
+        app.state(),
+        workout.session.id,
+        trashed.id,
+        lifting_set(12, 40.0),
+        None,
+    )
+    .unwrap();
+    delete_workout(app.state(), workout.session.id).unwrap();
+
+    assert_eq!(
+        delete_exercise(app.state(), logged.id).unwrap_err(),
+        "Hack Squat is used in workouts; archive it instead"
+    );
+    assert_eq!(
+        delete_exercise(app.state(), planned.id).unwrap_err(),
+        "Pendulum Squat is used in templates; archive it instead"
+    );
+    assert_eq!(
+        delete_exercise(app.state(), trashed.id).unwrap_err(),
+        "Sissy Squat is used in workouts in the trash; archive it instead"
+    );
+    // Used exercises keep their workout type
+    let mut as_cardio = hack_squat();
+    as_cardio.workout_type = "cardio".to_string();
+    assert!(update_exercise(app.state(), logged.id, as_cardio).is_err());
+
+    delete_exercise(app.state(), unused.id).unwrap();
+    assert_eq!(count_muscles(&pool, unused.id), 0);
+    assert!(!library_names(&app).contains(&"Belt Squat".to_string()));
+}
@@ -0,0 +1,126 @@
+use crate::helpers::{lifting_set, setup_test_pool};
+use librefit_lib::scenario;
+use librefit_lib::service::workout::{
+    add_workout_set, create_workout_for_date, delete_workout, get_exercise_library,
+    search_exercises, ExerciseDetail, ExerciseOrder, ExerciseSearch,
+};
+use tauri::Manager;
+
+fn ids(exercises: &[ExerciseDetail]) -> Vec<i32> {
+    exercises.iter().map(|e| e.id).collect()
+}
+
+#[test]
+fn search_filters_by_name_category_and_muscle() {
+    scenario!("[WO-056]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    let search = |search: ExerciseSearch| search_exercises(app.state(), search).unwrap();
+
+    let bench = search(ExerciseSearch {
+        name: Some("BENCH".to_string()),
+        ..Default::default()
+    });
+    assert!(ids(&bench).contains(&1) && ids(&bench).contains(&13));
+    assert!(bench
+        .iter()
+        .all(|e| e.name.to_lowercase().contains("bench")));
+    // Muscles come with the results
+    assert!(bench[0].muscles.iter().any(|m| m.role == "primary"));
+
+    let quads = search(ExerciseSearch {
+        category: Some("machine".to_string()),
+        muscle: Some("quadriceps".to_string()),
+        role: Some("primary".to_string()),
+        ..Default::default()
+    });
+    assert!(ids(&quads).contains(&9));
+    assert!(quads.iter().all(|e| e.category == "machine"
+        && e.muscles
+            .iter()
+            .any(|m| m.muscle == "quadriceps" && m.role == "primary")));
+
+    // Bench press only works the triceps as a secondary muscle
+    let triceps = search(ExerciseSearch {
+        muscle: Some("triceps".to_string()),
+        role: Some("primary".to_string()),
+        ..Default::default()
+    });
+    assert!(!triceps.is_empty());
+    assert!(!ids(&triceps).contains(&1));
+
+    // Wildcards are matched literally
+    assert!(search(ExerciseSearch {
+        name: Some("%".to_string()),
+        ..Default::default()
+    })
+    .is_empty());
+
+    assert_eq!(
+        search_exercises(
+            app.state(),
+            ExerciseSearch {
+                role: Some("main".to_string()),
+                ..Default::default()
+            }
+        )
+        .unwrap_err(),
+        "A muscle's role must be primary or secondary"
+    );
+
+    // The library is the unfiltered search
+    let library = get_exercise_library(app.state(), None).unwrap();
+    assert_eq!(ids(&library), ids(&search(ExerciseSearch::default())));
+    assert!(library.iter().find(|e| e.id == 1).unwrap().muscles.len() == 3);
+}
+
+#[test]
+fn search_orders_by_usage() {
+    scenario!("[WO-057]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    let workout = |started_at: &str, exercise_ids: &[i32]| {
+        let w = create_workout_for_date(app.state(), started_at.to_string(), None).unwrap();
+        for &id in exercise_ids {
+            add_workout_set(app.state(), w.session.id, id, lifting_set(5, 60.0), None).unwrap();
+        }
+        w.session.id
+    };
+    workout("2026-03-02T10:00:00.000Z", &[1, 2]);
+    workout("2026-03-03T10:00:00.000Z", &[2]);
+    workout("2026-03-04T10:00:00.000Z", &[1]);
+    // Trashed workouts don't count
+    let trashed = workout("2026-03-05T10:00:00.000Z", &[3, 3]);
+    delete_workout(app.state(), trashed).unwrap();
+
+    let ordered = |order: ExerciseOrder| {
+        search_exercises(
+            app.state(),
+            ExerciseSearch {
+                order,
+                ..Default::default()
+            },
+        )
+        .unwrap()
+    };
+
+    let recent = ordered(ExerciseOrder::RecentlyUsed);
+    assert_eq!(ids(&recent)[..2], [1, 2]);
+    // Never used ones follow by name
+    let unused: Vec<&str> = recent[2..].iter().map(|e| e.name.as_str()).collect();
+    let mut by_name = unused.clone();
+    by_name.sort();
+    assert_eq!(unused, by_name);
+
+    // Both were used twice, bench press last
+    let most = ordered(ExerciseOrder::MostUsed);
+    assert_eq!(ids(&most)[..2], [1, 2]);
+    workout("2026-03-06T10:00:00.000Z", &[2]);
+    let most = ordered(ExerciseOrder::MostUsed);
+    assert_eq!(ids(&most)[..2], [2, 1]);
+    assert_ne!(most[2].id, 3);
+}
@@ -0,0 +1,366 @@
+use crate::helpers::{
+    create_test_intake_entry, create_test_weight_entry, history, lifting_set, setup_test_pool,
+};
+use chrono::{Duration, SecondsFormat, Utc};
+use diesel::prelude::*;
+use librefit_lib::db::schema::operation_journal;
+use librefit_lib::scenario;
+use librefit_lib::service::intake::{delete_intake, get_intake_for_date_range};
+use librefit_lib::service::journal::{
+    get_last_operation, get_last_undone_operation, redo_last_operation, undo_last_operation,
+    NewJournalEntry, Operation,
+};
+use librefit_lib::service::personal_record::get_personal_records;
+use librefit_lib::service::settings::{update_app_settings, AppSettingsInput};
+use librefit_lib::service::trash::{get_trash, restore_from_trash, TrashKind};
+use librefit_lib::service::weight::{
+    delete_weight_tracker_entry, get_weight_tracker_for_date_range,
+};
+use librefit_lib::service::workout::{
+    add_workout_set, create_workout_for_date, delete_workout, delete_workout_set,
+    discard_workout_session, end_workout_session, get_active_workout, log_workout_set,
+    pause_workout_session, resume_workout_session, start_workout_session, WorkoutDetail,
+};
+use tauri::Manager;
+
+fn set_count(workout: &WorkoutDetail) -> usize {
+    workout.exercises.iter().map(|block| block.sets.len()).sum()
+}
+
+#[test]
+fn undo_restores_deleted_intake_and_weight_entries() {
+    scenario!("[HI-027]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool.clone());
+
+    let intake = create_test_intake_entry(&pool, "2026-03-02", 650, "l", Some("Pasta".into()));
+    let weight = create_test_weight_entry(&pool, "2026-03-02", 81.5);
+
+    delete_intake(app.state(), intake.id).unwrap();
+    delete_weight_tracker_entry(app.state(), weight.id).unwrap();
+    assert_eq!(
+        get_last_operation(app.state()).unwrap().unwrap().operation,
+        Operation::DeleteWeight
+    );
+
+    // Latest first
+    let undone = undo_last_operation(app.state()).unwrap().unwrap();
+    assert_eq!(undone.operation, Operation::DeleteWeight);
+    let weights = get_weight_tracker_for_date_range(
+        app.state(),
+        "2026-03-02".to_string(),
+        "2026-03-02".to_string(),
+    )
+    .unwrap();
+    assert_eq!(weights.len(), 1);
+    assert_eq!(weights[0].amount, 81.5);
+    assert_eq!(weights[0].time, weight.time);
+
+    let undone = undo_last_operation(app.state()).unwrap().unwrap();
+    assert_eq!(undone.operation, Operation::DeleteIntake);
+    let intakes = get_intake_for_date_range(
+        app.state(),
+        "2026-03-02".to_string(),
+        "2026-03-02".to_string(),
+    )
+    .unwrap();
+    assert_eq!(intakes.len(), 1);
+    assert_eq!(intakes[0].amount, 650);
+    assert_eq!(intakes[0].description.as_deref(), Some("Pasta"));
+
+    // Nothing left to undo
+    assert!(undo_last_operation(app.state()).unwrap().is_none());
+    assert!(get_last_operation(app.state()).unwrap().is_none());
+}
+
+#[test]
+fn undo_restores_a_deleted_workout_with_its_sets_and_records() {
+    scenario!("[HI-027]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    let workout =
+        create_workout_for_date(app.state(), "2026-03-02T10:00:00.000Z".to_string(), None).unwrap();
+    let session_id = workout.session.id;
+    add_workout_set(app.state(), session_id, 1, lifting_set(5, 100.0), None).unwrap();
+    add_workout_set(app.state(), session_id, 1, lifting_set(5, 105.0), None).unwrap();
+    let detail = add_workout_set(app.state(), session_id, 2, lifting_set(5, 140.0), None).unwrap();
+    let heaviest_set = detail.exercises[0].sets[1].id;
+
+    // A deleted set, then its whole workout
+    delete_workout_set(app.state(), heaviest_set).unwrap();
+    delete_workout(app.state(), session_id).unwrap();
+    assert!(history(&app).is_empty());
+    assert!(get_personal_records(app.state(), 1)
+        .unwrap()
+        .heaviest_weight
+        .is_none());
+
+    // Another workout logged in between
+    let other =
+        create_workout_for_date(app.state(), "2026-03-03T10:00:00.000Z".to_string(), None).unwrap();
+    add_workout_set(
+        app.state(),
+        other.session.id,
+        3,
+        lifting_set(5, 180.0),
+        None,
+    )
+    .unwrap();
+
+    let undone = undo_last_operation(app.state()).unwrap().unwrap();
+    assert_eq!(undone.operation, Operation::DeleteWorkout);
+    let restored = history(&app)
+        .into_iter()
+        .find(|w| w.session.started_at == "2026-03-02T10:00:00.000Z")
+        .unwrap();
+    assert_eq!(set_count(&restored), 2);
+    assert_eq!(restored.exercises[0].exercise_id, 1);
+    assert_eq!(restored.exercises[1].exercise_id, 2);
+
+    // The set deleted before the workout returns to the restored workout
+    let undone = undo_last_operation(app.state()).unwrap().unwrap();
+    assert_eq!(undone.operation, Operation::DeleteWorkoutSet);
+    let restored = history(&app)
+        .into_iter()
+        .find(|w| w.session.started_at == "2026-03-02T10:00:00.000Z")
+        .unwrap();
+    assert_eq!(set_count(&restored), 3);
+    assert_eq!(restored.exercises[0].sets.len(), 2);
+    assert_eq!(
+        get_personal_records(app.state(), 1)
+            .unwrap()
+            .heaviest_weight
+            .unwrap()
+            .value,
+        105.0
+    );
+    // The other workout is untouched
+    assert!(history(&app)
+        .iter()
+        .any(|w| w.session.id == other.session.id && set_count(w) == 1));
+}
+
+#[test]
+fn undo_restores_a_discarded_workout_unless_another_is_active() {
+    scenario!("[HI-027]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    start_workout_session(app.state(), Some("Push".to_string())).unwrap();
+    log_workout_set(app.state(), 1, lifting_set(5, 100.0)).unwrap();
+    pause_workout_session(app.state()).unwrap();
+    resume_workout_session(app.state()).unwrap();
+    discard_workout_session(app.state()).unwrap();
+
+    start_workout_session(app.state(), None).unwrap();
+    let err = undo_last_operation(app.state()).unwrap_err();
+    assert_eq!(
+        err,
+        "End the active workout before restoring the discarded one"
+    );
+    discard_workout_session(app.state()).unwrap();
+
+    // Undo the second discard, then end it to free the slot
+    undo_last_operation(app.state()).unwrap();
+    end_workout_session(app.state()).unwrap();
+
+    let undone = undo_last_operation(app.state()).unwrap().unwrap();
+    assert_eq!(undone.operation, Operation::DiscardWorkout);
+    let active = get_active_workout(app.state()).unwrap().unwrap();
+    assert_eq!(active.session.name.as_deref(), Some("Push"));
+    assert_eq!(set_count(&active), 1);
+    assert_eq!(active.pauses.len(), 1);
+}
+
+#[test]
+fn looking_up_the_last_operation_skips_stale_entries_without_removing_them() {
+    scenario!("[HI-027]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool.clone());
+    let journal_size = || -> i64 {
+        let mut conn = pool.get().unwrap();
+        operation_journal::table
+            .count()
+            .get_result(&mut conn)
+            .unwrap()
+    };
+
+    let weight = create_test_weight_entry(&pool, "2026-03-01", 82.0);
+    delete_weight_tracker_entry(app.state(), weight.id).unwrap();
+    let intake = create_test_intake_entry(&pool, "2026-03-02", 650, "l", None);
+    delete_intake(app.state(), intake.id).unwrap();
+    // Restored from the trash: deleting the intake can no longer be undone
+    restore_from_trash(app.state(), TrashKind::Intake, intake.id).unwrap();
+
+    let last = get_last_operation(app.state()).unwrap().unwrap();
+    assert_eq!(last.operation, Operation::DeleteWeight);
+    assert_eq!(journal_size(), 2);
+
+    // Undoing drops the stale entry and keeps the undone one to redo it
+    undo_last_operation(app.state()).unwrap();
+    assert_eq!(journal_size(), 1);
+}
+
+#[test]
+fn redo_removes_what_the_undos_restored_last_undone_first() {
+    scenario!("[HI-032]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool.clone());
+
+    let intake = create_test_intake_entry(&pool, "2026-03-02", 650, "l", None);
+    let workout =
+        create_workout_for_date(app.state(), "2026-03-02T10:00:00.000Z".to_string(), None).unwrap();
+    add_workout_set(
+        app.state(),
+        workout.session.id,
+        1,
+        lifting_set(5, 100.0),
+        None,
+    )
+    .unwrap();
+    let detail = add_workout_set(
+        app.state(),
+        workout.session.id,
+        1,
+        lifting_set(5, 110.0),
+        None,
+    )
+    .unwrap();
+
+    delete_intake(app.state(), intake.id).unwrap();
+    delete_workout_set(app.state(), detail.exercises[0].sets[1].id).unwrap();
+    assert!(redo_last_operation(app.state()).unwrap().is_none());
+
+    undo_last_operation(app.state()).unwrap();
+    undo_last_operation(app.state()).unwrap();
+    assert_eq!(
+        get_last_undone_operation(app.state())
+            .unwrap()
+            .unwrap()
+            .operation,
+        Operation::DeleteIntake
+    );
+
+    // The intake goes back to the trash
+    let redone = redo_last_operation(app.state()).unwrap().unwrap();
+    assert_eq!(redone.operation, Operation::DeleteIntake);
+    assert_eq!(get_trash(app.state()).unwrap().intake[0].id, intake.id);
+
+    // The restored set is deleted again, with the records following
+    let redone = redo_last_operation(app.state()).unwrap().unwrap();
+    assert_eq!(redone.operation, Operation::DeleteWorkoutSet);
+    assert_eq!(set_count(&history(&app)[0]), 1);
+    assert_eq!(
+        get_personal_records(app.state(), 1)
+            .unwrap()
+            .heaviest_weight
+            .unwrap()
+            .value,
+        100.0
+    );
+    assert!(redo_last_operation(app.state()).unwrap().is_none());
+
+    // And can be undone again
+    let undone = undo_last_operation(app.state()).unwrap().unwrap();
+    assert_eq!(undone.operation, Operation::DeleteWorkoutSet);
+    assert_eq!(set_count(&history(&app)[0]), 2);
+}
+
+#[test]
+fn nothing_can_be_redone_after_another_operation() {
+    scenario!("[HI-032]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool.clone());
+
+    let weight = create_test_weight_entry(&pool, "2026-03-01", 82.0);
+    let intake = create_test_intake_entry(&pool, "2026-03-02", 650, "l", None);
+    delete_weight_tracker_entry(app.state(), weight.id).unwrap();
+    undo_last_operation(app.state()).unwrap();
+
+    delete_intake(app.state(), intake.id).unwrap();
+    assert!(get_last_undone_operation(app.state()).unwrap().is_none());
+    assert!(redo_last_operation(app.state()).unwrap().is_none());
+    let weights = get_weight_tracker_for_date_range(
+        app.state(),
+        "2026-03-01".to_string(),
+        "2026-03-01".to_string(),
+    )
+    .unwrap();
+    assert_eq!(weights.len(), 1);
+}
+
+#[test]
+fn journal_entries_expire_after_the_retention_window() {
+    scenario!("[HI-028]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool.clone());
+
+    {
+        let mut conn = pool.get().unwrap();
+        diesel::insert_into(operation_journal::table)
+            .values(&NewJournalEntry {
+                operation: Operation::DeleteIntake.key().to_string(),
+                payload: "{}".to_string(),
+                performed_at: "2020-01-01T00:00:00.000Z".to_string(),
+            })
+            .execute(&mut conn)
+            .unwrap();
+    }
+    assert!(get_last_operation(app.state()).unwrap().is_none());
+    assert!(undo_last_operation(app.state()).unwrap().is_none());
+
+    // Recent entries are kept
+    let intake = create_test_intake_entry(&pool, "2026-03-02", 650, "l", None);
+    delete_intake(app.state(), intake.id).unwrap();
+    assert!(get_last_operation(app.state()).unwrap().is_some());
+}
+
+#[test]
+fn journal_entries_outlive_a_shorter_trash_retention() {
+    scenario!("[HI-028]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool.clone());
+    update_app_settings(
+        app.state(),
+        AppSettingsInput {
+            add_workout_calories: false,
+            stale_session_hours: Some(4),
+            trash_retention_days: 1,
+        },
+    )
+    .unwrap();
+
+    let workout =
+        create_workout_for_date(app.state(), "2026-03-02T10:00:00.000Z".to_string(), None).unwrap();
+    let detail = add_workout_set(
+        app.state(),
+        workout.session.id,
+        1,
+        lifting_set(5, 100.0),
+        None,
+    )
+    .unwrap();
+    delete_workout_set(app.state(), detail.exercises[0].sets[0].id).unwrap();
+    {
+        let five_days_ago =
+            (Utc::now() - Duration::days(5)).to_rfc3339_opts(SecondsFormat::Millis, true);
+        let mut conn = pool.get().unwrap();
+        diesel::update(operation_journal::table)
+            .set(operation_journal::performed_at.eq(five_days_ago))
+            .execute(&mut conn)
+            .unwrap();
+    }
+
+    let undone = undo_last_operation(app.state()).unwrap().unwrap();
+    assert_eq!(undone.operation, Operation::DeleteWorkoutSet);
+    assert_eq!(set_count(&history(&app)[0]), 1);
+}
@@ -0,0 +1,138 @@
+use crate::helpers::{lifting_set, local_noon_timestamp, setup_test_pool};
+use librefit_lib::scenario;
+use librefit_lib::service::muscle_volume::{
+    get_muscle_volume, get_muscle_volume_targets, update_muscle_volume_target, MuscleVolume,
+    MuscleVolumeReport, MuscleVolumeTargetInput, VolumeStatus,
+};
+use librefit_lib::service::workout::{
+    add_workout_set, create_workout_for_date, log_workout_set, start_workout_session,
+    LiftingSetMetrics, SetKind,
+};
+use tauri::Manager;
+
+fn muscle<'a>(report: &'a MuscleVolumeReport, muscle: &str) -> &'a MuscleVolume {
+    report
+        .muscles
+        .iter()
+        .find(|m| m.muscle == muscle)
+        .expect("muscle in report")
+}
+
+/// Bench Press (chest primary; triceps and shoulders secondary): 3 working sets on Monday
+/// and 2 on Wednesday, 10 × 80 kg each, plus 3 sets outside the week
+fn bench_press_week() -> tauri::App<tauri::test::MockRuntime> {
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    for (date, sets) in [("2026-03-02", 3), ("2026-03-04", 2), ("2026-03-20", 3)] {
+        let workout =
+            create_workout_for_date(app.state(), local_noon_timestamp(date), None).unwrap();
+        add_workout_set(
+            app.state(),
+            workout.session.id,
+            1,
+            LiftingSetMetrics {
+                kind: SetKind::Warmup,
+                ..lifting_set(10, 40.0)
+            },
+            None,
+        )
+        .unwrap();
+        for _ in 0..sets {
+            add_workout_set(
+                app.state(),
+                workout.session.id,
+                1,
+                lifting_set(10, 80.0),
+                None,
+            )
+            .unwrap();
+        }
+    }
+    // The active session doesn't count
+    start_workout_session(app.state(), None).unwrap();
+    log_workout_set(app.state(), 1, lifting_set(10, 80.0)).unwrap();
+
+    app
+}
+
+#[test]
+fn hard_sets_and_tonnage_per_muscle() {
+    scenario!("[PG-023]");
+    let app = bench_press_week();
+
+    let week = get_muscle_volume(
+        app.state(),
+        "2026-03-02".to_string(),
+        "2026-03-08".to_string(),
+    )
+    .unwrap();
+
+    assert_eq!(week.weeks, 1.0);
+    let chest = muscle(&week, "chest");
+    assert_eq!(chest.name, "Chest");
+    assert_eq!(chest.hard_sets, 5.0);
+    assert_eq!(chest.tonnage, 4000.0);
+    assert_eq!(chest.weekly_sets, 5.0);
+    // Secondary muscles are credited half
+    let triceps = muscle(&week, "triceps");
+    assert_eq!(triceps.hard_sets, 2.5);
+    assert_eq!(triceps.tonnage, 2000.0);
+    // Muscles without sets are listed too
+    assert_eq!(muscle(&week, "quadriceps").hard_sets, 0.0);
+    assert_eq!(muscle(&week, "quadriceps").status, VolumeStatus::Below);
+
+    // Across two weeks the weekly sets halve
+    let fortnight = get_muscle_volume(
+        app.state(),
+        "2026-03-02".to_string(),
+        "2026-03-15".to_string(),
+    )
+    .unwrap();
+    assert_eq!(fortnight.weeks, 2.0);
+    assert_eq!(muscle(&fortnight, "chest").hard_sets, 5.0);
+    assert_eq!(muscle(&fortnight, "chest").weekly_sets, 2.5);
+
+    assert!(get_muscle_volume(
+        app.state(),
+        "2026-03-08".to_string(),
+        "2026-03-02".to_string()
+    )
+    .is_err());
+}
+
+#[test]
+fn weekly_sets_are_compared_against_configurable_targets() {
+    scenario!("[PG-024]");
+    let app = bench_press_week();
+
+    let targets = get_muscle_volume_targets(app.state()).unwrap();
+    let chest = targets.iter().find(|t| t.muscle == "chest").unwrap();
+    assert_eq!((chest.min_weekly_sets, chest.max_weekly_sets), (10, 20));
+
+    let week = || {
+        get_muscle_volume(
+            app.state(),
+            "2026-03-02".to_string(),
+            "2026-03-08".to_string(),
+        )
+        .unwrap()
+    };
+    assert_eq!(muscle(&week(), "chest").status, VolumeStatus::Below);
+
+    let target = |min_weekly_sets, max_weekly_sets| MuscleVolumeTargetInput {
+        min_weekly_sets,
+        max_weekly_sets,
+    };
+    update_muscle_volume_target(app.state(), "chest".to_string(), target(4, 6)).unwrap();
+    assert_eq!(muscle(&week(), "chest").status, VolumeStatus::Within);
+    assert_eq!(muscle(&week(), "chest").min_weekly_sets, 4);
+
+    update_muscle_volume_target(app.state(), "chest".to_string(), target(2, 4)).unwrap();
+    assert_eq!(muscle(&week(), "chest").status, VolumeStatus::Above);
+
+    assert!(update_muscle_volume_target(app.state(), "chest".to_string(), target(8, 6)).is_err());
+    assert!(update_muscle_volume_target(app.state(), "chest".to_string(), target(0, 51)).is_err());
+    assert!(update_muscle_volume_target(app.state(), "tail".to_string(), target(4, 6)).is_err());
+}
@@ -0,0 +1,190 @@
+use crate::helpers::{lifting_set, setup_test_pool};
+use librefit_lib::scenario;
+use librefit_lib::service::personal_record::{
+    brzycki_one_rm, epley_one_rm, get_personal_records, RecordKind,
+};
+use librefit_lib::service::workout::{
+    add_workout_set, create_workout_for_date, delete_workout, delete_workout_set,
+    end_workout_session, log_workout_set, start_workout_session, update_workout_set,
+    LiftingSetMetrics, SetKind, WorkoutDetail,
+};
+use tauri::Manager;
+
+use RecordKind::*;
+
+fn records_of(detail: &WorkoutDetail) -> Vec<Vec<RecordKind>> {
+    detail.exercises[0]
+        .sets
+        .iter()
+        .map(|set| set.personal_records.clone())
+        .collect()
+}
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() < 0.01,
+        "expected {} to be {}",
+        actual,
+        expected
+    );
+}
+
+#[test]
+fn sets_beating_earlier_sets_are_flagged() {
+    scenario!("[WO-038]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    start_workout_session(app.state(), None).unwrap();
+    for (reps, weight_kg) in [(5, 100.0), (8, 90.0), (3, 110.0), (6, 100.0)] {
+        log_workout_set(app.state(), 1, lifting_set(reps, weight_kg)).unwrap();
+    }
+    let detail = log_workout_set(
+        app.state(),
+        1,
+        LiftingSetMetrics {
+            kind: SetKind::Warmup,
+            ..lifting_set(1, 120.0)
+        },
+    )
+    .unwrap();
+
+    assert_eq!(
+        records_of(&detail),
+        vec![
+            // The first set sets every record
+            vec![
+                HeaviestWeight,
+                EstimatedOneRmEpley,
+                EstimatedOneRmBrzycki,
+                BestVolumeSet
+            ],
+            // 720 kg beats 500 kg
+            vec![BestVolumeSet],
+            // 121 kg (Epley) and 116.5 kg (Brzycki) beat 116.7 kg and 112.5 kg
+            vec![HeaviestWeight, EstimatedOneRmEpley, EstimatedOneRmBrzycki],
+            // 6 reps beat 5 reps at 100 kg
+            vec![BestRepsAtWeight],
+            // Warm-ups never count
+            vec![],
+        ]
+    );
+
+    // Records carry across sessions
+    end_workout_session(app.state()).unwrap();
+    start_workout_session(app.state(), None).unwrap();
+    let detail = log_workout_set(app.state(), 1, lifting_set(2, 115.0)).unwrap();
+    assert_eq!(
+        records_of(&detail),
+        vec![vec![
+            HeaviestWeight,
+            EstimatedOneRmEpley,
+            EstimatedOneRmBrzycki
+        ]]
+    );
+}
+
+#[test]
+fn records_follow_edits_and_deletes() {
+    scenario!("[WO-039]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    start_workout_session(app.state(), None).unwrap();
+    log_workout_set(app.state(), 1, lifting_set(5, 100.0)).unwrap();
+    let detail = log_workout_set(app.state(), 1, lifting_set(5, 110.0)).unwrap();
+    let first_id = detail.exercises[0].sets[0].id;
+    let second_id = detail.exercises[0].sets[1].id;
+    assert_eq!(records_of(&detail)[1].len(), 4);
+
+    // Editing the heavier set below the first takes its records away
+    let detail = update_workout_set(app.state(), second_id, lifting_set(5, 90.0)).unwrap();
+    assert_eq!(records_of(&detail)[0].len(), 4);
+    assert!(records_of(&detail)[1].is_empty());
+
+    // Deleting the first set makes the remaining one the record holder
+    let detail = delete_workout_set(app.state(), first_id).unwrap();
+    assert_eq!(records_of(&detail)[0].len(), 4);
+    end_workout_session(app.state()).unwrap();
+
+    // A heavier set added to an earlier workout outranks the later one
+    let past =
+        create_workout_for_date(app.state(), "2020-01-01T12:00:00Z".to_string(), None).unwrap();
+    let past =
+        add_workout_set(app.state(), past.session.id, 1, lifting_set(5, 120.0), None).unwrap();
+    assert_eq!(records_of(&past)[0].len(), 4);
+    let records = get_personal_records(app.state(), 1).unwrap();
+    assert_eq!(records.heaviest_weight.unwrap().value, 120.0);
+
+    // ... until that workout is deleted again
+    delete_workout(app.state(), past.session.id).unwrap();
+    let records = get_personal_records(app.state(), 1).unwrap();
+    let heaviest = records.heaviest_weight.unwrap();
+    assert_eq!(heaviest.value, 90.0);
+    assert_eq!(heaviest.set_id, second_id);
+}
+
+#[test]
+fn current_records_per_exercise() {
+    scenario!("[WO-040]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    start_workout_session(app.state(), None).unwrap();
+    for (reps, weight_kg) in [(5, 100.0), (8, 90.0), (3, 110.0), (6, 100.0)] {
+        log_workout_set(app.state(), 1, lifting_set(reps, weight_kg)).unwrap();
+    }
+    // Bodyweight pull-ups only count for reps at their load
+    for reps in [8, 10] {
+        log_workout_set(
+            app.state(),
+            6,
+            LiftingSetMetrics {
+                bodyweight: true,
+                ..lifting_set(reps, 0.0)
+            },
+        )
+        .unwrap();
+    }
+
+    let records = get_personal_records(app.state(), 1).unwrap();
+    assert_eq!(records.exercise_id, 1);
+    assert_eq!(records.heaviest_weight.unwrap().value, 110.0);
+    assert_close(records.estimated_one_rm_epley.unwrap().value, 121.0);
+    assert_close(records.estimated_one_rm_brzycki.unwrap().value, 116.47);
+    assert_eq!(records.best_volume_set.unwrap().value, 720.0);
+    let reps_by_weight: Vec<(f64, i32)> = records
+        .best_reps_by_weight
+        .iter()
+        .map(|best| (best.weight_kg, best.reps))
+        .collect();
+    assert_eq!(reps_by_weight, vec![(90.0, 8), (100.0, 6), (110.0, 3)]);
+
+    let pull_ups = get_personal_records(app.state(), 6).unwrap();
+    assert!(pull_ups.heaviest_weight.is_none());
+    assert!(pull_ups.best_volume_set.is_none());
+    assert_eq!(pull_ups.best_reps_by_weight.len(), 1);
+    assert_eq!(pull_ups.best_reps_by_weight[0].reps, 10);
+
+    let untrained = get_personal_records(app.state(), 2).unwrap();
+    assert!(untrained.heaviest_weight.is_none());
+    assert!(untrained.best_reps_by_weight.is_empty());
+}
+
+#[test]
+fn one_rep_max_estimates() {
+    scenario!("[WO-040]");
+
+    assert_eq!(epley_one_rm(100.0, 1), Some(100.0));
+    assert_eq!(brzycki_one_rm(100.0, 1), Some(100.0));
+    assert_close(epley_one_rm(100.0, 10).unwrap(), 133.33);
+    assert_close(brzycki_one_rm(100.0, 10).unwrap(), 133.33);
+    assert_close(epley_one_rm(100.0, 5).unwrap(), 116.67);
+    assert_close(brzycki_one_rm(100.0, 5).unwrap(), 112.5);
+    // Too many reps for a meaningful estimate
+    assert_eq!(epley_one_rm(100.0, 13), None);
+    assert_eq!(brzycki_one_rm(100.0, 13), None);
+}
@@ -1,12 +1,19 @@
-use crate::helpers::setup_test_pool;
+use crate::helpers::{lifting_set, local_noon_timestamp, setup_test_pool};
 use chrono::{Days, Local};
 use librefit_lib::scenario;
 use librefit_lib::service::intake::{
     create_intake, create_intake_target, NewIntake, NewIntakeTarget,
 };
-use librefit_lib::service::progress::get_tracker_progress;
+use librefit_lib::service::progress::{
+    get_exercise_progress, get_tracker_progress, get_weight_forecast, ForecastStatus,
+};
 use librefit_lib::service::weight::{
     create_weight_target, create_weight_tracker_entry, NewWeightTarget, NewWeightTracker,
+    WeightTarget, WeightTracker,
+};
+use librefit_lib::service::workout::{
+    add_workout_set, create_workout_for_date, log_workout_set, start_workout_session,
+    LiftingSetMetrics, SetKind,
 };
 use tauri::Manager;
 
@@ -377,3 +384,227 @@ fn test_get_tracker_progress_with_weight_entries() {
     assert!(progress.weight_chart_data.min > 0.0);
     assert!(progress.weight_chart_data.max > 0.0);
 }
+
+// ============================================================================
+// WEIGHT FORECAST TESTS
+// ============================================================================
+
+/// Manages a pool with a loss target from 80 to 74 kg (2026-01-01 to 2026-03-01) and
+/// one weigh-in per given `(date, weight)`.
+fn forecast_app(weigh_ins: &[(&str, f32)]) -> tauri::App<tauri::test::MockRuntime> {
+    let pool = setup_test_pool();
+    {
+        let mut conn = pool.get().unwrap();
+        WeightTarget::create(
+            &mut conn,
+            &NewWeightTarget {
+                added: "2026-01-01".to_string(),
+                start_date: "2026-01-01".to_string(),
+                end_date: "2026-03-01".to_string(),
+                initial_weight: 80.0,
+                target_weight: 74.0,
+            },
+        )
+        .unwrap();
+        for (date, weight) in weigh_ins {
+            WeightTracker::create(&mut conn, &NewWeightTracker::new(date.to_string(), *weight))
+                .unwrap();
+        }
+    }
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+    app
+}
+
+#[test]
+fn forecast_projects_the_target_date_from_the_weight_trend() {
+    scenario!("[PG-015]", "[PG-016]");
+    // -0.1 kg per day: 4 kg left reach the target on 2026-03-02, a day after the end date
+    let app = forecast_app(&[
+        ("2026-01-01", 80.0),
+        ("2026-01-11", 79.0),
+        ("2026-01-21", 78.0),
+    ]);
+
+    let forecast = get_weight_forecast(app.state(), "2026-01-21".to_string()).unwrap();
+
+    assert_eq!(forecast.trend_weight, 78.0);
+    assert_eq!(forecast.daily_change, -0.1);
+    assert_eq!(forecast.projected_date.as_deref(), Some("2026-03-02"));
+    assert_eq!(forecast.status, ForecastStatus::OnTrack);
+    assert_eq!(forecast.days_remaining, 39);
+    assert_eq!(forecast.required_daily_adjustment, -18);
+}
+
+#[test]
+fn forecast_reports_behind_and_the_deficit_to_catch_up() {
+    scenario!("[PG-016]", "[PG-017]");
+    let app = forecast_app(&[
+        ("2026-01-01", 80.0),
+        ("2026-01-11", 79.5),
+        ("2026-01-21", 79.0),
+    ]);
+
+    let forecast = get_weight_forecast(app.state(), "2026-01-21".to_string()).unwrap();
+
+    assert_eq!(forecast.projected_date.as_deref(), Some("2026-05-01"));
+    assert_eq!(forecast.status, ForecastStatus::Behind);
+    // 5 kg in 39 days instead of 0.05 kg per day
+    assert_eq!(forecast.required_daily_adjustment, -547);
+}
+
+#[test]
+fn forecast_reports_ahead_with_room_to_eat_more() {
+    scenario!("[PG-016]", "[PG-017]");
+    let app = forecast_app(&[
+        ("2026-01-01", 80.0),
+        ("2026-01-11", 78.0),
+        ("2026-01-21", 76.0),
+    ]);
+
+    let forecast = get_weight_forecast(app.state(), "2026-01-21".to_string()).unwrap();
+
+    assert_eq!(forecast.projected_date.as_deref(), Some("2026-01-31"));
+    assert_eq!(forecast.status, ForecastStatus::Ahead);
+    assert_eq!(forecast.required_daily_adjustment, 1041);
+}
+
+#[test]
+fn forecast_without_progress_towards_the_target_has_no_projected_date() {
+    scenario!("[PG-016]");
+    let app = forecast_app(&[("2026-01-01", 80.0), ("2026-01-21", 81.0)]);
+
+    let forecast = get_weight_forecast(app.state(), "2026-01-21".to_string()).unwrap();
+
+    assert_eq!(forecast.projected_date, None);
+    assert_eq!(forecast.status, ForecastStatus::Behind);
+    assert!(forecast.required_daily_adjustment < 0);
+}
+
+#[test]
+fn forecast_reports_a_reached_target() {
+    scenario!("[PG-016]");
+    let app = forecast_app(&[
+        ("2026-01-01", 80.0),
+        ("2026-01-11", 77.0),
+        ("2026-01-21", 73.5),
+    ]);
+
+    let forecast = get_weight_forecast(app.state(), "2026-01-21".to_string()).unwrap();
+
+    assert_eq!(forecast.status, ForecastStatus::Reached);
+    assert_eq!(forecast.projected_date.as_deref(), Some("2026-01-21"));
+    assert_eq!(forecast.required_daily_adjustment, 0);
+}
+
+#[test]
+fn forecast_needs_two_days_of_weigh_ins() {
+    scenario!("[PG-018]");
+    let app = forecast_app(&[("2026-01-01", 80.0), ("2026-01-01", 79.8)]);
+
+    assert!(get_weight_forecast(app.state(), "2026-01-21".to_string()).is_err());
+}
+
+// ============================================================================
+// EXERCISE PROGRESS TESTS
+// ============================================================================
+
+#[test]
+fn exercise_progress_has_one_point_per_workout() {
+    scenario!("[PG-021]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    let workouts = [
+        // Before the range
+        ("2026-01-20", vec![(5, 200.0)]),
+        ("2026-02-02", vec![(5, 100.0), (5, 100.0), (3, 110.0)]),
+        ("2026-02-09", vec![(8, 90.0), (6, 100.0)]),
+    ];
+    for (date, sets) in workouts {
+        let workout =
+            create_workout_for_date(app.state(), local_noon_timestamp(date), None).unwrap();
+        for (reps, weight_kg) in sets {
+            add_workout_set(
+                app.state(),
+                workout.session.id,
+                1,
+                lifting_set(reps, weight_kg),
+                None,
+            )
+            .unwrap();
+        }
+        // Warm-ups and other exercises don't count
+        add_workout_set(
+            app.state(),
+            workout.session.id,
+            1,
+            LiftingSetMetrics {
+                kind: SetKind::Warmup,
+                ..lifting_set(10, 40.0)
+            },
+            None,
+        )
+        .unwrap();
+        add_workout_set(
+            app.state(),
+            workout.session.id,
+            2,
+            lifting_set(10, 50.0),
+            None,
+        )
+        .unwrap();
+    }
+    // Neither does the active session
+    start_workout_session(app.state(), None).unwrap();
+    log_workout_set(app.state(), 1, lifting_set(5, 150.0)).unwrap();
+
+    let progress = get_exercise_progress(
+        app.state(),
+        1,
+        "2026-02-01".to_string(),
+        "2026-02-28".to_string(),
+    )
+    .unwrap();
+
+    assert_eq!(progress.exercise_id, 1);
+    assert_eq!(progress.best_set.legend, vec!["02.02", "09.02"]);
+    assert_eq!(progress.best_set.values, vec![110.0, 100.0]);
+    assert_eq!(progress.best_set.min, 100.0);
+    assert_eq!(progress.best_set.max, 110.0);
+    assert_eq!(progress.best_set.avg, 105.0);
+    // Epley: 110 kg × 3 and 100 kg × 6
+    assert_eq!(progress.estimated_one_rm.values, vec![121.0, 120.0]);
+    assert_eq!(progress.volume.values, vec![1330.0, 1320.0]);
+    assert_eq!(progress.reps.values, vec![13.0, 14.0]);
+    assert_eq!(progress.reps.legend, progress.best_set.legend);
+}
+
+#[test]
+fn exercise_progress_without_workouts_is_empty() {
+    scenario!("[PG-022]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    let progress = get_exercise_progress(
+        app.state(),
+        1,
+        "2026-02-01".to_string(),
+        "2026-02-28".to_string(),
+    )
+    .unwrap();
+
+    assert!(progress.best_set.legend.is_empty());
+    assert!(progress.estimated_one_rm.values.is_empty());
+    assert_eq!(progress.volume.max, 0.0);
+
+    assert!(get_exercise_progress(
+        app.state(),
+        1,
+        "01.02.2026".to_string(),
+        "2026-02-28".to_string()
+    )
+    .is_err());
+}
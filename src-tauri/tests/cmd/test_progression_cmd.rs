@@ -0,0 +1,227 @@
+use crate::helpers::{lifting_set, setup_test_pool};
+use librefit_lib::scenario;
+use librefit_lib::service::progression::{get_previous_performance, suggest_next, SetSuggestion};
+use librefit_lib::service::workout::{
+    add_workout_exercise, add_workout_set, create_workout_for_date, log_workout_block_set,
+    log_workout_set, start_workout_session, ExercisePlan, LiftingSetMetrics, SetKind, SetMetrics,
+};
+use tauri::Manager;
+
+fn warmup(reps: i32, weight_kg: f64) -> LiftingSetMetrics {
+    LiftingSetMetrics {
+        kind: SetKind::Warmup,
+        ..lifting_set(reps, weight_kg)
+    }
+}
+
+fn plan(target_sets: i32, target_reps_min: i32, target_reps_max: i32) -> ExercisePlan {
+    ExercisePlan {
+        target_sets,
+        target_reps_min,
+        target_reps_max,
+        target_weight_kg: None,
+        target_rpe: None,
+        rest_seconds: None,
+    }
+}
+
+#[test]
+fn previous_performance_comes_from_the_last_completed_workout() {
+    scenario!("[WO-041]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    let older =
+        create_workout_for_date(app.state(), "2026-01-05T12:00:00Z".to_string(), None).unwrap();
+    for _ in 0..3 {
+        add_workout_set(
+            app.state(),
+            older.session.id,
+            1,
+            lifting_set(5, 100.0),
+            None,
+        )
+        .unwrap();
+    }
+    let latest =
+        create_workout_for_date(app.state(), "2026-01-12T12:00:00Z".to_string(), None).unwrap();
+    add_workout_set(app.state(), latest.session.id, 1, warmup(10, 40.0), None).unwrap();
+    for reps in [5, 5, 4] {
+        add_workout_set(
+            app.state(),
+            latest.session.id,
+            1,
+            lifting_set(reps, 102.5),
+            None,
+        )
+        .unwrap();
+    }
+    // The active session doesn't count as a previous workout
+    start_workout_session(app.state(), None).unwrap();
+    log_workout_set(app.state(), 1, lifting_set(5, 105.0)).unwrap();
+
+    let previous = get_previous_performance(app.state(), 1).unwrap().unwrap();
+    assert_eq!(previous.session.id, latest.session.id);
+    assert_eq!(previous.exercise.exercise_id, 1);
+    assert_eq!(previous.exercise.sets.len(), 4);
+    // The last set missed a rep, so the same target is suggested again
+    assert_eq!(
+        previous.suggestion,
+        Some(SetSuggestion {
+            weight_kg: 102.5,
+            reps: 5,
+            sets: 3,
+            increased: false,
+        })
+    );
+
+    assert!(get_previous_performance(app.state(), 2).unwrap().is_none());
+}
+
+#[test]
+fn previous_performance_merges_the_blocks_of_the_exercise() {
+    scenario!("[WO-041]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    let workout =
+        create_workout_for_date(app.state(), "2026-01-05T12:00:00Z".to_string(), None).unwrap();
+    add_workout_set(
+        app.state(),
+        workout.session.id,
+        1,
+        lifting_set(5, 100.0),
+        None,
+    )
+    .unwrap();
+    add_workout_set(
+        app.state(),
+        workout.session.id,
+        2,
+        lifting_set(8, 60.0),
+        None,
+    )
+    .unwrap();
+    // A second block of the first exercise after the other one
+    let detail = add_workout_exercise(app.state(), workout.session.id, 1).unwrap();
+    let second_block = detail.exercises[2].id;
+    for _ in 0..2 {
+        log_workout_block_set(
+            app.state(),
+            second_block,
+            SetMetrics::Lifting(lifting_set(5, 100.0)),
+            None,
+        )
+        .unwrap();
+    }
+    // A later workout where the exercise was added but never done
+    let later =
+        create_workout_for_date(app.state(), "2026-01-12T12:00:00Z".to_string(), None).unwrap();
+    add_workout_exercise(app.state(), later.session.id, 1).unwrap();
+
+    let previous = get_previous_performance(app.state(), 1).unwrap().unwrap();
+    assert_eq!(previous.session.id, workout.session.id);
+    assert_eq!(previous.exercise.sets.len(), 3);
+    assert_eq!(
+        previous.suggestion,
+        Some(SetSuggestion {
+            weight_kg: 102.5,
+            reps: 5,
+            sets: 3,
+            increased: true,
+        })
+    );
+}
+
+#[test]
+fn progression_adds_load_once_all_reps_were_hit() {
+    scenario!("[WO-042]");
+
+    let all_hit = [
+        lifting_set(5, 100.0),
+        lifting_set(5, 100.0),
+        lifting_set(5, 100.0),
+    ];
+    assert_eq!(
+        suggest_next(&all_hit, None),
+        Some(SetSuggestion {
+            weight_kg: 102.5,
+            reps: 5,
+            sets: 3,
+            increased: true,
+        })
+    );
+
+    let missed = [
+        lifting_set(5, 100.0),
+        lifting_set(5, 100.0),
+        lifting_set(4, 100.0),
+    ];
+    assert_eq!(
+        suggest_next(&missed, None),
+        Some(SetSuggestion {
+            weight_kg: 100.0,
+            reps: 5,
+            sets: 3,
+            increased: false,
+        })
+    );
+
+    // Warm-ups and back-off sets below the top load don't count
+    let mixed = [
+        warmup(10, 40.0),
+        lifting_set(5, 100.0),
+        lifting_set(8, 80.0),
+    ];
+    assert_eq!(
+        suggest_next(&mixed, None),
+        Some(SetSuggestion {
+            weight_kg: 102.5,
+            reps: 5,
+            sets: 1,
+            increased: true,
+        })
+    );
+}
+
+#[test]
+fn progression_follows_the_plan() {
+    scenario!("[WO-042]");
+
+    let top_of_range = [
+        lifting_set(12, 60.0),
+        lifting_set(12, 60.0),
+        lifting_set(12, 60.0),
+    ];
+    assert_eq!(
+        suggest_next(&top_of_range, Some(&plan(3, 8, 12))),
+        Some(SetSuggestion {
+            weight_kg: 62.5,
+            reps: 8,
+            sets: 3,
+            increased: true,
+        })
+    );
+
+    // A planned set was skipped
+    let too_few_sets = [lifting_set(12, 60.0), lifting_set(12, 60.0)];
+    assert_eq!(
+        suggest_next(&too_few_sets, Some(&plan(3, 8, 12))),
+        Some(SetSuggestion {
+            weight_kg: 60.0,
+            reps: 12,
+            sets: 3,
+            increased: false,
+        })
+    );
+
+    // Nothing to progress without loaded working sets
+    let bodyweight = [LiftingSetMetrics {
+        bodyweight: true,
+        ..lifting_set(10, 0.0)
+    }];
+    assert_eq!(suggest_next(&bodyweight, Some(&plan(3, 8, 12))), None);
+    assert_eq!(suggest_next(&[warmup(10, 40.0)], None), None);
+}
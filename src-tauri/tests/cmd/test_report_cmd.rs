@@ -0,0 +1,147 @@
+use crate::helpers::{
+    create_test_intake_entry, create_test_intake_target, create_test_weight_entry,
+    local_noon_timestamp, setup_test_pool, TestPool,
+};
+use librefit_lib::scenario;
+use librefit_lib::service::export::export_period_report;
+use librefit_lib::service::report::{get_period_report, ReportPeriod};
+use librefit_lib::service::workout::{
+    add_workout_set, create_workout_for_date, LiftingSetMetrics, SetKind,
+};
+use tauri::Manager;
+
+/// Intake, weigh-ins and a workout in the ISO weeks of 2026-01-05 and 2026-01-12
+fn report_app() -> tauri::App<tauri::test::MockRuntime> {
+    let pool: TestPool = setup_test_pool();
+    create_test_intake_target(&pool, "2026-01-01", "2026-06-01", 2000, 2500);
+
+    // Previous week
+    create_test_intake_entry(&pool, "2026-01-05", 1800, "b", None);
+    create_test_intake_entry(&pool, "2026-01-06", 3000, "d", None);
+    create_test_weight_entry(&pool, "2026-01-05", 81.0);
+    create_test_weight_entry(&pool, "2026-01-11", 80.4);
+
+    // Current week
+    create_test_intake_entry(&pool, "2026-01-12", 2000, "b", None);
+    create_test_intake_entry(&pool, "2026-01-13", 2600, "d", None);
+    create_test_intake_entry(&pool, "2026-01-14", 1200, "l", None);
+    create_test_intake_entry(&pool, "2026-01-14", 1000, "d", None);
+    create_test_weight_entry(&pool, "2026-01-12", 80.0);
+    create_test_weight_entry(&pool, "2026-01-18", 79.4);
+
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    let workout =
+        create_workout_for_date(app.state(), local_noon_timestamp("2026-01-14"), None).unwrap();
+    // A warm-up set, which doesn't count towards the volume
+    add_workout_set(
+        app.state(),
+        workout.session.id,
+        1,
+        LiftingSetMetrics {
+            reps: Some(10),
+            weight_kg: 40.0,
+            kind: SetKind::Warmup,
+            ..Default::default()
+        },
+        None,
+    )
+    .unwrap();
+    for (reps, weight_kg) in [(5, 100.0), (8, 50.0)] {
+        add_workout_set(
+            app.state(),
+            workout.session.id,
+            1,
+            LiftingSetMetrics {
+                reps: Some(reps),
+                weight_kg,
+                ..Default::default()
+            },
+            None,
+        )
+        .unwrap();
+    }
+
+    app
+}
+
+#[test]
+fn weekly_report_aggregates_the_iso_week() {
+    scenario!("[PG-019]", "[WO-037]");
+    let app = report_app();
+
+    let report =
+        get_period_report(app.state(), ReportPeriod::Week, "2026-01-14".to_string()).unwrap();
+    let current = &report.current;
+
+    assert_eq!(current.date_from, "2026-01-12");
+    assert_eq!(current.date_to, "2026-01-18");
+    assert_eq!(current.days_logged, 3);
+    assert_eq!(current.calories_total, 6800);
+    assert_eq!(current.calories_average, 2266.7);
+    assert_eq!(current.days_over_maximum, 1);
+    // 0.1 kg a day over 7 days
+    assert_eq!(current.weight_change, Some(-0.7));
+    assert_eq!(current.workouts_completed, 1);
+    assert_eq!(current.lifting_volume, 900.0);
+}
+
+#[test]
+fn report_compares_with_the_previous_period() {
+    scenario!("[PG-020]");
+    let app = report_app();
+
+    let report =
+        get_period_report(app.state(), ReportPeriod::Week, "2026-01-18".to_string()).unwrap();
+
+    assert_eq!(report.previous.date_from, "2026-01-05");
+    assert_eq!(report.previous.date_to, "2026-01-11");
+    assert_eq!(report.previous.calories_total, 4800);
+    assert_eq!(report.previous.days_over_maximum, 1);
+    assert_eq!(report.previous.workouts_completed, 0);
+
+    assert_eq!(report.change.calories_total, 2000);
+    assert_eq!(report.change.calories_average, -133.3);
+    assert_eq!(report.change.days_over_maximum, 0);
+    assert_eq!(report.change.weight_change, Some(0.0));
+    assert_eq!(report.change.workouts_completed, 1);
+    assert_eq!(report.change.lifting_volume, 900.0);
+}
+
+#[test]
+fn monthly_report_covers_the_calendar_month() {
+    scenario!("[PG-019]", "[PG-020]");
+    let app = report_app();
+
+    let report =
+        get_period_report(app.state(), ReportPeriod::Month, "2026-01-20".to_string()).unwrap();
+
+    assert_eq!(report.current.date_from, "2026-01-01");
+    assert_eq!(report.current.date_to, "2026-01-31");
+    assert_eq!(report.current.days_logged, 5);
+    assert_eq!(report.current.calories_total, 11600);
+    assert_eq!(report.current.days_over_maximum, 2);
+
+    // Nothing logged in December: no trend to compare with
+    assert_eq!(report.previous.date_from, "2025-12-01");
+    assert_eq!(report.previous.days_logged, 0);
+    assert_eq!(report.previous.calories_average, 0.0);
+    assert_eq!(report.previous.weight_change, None);
+    assert_eq!(report.change.weight_change, None);
+}
+
+#[test]
+fn report_exports_as_csv() {
+    scenario!("[EX-008]");
+    let app = report_app();
+
+    let export =
+        export_period_report(app.state(), ReportPeriod::Week, "2026-01-14".to_string()).unwrap();
+    let csv = String::from_utf8(export.bytes).unwrap();
+
+    assert_eq!(export.file_path, "librefit_report_week_2026-01-12.csv");
+    assert!(csv.starts_with("metric,current,previous,change\n"));
+    assert!(csv.contains("calories_total,6800,4800,2000\n"));
+    assert!(csv.contains("workouts_completed,1,0,1\n"));
+}
@@ -0,0 +1,135 @@
+use crate::helpers::{lifting_set, setup_test_pool, TestPool};
+use chrono::{DateTime, Duration, SecondsFormat, Utc};
+use diesel::prelude::*;
+use librefit_lib::db::schema::{workout_pause, workout_set};
+use librefit_lib::scenario;
+use librefit_lib::service::workout::{
+    add_workout_set, create_workout_for_date, get_active_workout, log_workout_set,
+    start_workout_session, update_workout_set_rest, NewWorkoutPause,
+};
+use tauri::Manager;
+
+fn at(start: DateTime<Utc>, seconds: i64) -> String {
+    (start + Duration::seconds(seconds)).to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+fn set_logged_at(pool: &TestPool, set_id: i32, logged_at: &str) {
+    let mut conn = pool.get().unwrap();
+    diesel::update(workout_set::table.filter(workout_set::id.eq(set_id)))
+        .set(workout_set::logged_at.eq(logged_at))
+        .execute(&mut conn)
+        .unwrap();
+}
+
+/// Three sets of Bench Press and one of Back Squat, logged over the last quarter hour with
+/// a one-minute pause before the third set. Returns the set ids in logging order.
+fn timed_workout(pool: &TestPool, app: &tauri::App<tauri::test::MockRuntime>) -> Vec<i32> {
+    let session = start_workout_session(app.state(), None).unwrap().session;
+    let mut detail = None;
+    for exercise_id in [1, 1, 1, 2] {
+        detail = Some(log_workout_set(app.state(), exercise_id, lifting_set(5, 100.0)).unwrap());
+    }
+    let ids: Vec<i32> = detail
+        .unwrap()
+        .exercises
+        .iter()
+        .flat_map(|e| e.sets.iter().map(|s| s.id))
+        .collect();
+    let start = Utc::now() - Duration::minutes(15);
+    for (id, seconds) in ids.iter().zip([60, 240, 390, 600]) {
+        set_logged_at(pool, *id, &at(start, seconds));
+    }
+    let mut conn = pool.get().unwrap();
+    diesel::insert_into(workout_pause::table)
+        .values(&NewWorkoutPause {
+            session_id: session.id,
+            paused_at: at(start, 300),
+            resumed_at: Some(at(start, 360)),
+        })
+        .execute(&mut conn)
+        .unwrap();
+    ids
+}
+
+#[test]
+fn per_set_rest_override_sets_the_target_after_that_set() {
+    scenario!("[WO-048]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool.clone());
+    let ids = timed_workout(&pool, &app);
+
+    let detail = update_workout_set_rest(app.state(), ids[0], Some(240)).unwrap();
+    let bench = &detail.exercises[0];
+    assert_eq!(bench.sets[0].rest_seconds, Some(240));
+    assert_eq!(bench.sets[0].rest_target_seconds, 240);
+    // Bench Press rests 180 s by default
+    assert_eq!(bench.sets[1].rest_target_seconds, 180);
+    assert_eq!(bench.rest_target_seconds, 180);
+
+    // The countdown follows the latest set
+    let detail = update_workout_set_rest(app.state(), ids[2], Some(300)).unwrap();
+    assert_eq!(detail.exercises[0].rest_target_seconds, 300);
+
+    let detail = update_workout_set_rest(app.state(), ids[2], None).unwrap();
+    assert_eq!(detail.exercises[0].sets[2].rest_seconds, None);
+    assert_eq!(detail.exercises[0].rest_target_seconds, 180);
+
+    assert!(update_workout_set_rest(app.state(), ids[0], Some(-1)).is_err());
+    assert!(update_workout_set_rest(app.state(), ids[0], Some(3601)).is_err());
+}
+
+#[test]
+fn actual_rest_is_the_time_to_the_next_set_without_pauses() {
+    scenario!("[WO-049]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool.clone());
+    timed_workout(&pool, &app);
+
+    let detail = get_active_workout(app.state()).unwrap().unwrap();
+    let rests: Vec<Option<i64>> = detail.exercises[0]
+        .sets
+        .iter()
+        .map(|s| s.actual_rest_seconds)
+        .collect();
+    // 3 minutes; 2.5 minutes with a 1-minute pause; 3.5 minutes until the squat
+    assert_eq!(rests, vec![Some(180), Some(90), Some(210)]);
+    assert_eq!(detail.exercises[0].average_rest_seconds, Some(160));
+    // Nothing followed the last set yet
+    assert_eq!(detail.exercises[1].sets[0].actual_rest_seconds, None);
+    assert_eq!(detail.exercises[1].average_rest_seconds, None);
+}
+
+#[test]
+fn sets_added_to_a_past_workout_have_no_actual_rest() {
+    scenario!("[WO-049]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    let workout =
+        create_workout_for_date(app.state(), "2026-03-02T10:00:00.000Z".to_string(), None).unwrap();
+    add_workout_set(
+        app.state(),
+        workout.session.id,
+        1,
+        lifting_set(5, 100.0),
+        None,
+    )
+    .unwrap();
+    let detail = add_workout_set(
+        app.state(),
+        workout.session.id,
+        1,
+        lifting_set(5, 100.0),
+        None,
+    )
+    .unwrap();
+
+    assert!(detail.exercises[0]
+        .sets
+        .iter()
+        .all(|s| s.actual_rest_seconds.is_none()));
+    assert_eq!(detail.exercises[0].average_rest_seconds, None);
+}
@@ -0,0 +1,308 @@
+use crate::helpers::{history, lifting_set, setup_test_pool};
+use chrono::{Duration, SecondsFormat, Utc};
+use librefit_lib::scenario;
+use librefit_lib::service::personal_record::RecordKind;
+use librefit_lib::service::workout::{
+    add_workout_set, create_workout_for_date, get_active_workout, log_workout_block_set,
+    start_workout_session, update_workout_session, update_workout_set_logged_at, SetMetrics,
+    WorkoutDetail, WorkoutPauseInput, WorkoutSessionInput,
+};
+use tauri::Manager;
+
+fn pause(paused_at: &str, resumed_at: Option<&str>) -> WorkoutPauseInput {
+    WorkoutPauseInput {
+        paused_at: paused_at.to_string(),
+        resumed_at: resumed_at.map(str::to_string),
+    }
+}
+
+fn timing(
+    started_at: &str,
+    ended_at: Option<&str>,
+    pauses: Vec<WorkoutPauseInput>,
+) -> WorkoutSessionInput {
+    WorkoutSessionInput {
+        name: None,
+        started_at: started_at.to_string(),
+        ended_at: ended_at.map(str::to_string),
+        pauses,
+    }
+}
+
+#[test]
+fn update_session_name_and_timing() {
+    scenario!("[WO-054]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    let workout =
+        create_workout_for_date(app.state(), "2026-03-02T10:00:00.000Z".to_string(), None).unwrap();
+    let session_id = workout.session.id;
+    add_workout_set(app.state(), session_id, 1, lifting_set(5, 100.0), None).unwrap();
+    add_workout_set(app.state(), session_id, 1, lifting_set(5, 100.0), None).unwrap();
+
+    // Both sets were logged before a later start
+    assert_eq!(
+        update_workout_session(
+            app.state(),
+            session_id,
+            timing(
+                "2026-03-02T10:05:00.000Z",
+                Some("2026-03-02T11:00:00.000Z"),
+                vec![]
+            ),
+        )
+        .unwrap_err(),
+        "Sets logged outside the new times: Bench Press set 1, Bench Press set 2; move or delete them first"
+    );
+
+    let detail = update_workout_session(
+        app.state(),
+        session_id,
+        WorkoutSessionInput {
+            name: Some("Morning push".to_string()),
+            ..timing(
+                // Offsets are stored in UTC
+                "2026-03-02T10:58:00+01:00",
+                Some("2026-03-02T11:00:00.000Z"),
+                vec![pause(
+                    "2026-03-02T10:30:00.000Z",
+                    Some("2026-03-02T10:40:00.000Z"),
+                )],
+            )
+        },
+    )
+    .unwrap();
+    assert_eq!(detail.session.name.as_deref(), Some("Morning push"));
+    assert_eq!(detail.session.started_at, "2026-03-02T09:58:00.000Z");
+    assert_eq!(
+        detail.session.ended_at.as_deref(),
+        Some("2026-03-02T11:00:00.000Z")
+    );
+    assert_eq!(detail.pauses.len(), 1);
+    assert_eq!(
+        detail.exercises[0].sets[0].logged_at,
+        "2026-03-02T10:00:00.000Z"
+    );
+    assert_eq!(
+        history(&app)[0]
+            .summary
+            .clone()
+            .unwrap()
+            .totals
+            .active_seconds,
+        52 * 60
+    );
+
+    let invalid = [
+        timing(
+            "2026-03-02T10:00:00.000Z",
+            Some("2026-03-02T10:00:00.000Z"),
+            vec![],
+        ),
+        timing("2026-03-02T10:00:00.000Z", None, vec![]),
+        timing(
+            "2026-03-02T10:00:00.000Z",
+            Some("2026-03-02T11:00:00.000Z"),
+            vec![pause(
+                "2026-03-02T09:50:00.000Z",
+                Some("2026-03-02T10:10:00.000Z"),
+            )],
+        ),
+        timing(
+            "2026-03-02T10:00:00.000Z",
+            Some("2026-03-02T11:00:00.000Z"),
+            vec![pause(
+                "2026-03-02T10:50:00.000Z",
+                Some("2026-03-02T11:10:00.000Z"),
+            )],
+        ),
+        timing(
+            "2026-03-02T10:00:00.000Z",
+            Some("2026-03-02T11:00:00.000Z"),
+            vec![
+                pause("2026-03-02T10:10:00.000Z", Some("2026-03-02T10:30:00.000Z")),
+                pause("2026-03-02T10:20:00.000Z", Some("2026-03-02T10:40:00.000Z")),
+            ],
+        ),
+        timing(
+            "2026-03-02T10:00:00.000Z",
+            Some("2026-03-02T11:00:00.000Z"),
+            vec![pause("2026-03-02T10:10:00.000Z", None)],
+        ),
+    ];
+    for input in invalid {
+        assert!(update_workout_session(app.state(), session_id, input).is_err());
+    }
+}
+
+#[test]
+fn update_active_session_timing() {
+    scenario!("[WO-054]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    let session_id = start_workout_session(app.state(), None).unwrap().session.id;
+    let ago = |minutes: i64| {
+        (Utc::now() - Duration::minutes(minutes)).to_rfc3339_opts(SecondsFormat::Millis, true)
+    };
+
+    let started_at = ago(30);
+    let detail = update_workout_session(
+        app.state(),
+        session_id,
+        timing(&started_at, None, vec![pause(&ago(5), None)]),
+    )
+    .unwrap();
+    assert_eq!(detail.session.started_at, started_at);
+    assert!(detail.pauses[0].resumed_at.is_none());
+    assert!(get_active_workout(app.state()).unwrap().is_some());
+
+    // An active workout is ended by ending it, and cannot start later than now
+    assert!(update_workout_session(
+        app.state(),
+        session_id,
+        timing(&ago(30), Some(&ago(1)), vec![])
+    )
+    .is_err());
+    assert!(
+        update_workout_session(app.state(), session_id, timing(&ago(-10), None, vec![])).is_err()
+    );
+}
+
+#[test]
+fn set_logged_at_can_be_edited_within_the_session() {
+    scenario!("[WO-055]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    let workout =
+        create_workout_for_date(app.state(), "2026-03-02T10:00:00.000Z".to_string(), None).unwrap();
+    let session_id = workout.session.id;
+    add_workout_set(app.state(), session_id, 1, lifting_set(5, 100.0), None).unwrap();
+    add_workout_set(app.state(), session_id, 1, lifting_set(5, 110.0), None).unwrap();
+    let detail = add_workout_set(app.state(), session_id, 1, lifting_set(5, 90.0), None).unwrap();
+    let ids: Vec<i32> = detail.exercises[0].sets.iter().map(|s| s.id).collect();
+
+    // A backfilled workout starts and ends at once; give it a duration first
+    assert!(update_workout_set_logged_at(
+        app.state(),
+        ids[1],
+        "2026-03-02T10:03:00.000Z".to_string()
+    )
+    .is_err());
+    update_workout_session(
+        app.state(),
+        session_id,
+        timing(
+            "2026-03-02T10:00:00.000Z",
+            Some("2026-03-02T10:30:00.000Z"),
+            vec![],
+        ),
+    )
+    .unwrap();
+
+    update_workout_set_logged_at(app.state(), ids[1], "2026-03-02T10:03:00.000Z".to_string())
+        .unwrap();
+    update_workout_set_logged_at(app.state(), ids[2], "2026-03-02T10:06:00.000Z".to_string())
+        .unwrap();
+    // The first set now comes last
+    let detail =
+        update_workout_set_logged_at(app.state(), ids[0], "2026-03-02T10:09:00.000Z".to_string())
+            .unwrap();
+    let sets = &detail.exercises[0].sets;
+    assert_eq!(sets[0].logged_at, "2026-03-02T10:09:00.000Z");
+    assert_eq!(sets[1].actual_rest_seconds, Some(180));
+    assert_eq!(sets[2].actual_rest_seconds, Some(180));
+    assert_eq!(sets[0].actual_rest_seconds, None);
+    // 100 kg no longer beats the earlier 110 kg
+    assert!(!sets[0]
+        .personal_records
+        .contains(&RecordKind::HeaviestWeight));
+    assert!(sets[1]
+        .personal_records
+        .contains(&RecordKind::HeaviestWeight));
+
+    for outside in [
+        "2026-03-02T09:59:00.000Z",
+        "2026-03-02T10:31:00.000Z",
+        "yesterday",
+    ] {
+        assert!(update_workout_set_logged_at(app.state(), ids[0], outside.to_string()).is_err());
+    }
+}
+
+#[test]
+fn sets_added_to_a_past_workout_follow_their_block() {
+    scenario!("[WO-055]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    let workout =
+        create_workout_for_date(app.state(), "2026-03-02T10:00:00.000Z".to_string(), None).unwrap();
+    let session_id = workout.session.id;
+    update_workout_session(
+        app.state(),
+        session_id,
+        timing(
+            "2026-03-02T10:00:00.000Z",
+            Some("2026-03-02T10:30:00.000Z"),
+            vec![],
+        ),
+    )
+    .unwrap();
+    let logged_at = |detail: &WorkoutDetail| -> Vec<String> {
+        detail.exercises[0]
+            .sets
+            .iter()
+            .map(|s| s.logged_at.clone())
+            .collect()
+    };
+
+    add_workout_set(app.state(), session_id, 1, lifting_set(5, 100.0), None).unwrap();
+    let detail = add_workout_set(app.state(), session_id, 1, lifting_set(5, 100.0), None).unwrap();
+    assert_eq!(
+        logged_at(&detail),
+        ["2026-03-02T10:00:00.000Z", "2026-03-02T10:01:00.000Z"]
+    );
+    assert_eq!(detail.exercises[0].sets[1].actual_rest_seconds, Some(60));
+
+    // A given time is kept, and the next set follows it up to the end of the workout
+    let block_id = detail.exercises[0].id;
+    log_workout_block_set(
+        app.state(),
+        block_id,
+        SetMetrics::Lifting(lifting_set(5, 100.0)),
+        Some("2026-03-02T10:29:30.000Z".to_string()),
+    )
+    .unwrap();
+    let detail = log_workout_block_set(
+        app.state(),
+        block_id,
+        SetMetrics::Lifting(lifting_set(5, 100.0)),
+        None,
+    )
+    .unwrap();
+    assert_eq!(
+        logged_at(&detail)[2..],
+        ["2026-03-02T10:29:30.000Z", "2026-03-02T10:30:00.000Z"]
+    );
+
+    for outside in ["2026-03-02T09:59:00.000Z", "2026-03-02T10:31:00.000Z"] {
+        assert_eq!(
+            add_workout_set(
+                app.state(),
+                session_id,
+                1,
+                lifting_set(5, 100.0),
+                Some(outside.to_string())
+            )
+            .unwrap_err(),
+            "A set must be logged within its workout"
+        );
+    }
+}
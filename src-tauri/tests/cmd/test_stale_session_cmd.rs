@@ -0,0 +1,121 @@
+use crate::helpers::{setup_test_pool, TestPool};
+use chrono::{Duration, SecondsFormat, Utc};
+use diesel::prelude::*;
+use librefit_lib::db::schema::workout_session;
+use librefit_lib::scenario;
+use librefit_lib::service::settings::{get_app_settings, update_app_settings, AppSettingsInput};
+use librefit_lib::service::workout::{
+    end_workout_session, get_active_workout, list_workouts, start_workout_session, WorkoutSession,
+};
+use tauri::Manager;
+
+fn backdate_start(pool: &TestPool, hours: i64) -> String {
+    let started_at =
+        (Utc::now() - Duration::hours(hours)).to_rfc3339_opts(SecondsFormat::Millis, true);
+    let mut conn = pool.get().unwrap();
+    diesel::update(workout_session::table.filter(workout_session::ended_at.is_null()))
+        .set(workout_session::started_at.eq(&started_at))
+        .execute(&mut conn)
+        .unwrap();
+    started_at
+}
+
+fn stale_after(app: &tauri::App<tauri::test::MockRuntime>, hours: Option<i32>) {
+    update_app_settings(
+        app.state(),
+        AppSettingsInput {
+            add_workout_calories: false,
+            stale_session_hours: hours,
+            trash_retention_days: 30,
+        },
+    )
+    .unwrap();
+}
+
+fn sessions(app: &tauri::App<tauri::test::MockRuntime>) -> Vec<WorkoutSession> {
+    list_workouts(
+        app.state(),
+        "2000-01-01T00:00:00Z".to_string(),
+        "2100-01-01T00:00:00Z".to_string(),
+    )
+    .unwrap()
+    .into_iter()
+    .map(|w| w.session)
+    .collect()
+}
+
+#[test]
+fn stale_threshold_is_configurable() {
+    scenario!("[WO-052]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool.clone());
+
+    assert_eq!(
+        get_app_settings(app.state()).unwrap().stale_session_hours,
+        Some(4)
+    );
+
+    start_workout_session(app.state(), None).unwrap();
+    backdate_start(&pool, 6);
+    stale_after(&app, Some(8));
+    assert!(get_active_workout(app.state()).unwrap().is_some());
+
+    stale_after(&app, Some(5));
+    assert!(get_active_workout(app.state()).unwrap().is_none());
+
+    // Never auto-completing keeps even a day-old session active
+    start_workout_session(app.state(), None).unwrap();
+    backdate_start(&pool, 30);
+    stale_after(&app, None);
+    assert!(get_active_workout(app.state()).unwrap().is_some());
+    assert_eq!(
+        get_app_settings(app.state()).unwrap().stale_session_hours,
+        None
+    );
+
+    for hours in [0, 169] {
+        let result = update_app_settings(
+            app.state(),
+            AppSettingsInput {
+                add_workout_calories: false,
+                stale_session_hours: Some(hours),
+                trash_retention_days: 30,
+            },
+        );
+        assert!(result.is_err());
+    }
+}
+
+#[test]
+fn auto_completed_sessions_are_flagged() {
+    scenario!("[WO-053]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool.clone());
+
+    start_workout_session(app.state(), None).unwrap();
+    end_workout_session(app.state()).unwrap();
+
+    start_workout_session(app.state(), None).unwrap();
+    let started_at = backdate_start(&pool, 10);
+    {
+        // The check that runs at app start
+        let mut conn = pool.get().unwrap();
+        WorkoutSession::auto_complete_if_stale(&mut conn).unwrap();
+    }
+
+    let sessions = sessions(&app);
+    let forgotten = sessions
+        .iter()
+        .find(|s| s.started_at == started_at)
+        .unwrap();
+    assert!(forgotten.auto_completed);
+    // Ended at the last activity, not 10 hours later
+    assert_eq!(forgotten.ended_at.as_deref(), Some(started_at.as_str()));
+    let ended = sessions
+        .iter()
+        .find(|s| s.started_at != started_at)
+        .unwrap();
+    assert!(!ended.auto_completed);
+}
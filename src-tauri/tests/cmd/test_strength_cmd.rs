@@ -0,0 +1,174 @@
+use crate::helpers::{
+    birth_date_for_age, create_test_weight_entry, lifting_set, local_noon_timestamp,
+    setup_test_pool,
+};
+use librefit_lib::scenario;
+use librefit_lib::service::body::update_body_data;
+use librefit_lib::service::exercise::{create_exercise, ExerciseInput, ExerciseMuscleInput};
+use librefit_lib::service::strength::{
+    get_strength_scores, tag_strength_lift, StrengthLift, StrengthScores,
+};
+use librefit_lib::service::workout::{
+    add_workout_set, create_workout_for_date, LiftingSetMetrics, SetKind,
+};
+use tauri::Manager;
+
+/// A past workout on a local date with sets of (exercise, reps, kg)
+fn workout_on(app: &tauri::App<tauri::test::MockRuntime>, date: &str, sets: &[(i32, i32, f64)]) {
+    let session_id = create_workout_for_date(app.state(), local_noon_timestamp(date), None)
+        .unwrap()
+        .session
+        .id;
+    for (exercise_id, reps, weight_kg) in sets {
+        add_workout_set(
+            app.state(),
+            session_id,
+            *exercise_id,
+            lifting_set(*reps, *weight_kg),
+            None,
+        )
+        .unwrap();
+    }
+}
+
+fn body_data(app: &tauri::App<tauri::test::MockRuntime>, sex: &str) {
+    update_body_data(
+        app.state(),
+        birth_date_for_age(30),
+        180.0,
+        90.0,
+        sex.to_string(),
+        1.5,
+        None,
+    )
+    .unwrap();
+}
+
+fn scores(app: &tauri::App<tauri::test::MockRuntime>, from: &str, to: &str) -> StrengthScores {
+    get_strength_scores(app.state(), from.to_string(), to.to_string()).unwrap()
+}
+
+#[test]
+fn strength_scores_follow_the_best_lifts_and_body_weight() {
+    scenario!("[PG-025]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool.clone());
+
+    // The sex comes from the body data
+    assert!(get_strength_scores(app.state(), "2026-03-01".into(), "2026-03-31".into()).is_err());
+    body_data(&app, "MALE");
+    create_test_weight_entry(&pool, "2026-03-01", 90.0);
+    create_test_weight_entry(&pool, "2026-03-15", 87.0);
+
+    // Exercises 1-3 are bench press, back squat and deadlift
+    workout_on(
+        &app,
+        "2026-03-02",
+        &[(2, 5, 140.0), (1, 5, 100.0), (3, 3, 180.0)],
+    );
+    let session_id = create_workout_for_date(app.state(), local_noon_timestamp("2026-03-16"), None)
+        .unwrap()
+        .session
+        .id;
+    add_workout_set(
+        app.state(),
+        session_id,
+        1,
+        LiftingSetMetrics {
+            kind: SetKind::Warmup,
+            ..lifting_set(5, 110.0)
+        },
+        None,
+    )
+    .unwrap();
+    add_workout_set(app.state(), session_id, 1, lifting_set(5, 105.0), None).unwrap();
+
+    let result = scores(&app, "2026-03-01", "2026-03-31");
+    assert_eq!(result.points.len(), 2);
+    let first = &result.points[0];
+    assert_eq!(first.date, "2026-03-02");
+    // Epley estimates
+    assert_eq!(first.squat_kg, Some(163.3));
+    assert_eq!(first.bench_kg, Some(116.7));
+    assert_eq!(first.deadlift_kg, Some(198.0));
+    assert_eq!(first.total_kg, Some(478.0));
+    assert_eq!(first.body_weight_kg, Some(90.0));
+    assert_eq!(first.wilks, Some(305.15));
+    assert_eq!(first.dots, Some(309.08));
+    assert_eq!(first.ipf_gl, Some(63.55));
+
+    // Squat and deadlift carry over within the window; the warm-up doesn't count
+    let second = &result.points[1];
+    assert_eq!(second.bench_kg, Some(122.5));
+    assert_eq!(second.total_kg, Some(483.8));
+    assert_eq!(second.body_weight_kg, Some(87.0));
+    assert_eq!(second.wilks, Some(314.41));
+    assert_eq!(second.dots, Some(318.34));
+    assert_eq!(second.ipf_gl, Some(65.41));
+
+    // Past the 28-day window only the bench press is left, so there is no total
+    workout_on(&app, "2026-04-20", &[(1, 5, 100.0)]);
+    let later = scores(&app, "2026-04-01", "2026-04-30");
+    assert_eq!(later.points.len(), 1);
+    assert_eq!(later.points[0].squat_kg, None);
+    assert_eq!(later.points[0].total_kg, None);
+    assert_eq!(later.points[0].wilks, None);
+
+    assert!(scores(&app, "2026-05-01", "2026-05-31").points.is_empty());
+}
+
+#[test]
+fn tagged_exercises_count_as_their_lift() {
+    scenario!("[PG-026]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool.clone());
+    body_data(&app, "FEMALE");
+    create_test_weight_entry(&pool, "2026-03-01", 60.0);
+
+    let pause_squat = create_exercise(
+        app.state(),
+        ExerciseInput {
+            name: "Pause Squat".to_string(),
+            category: "barbell".to_string(),
+            default_rest_seconds: None,
+            workout_type: "wl".to_string(),
+            muscles: vec![ExerciseMuscleInput {
+                muscle: "quadriceps".to_string(),
+                role: "primary".to_string(),
+            }],
+        },
+    )
+    .unwrap();
+    workout_on(
+        &app,
+        "2026-03-02",
+        &[
+            (2, 1, 100.0),
+            (pause_squat.id, 1, 110.0),
+            (1, 1, 60.0),
+            (3, 1, 130.0),
+        ],
+    );
+
+    // Untagged exercises don't count
+    let point = scores(&app, "2026-03-01", "2026-03-31").points[0].clone();
+    assert_eq!(point.squat_kg, Some(100.0));
+
+    let tagged = tag_strength_lift(app.state(), pause_squat.id, Some(StrengthLift::Squat)).unwrap();
+    assert_eq!(tagged.strength_lift.as_deref(), Some("squat"));
+    let point = scores(&app, "2026-03-01", "2026-03-31").points[0].clone();
+    assert_eq!(point.squat_kg, Some(110.0));
+    assert_eq!(point.total_kg, Some(300.0));
+    assert!(point.wilks.is_some());
+
+    // Seeded lifts can be untagged
+    tag_strength_lift(app.state(), 1, None).unwrap();
+    let point = scores(&app, "2026-03-01", "2026-03-31").points[0].clone();
+    assert_eq!(point.bench_kg, None);
+    assert_eq!(point.total_kg, None);
+
+    // Only weight-lifting exercises are lifts
+    assert!(tag_strength_lift(app.state(), 50, Some(StrengthLift::Squat)).is_err());
+}
@@ -0,0 +1,209 @@
+use crate::helpers::{
+    create_test_intake_entry, create_test_weight_entry, history, lifting_set, setup_test_pool,
+};
+use diesel::prelude::*;
+use librefit_lib::db::schema::{intake, workout_session};
+use librefit_lib::scenario;
+use librefit_lib::service::calendar::get_activity_dates_in_range;
+use librefit_lib::service::intake::{
+    delete_intake, get_intake_for_date_range, update_intake, NewIntake,
+};
+use librefit_lib::service::personal_record::get_personal_records;
+use librefit_lib::service::settings::{update_app_settings, AppSettingsInput};
+use librefit_lib::service::trash::{
+    empty_trash, get_trash, purge_expired, restore_from_trash, TrashKind,
+};
+use librefit_lib::service::weight::{
+    delete_weight_tracker_entry, get_weight_tracker_for_date_range, update_weight_tracker_entry,
+    NewWeightTracker,
+};
+use librefit_lib::service::workout::{add_workout_set, create_workout_for_date, delete_workout};
+use tauri::Manager;
+
+/// Logs an intake entry, a weigh-in and a bench press workout on 2026-03-02 and deletes
+/// them all. Returns their ids.
+fn trash_a_day(
+    pool: &crate::helpers::TestPool,
+    app: &tauri::App<tauri::test::MockRuntime>,
+) -> (i32, i32, i32) {
+    let intake = create_test_intake_entry(pool, "2026-03-02", 650, "l", None);
+    let weight = create_test_weight_entry(pool, "2026-03-02", 81.5);
+    let workout =
+        create_workout_for_date(app.state(), "2026-03-02T10:00:00.000Z".to_string(), None).unwrap();
+    add_workout_set(
+        app.state(),
+        workout.session.id,
+        1,
+        lifting_set(5, 100.0),
+        None,
+    )
+    .unwrap();
+
+    delete_intake(app.state(), intake.id).unwrap();
+    delete_weight_tracker_entry(app.state(), weight.id).unwrap();
+    delete_workout(app.state(), workout.session.id).unwrap();
+    (intake.id, weight.id, workout.session.id)
+}
+
+#[test]
+fn deleted_data_is_hidden_and_listed_in_the_trash() {
+    scenario!("[HI-029]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool.clone());
+
+    let (intake_id, weight_id, session_id) = trash_a_day(&pool, &app);
+
+    let day = || ("2026-03-02".to_string(), "2026-03-02".to_string());
+    assert!(get_intake_for_date_range(app.state(), day().0, day().1)
+        .unwrap()
+        .is_empty());
+    assert!(
+        get_weight_tracker_for_date_range(app.state(), day().0, day().1)
+            .unwrap()
+            .is_empty()
+    );
+    assert!(history(&app).is_empty());
+    assert!(get_activity_dates_in_range(app.state(), day().0, day().1)
+        .unwrap()
+        .is_empty());
+    assert!(get_personal_records(app.state(), 1)
+        .unwrap()
+        .heaviest_weight
+        .is_none());
+    // Deleting again changes nothing
+    assert_eq!(delete_intake(app.state(), intake_id).unwrap(), 0);
+
+    let trash = get_trash(app.state()).unwrap();
+    assert_eq!(trash.intake.len(), 1);
+    assert_eq!(trash.intake[0].id, intake_id);
+    assert!(trash.intake[0].deleted_at.is_some());
+    assert_eq!(trash.weight[0].id, weight_id);
+    assert_eq!(trash.workouts.len(), 1);
+    assert_eq!(trash.workouts[0].session.id, session_id);
+    assert_eq!(trash.workouts[0].exercises[0].sets.len(), 1);
+}
+
+#[test]
+fn trashed_entries_cannot_be_edited() {
+    scenario!("[HI-029]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool.clone());
+
+    let (intake_id, weight_id, _) = trash_a_day(&pool, &app);
+
+    let intake = NewIntake::new("2026-03-02".to_string(), 700, "l".to_string(), None);
+    assert!(update_intake(app.state(), intake_id, intake).is_err());
+    let weight = NewWeightTracker::new("2026-03-02".to_string(), 80.0);
+    assert!(update_weight_tracker_entry(app.state(), weight_id, weight).is_err());
+
+    let trash = get_trash(app.state()).unwrap();
+    assert_eq!(trash.intake[0].amount, 650);
+    assert_eq!(trash.weight[0].amount, 81.5);
+}
+
+#[test]
+fn restoring_from_the_trash_brings_data_back() {
+    scenario!("[HI-030]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool.clone());
+
+    let (intake_id, weight_id, session_id) = trash_a_day(&pool, &app);
+
+    restore_from_trash(app.state(), TrashKind::Intake, intake_id).unwrap();
+    restore_from_trash(app.state(), TrashKind::Weight, weight_id).unwrap();
+    restore_from_trash(app.state(), TrashKind::Workout, session_id).unwrap();
+
+    let activity = get_activity_dates_in_range(
+        app.state(),
+        "2026-03-02".to_string(),
+        "2026-03-02".to_string(),
+    )
+    .unwrap();
+    assert_eq!(activity.len(), 1);
+    assert!(activity[0].intake && activity[0].weight && activity[0].workout);
+    assert_eq!(history(&app)[0].session.id, session_id);
+    assert_eq!(
+        get_personal_records(app.state(), 1)
+            .unwrap()
+            .heaviest_weight
+            .unwrap()
+            .value,
+        100.0
+    );
+
+    let trash = get_trash(app.state()).unwrap();
+    assert!(trash.intake.is_empty() && trash.weight.is_empty() && trash.workouts.is_empty());
+    assert_eq!(
+        restore_from_trash(app.state(), TrashKind::Intake, intake_id).unwrap_err(),
+        "The entry is not in the trash"
+    );
+    assert_eq!(
+        restore_from_trash(app.state(), TrashKind::Workout, session_id).unwrap_err(),
+        "The workout is not in the trash"
+    );
+}
+
+#[test]
+fn emptying_the_trash_purges_everything_in_it() {
+    scenario!("[HI-031]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool.clone());
+
+    let (_, _, session_id) = trash_a_day(&pool, &app);
+    let kept = create_test_intake_entry(&pool, "2026-03-03", 400, "b", None);
+
+    assert_eq!(empty_trash(app.state()).unwrap(), 3);
+    let trash = get_trash(app.state()).unwrap();
+    assert!(trash.intake.is_empty() && trash.weight.is_empty() && trash.workouts.is_empty());
+
+    let mut conn = pool.get().unwrap();
+    let intake_ids: Vec<i32> = intake::table.select(intake::id).load(&mut conn).unwrap();
+    assert_eq!(intake_ids, vec![kept.id]);
+    let sessions: i64 = workout_session::table
+        .filter(workout_session::id.eq(session_id))
+        .count()
+        .get_result(&mut conn)
+        .unwrap();
+    assert_eq!(sessions, 0);
+}
+
+#[test]
+fn trashed_data_is_purged_after_the_retention() {
+    scenario!("[HI-031]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool.clone());
+
+    let old = create_test_intake_entry(&pool, "2026-03-01", 650, "l", None);
+    let recent = create_test_intake_entry(&pool, "2026-03-02", 400, "b", None);
+    delete_intake(app.state(), old.id).unwrap();
+    delete_intake(app.state(), recent.id).unwrap();
+    {
+        let mut conn = pool.get().unwrap();
+        diesel::update(intake::table.filter(intake::id.eq(old.id)))
+            .set(intake::deleted_at.eq("2020-01-01T00:00:00.000Z"))
+            .execute(&mut conn)
+            .unwrap();
+        // As on startup
+        assert_eq!(purge_expired(&mut conn).unwrap(), 1);
+    }
+
+    let trash = get_trash(app.state()).unwrap();
+    assert_eq!(trash.intake.len(), 1);
+    assert_eq!(trash.intake[0].id, recent.id);
+
+    let settings = |days: i32| AppSettingsInput {
+        add_workout_calories: false,
+        stale_session_hours: Some(4),
+        trash_retention_days: days,
+    };
+    for days in [0, 366] {
+        assert!(update_app_settings(app.state(), settings(days)).is_err());
+    }
+    let updated = update_app_settings(app.state(), settings(7)).unwrap();
+    assert_eq!(updated.trash_retention_days, 7);
+}
@@ -3,25 +3,27 @@ use std::collections::HashMap;
 use chrono::{Days, NaiveDate};
 
 use librefit_lib::scenario;
+use librefit_lib::service::body::{BodyData, NewBodyData};
 use librefit_lib::service::intake::{IntakeTarget, NewIntakeTarget};
 use librefit_lib::service::weight::{
     NewWeightTarget, NewWeightTracker, WeightTarget, WeightTracker,
 };
 use librefit_lib::service::wizard::{
-    wizard_calculate_for_target_date, wizard_calculate_for_target_weight, wizard_calculate_tdee,
-    wizard_create_targets, BmiCategory, CalculationGoal, CalculationSex, Wizard, WizardInput,
-    WizardResult, WizardTargetDateInput, WizardTargetWeightInput,
+    calculate_as_of, wizard_calculate_for_target_date, wizard_calculate_for_target_weight,
+    wizard_calculate_tdee, wizard_calculate_tdee_for_date, wizard_create_targets, BmiCategory,
+    CalculationGoal, CalculationSex, Wizard, WizardInput, WizardResult, WizardTargetDateInput,
+    WizardTargetWeightInput,
 };
 use tauri::Manager;
-use validator::Validate;
+use validator::ValidationErrors;
 
-use crate::helpers::setup_test_pool;
+use crate::helpers::{birth_date_for_age, setup_test_pool};
 
 #[test]
 fn weight_loss_calculation_for_men() {
     scenario!("[OB-009]", "[OB-013]");
     let input: WizardInput = WizardInput {
-        age: 30,
+        birth_date: birth_date_for_age(30),
         weight: 90.0,
         height: 180.0,
         sex: CalculationSex::MALE,
@@ -54,7 +56,7 @@ fn weight_loss_calculation_for_men() {
 fn weight_gain_for_women_in_hold_range() {
     scenario!("[OB-014]");
     let input = WizardInput {
-        age: 25,
+        birth_date: birth_date_for_age(25),
         weight: 52.0,
         height: 155.0,
         weekly_difference: 1,
@@ -88,7 +90,7 @@ fn weight_gain_for_women_in_hold_range() {
 fn underweight_classification_for_men() {
     scenario!("[OB-011]");
     let input_underweight = WizardInput {
-        age: 25,
+        birth_date: birth_date_for_age(25),
         weight: 59.7,
         height: 180.0,
         weekly_difference: 0,
@@ -107,7 +109,7 @@ fn underweight_classification_for_men() {
 fn obese_classification_for_men() {
     scenario!("[OB-009]");
     let input_obese = WizardInput {
-        age: 25,
+        birth_date: birth_date_for_age(25),
         weight: 125.0,
         height: 180.0,
         weekly_difference: 0,
@@ -126,7 +128,7 @@ fn obese_classification_for_men() {
 fn severely_obese_classification_for_men() {
     scenario!("[OB-009]");
     let input_severely_obese = WizardInput {
-        age: 45,
+        birth_date: birth_date_for_age(45),
         weight: 150.0,
         height: 180.0,
         weekly_difference: 0,
@@ -148,7 +150,7 @@ fn severely_obese_classification_for_men() {
 fn obese_classification_for_women() {
     scenario!("[OB-009]");
     let input_obese = WizardInput {
-        age: 30,
+        birth_date: birth_date_for_age(30),
         weight: 80.0,
         height: 160.0,
         weekly_difference: 0,
@@ -167,7 +169,7 @@ fn obese_classification_for_women() {
 fn underweight_classification_for_women() {
     scenario!("[OB-011]");
     let input_underweight = WizardInput {
-        age: 18,
+        birth_date: birth_date_for_age(18),
         weight: 40.0,
         height: 150.0,
         weekly_difference: 0,
@@ -186,7 +188,7 @@ fn underweight_classification_for_women() {
 fn severely_obese_classification_for_women() {
     scenario!("[OB-009]");
     let input_severely_obese = WizardInput {
-        age: 45,
+        birth_date: birth_date_for_age(45),
         weight: 120.0,
         height: 165.0,
         weekly_difference: 0,
@@ -211,7 +213,7 @@ fn caclulate_target_date_weight_loss() {
     let target_date_nd = start_date_nd.checked_add_days(Days::new(150)).unwrap();
 
     let input_target_date = WizardTargetDateInput {
-        age: 30,
+        birth_date: birth_date_for_age(30),
         sex: CalculationSex::MALE,
         current_weight: 90.0,
         height: 180.0,
@@ -234,7 +236,7 @@ fn calculate_target_weight_date() {
     let start_date_nd = NaiveDate::from_ymd_opt(2025, 01, 01).unwrap();
 
     let input_target_weight = WizardTargetWeightInput {
-        age: 30,
+        birth_date: birth_date_for_age(30),
         sex: CalculationSex::MALE,
         current_weight: 90.0,
         height: 180.0,
@@ -302,8 +304,9 @@ fn calculate_target_weight_date() {
 /// Verfiy [WizardInput] validation and expected error codes.
 #[test]
 fn return_validation_errors() {
+    scenario!("[OB-022]");
     let invalid_input = WizardInput {
-        age: 13,
+        birth_date: birth_date_for_age(13),
         weight: 20.0,
         height: 340.0,
         weekly_difference: 8,
@@ -312,8 +315,8 @@ fn return_validation_errors() {
         sex: CalculationSex::FEMALE,
     };
 
-    let validation_errors = invalid_input.validate().unwrap_err();
-    let age_error = validation_errors.field_errors().get("age").unwrap()[0]
+    let validation_errors = wizard_calculate_tdee(invalid_input).unwrap_err();
+    let age_error = validation_errors.field_errors().get("birth_date").unwrap()[0]
         .code
         .clone();
 
@@ -353,7 +356,7 @@ fn return_validation_errors() {
 #[test]
 fn return_target_date_validation_errors() {
     let invalid_target_date_input = WizardTargetDateInput {
-        age: 100,
+        birth_date: birth_date_for_age(100),
         sex: CalculationSex::FEMALE,
         current_weight: 29.9,
         height: 99.9,
@@ -362,9 +365,10 @@ fn return_target_date_validation_errors() {
         target_date: "2024-06-01".to_string(),
     };
 
-    let validation_errors = invalid_target_date_input.validate().unwrap_err();
+    let validation_errors =
+        wizard_calculate_for_target_date(invalid_target_date_input).unwrap_err();
 
-    let age_error = validation_errors.field_errors().get("age").unwrap()[0]
+    let age_error = validation_errors.field_errors().get("birth_date").unwrap()[0]
         .code
         .clone();
 
@@ -388,7 +392,7 @@ fn return_target_date_validation_errors() {
 #[test]
 fn return_target_weight_validation_errors() {
     let invalid_target_weight_input = WizardTargetWeightInput {
-        age: 100,
+        birth_date: birth_date_for_age(100),
         sex: CalculationSex::MALE,
         height: 220.1,
         current_weight: 300.1,
@@ -396,9 +400,10 @@ fn return_target_weight_validation_errors() {
         start_date: "2024-06-01".to_string(),
     };
 
-    let validation_errors = invalid_target_weight_input.validate().unwrap_err();
+    let validation_errors =
+        wizard_calculate_for_target_weight(invalid_target_weight_input).unwrap_err();
 
-    let age_error = validation_errors.field_errors().get("age").unwrap()[0]
+    let age_error = validation_errors.field_errors().get("birth_date").unwrap()[0]
         .code
         .clone();
 
@@ -430,7 +435,7 @@ fn return_target_weight_validation_errors() {
 #[test]
 fn return_underweight_classification() {
     let underweight_target_weight_input = WizardTargetWeightInput {
-        age: 30,
+        birth_date: birth_date_for_age(30),
         sex: CalculationSex::MALE,
         current_weight: 60.0,
         height: 170.0,
@@ -448,7 +453,7 @@ fn return_underweight_classification() {
 #[test]
 fn return_obese_classification() {
     let obese_target_weight_input = WizardTargetWeightInput {
-        age: 30,
+        birth_date: birth_date_for_age(30),
         sex: CalculationSex::MALE,
         current_weight: 60.0,
         height: 170.0,
@@ -466,7 +471,7 @@ fn return_obese_classification() {
 #[test]
 fn return_severely_obese_classification() {
     let severely_obese_target_weight_input = WizardTargetWeightInput {
-        age: 30,
+        birth_date: birth_date_for_age(30),
         sex: CalculationSex::MALE,
         current_weight: 60.0,
         height: 170.0,
@@ -484,7 +489,7 @@ fn return_severely_obese_classification() {
 fn return_underweight_warning() {
     scenario!("[OB-012]");
     let underweight_target_weight_input = WizardTargetWeightInput {
-        age: 30,
+        birth_date: birth_date_for_age(30),
         sex: CalculationSex::MALE,
         current_weight: 50.0, // currently underweight
         height: 170.0,
@@ -502,7 +507,7 @@ fn return_underweight_warning() {
 #[test]
 fn return_obese_warning() {
     let obese_target_weight_input = WizardTargetWeightInput {
-        age: 30,
+        birth_date: birth_date_for_age(30),
         sex: CalculationSex::MALE,
         current_weight: 100.0, // currently obese
         height: 170.0,
@@ -520,7 +525,7 @@ fn return_obese_warning() {
 #[test]
 fn return_severely_obese_warning() {
     let severely_obese_target_weight_input = WizardTargetWeightInput {
-        age: 30,
+        birth_date: birth_date_for_age(30),
         sex: CalculationSex::MALE,
         current_weight: 150.0, // currently severely obese
         height: 170.0,
@@ -551,7 +556,7 @@ fn calculate_weight_loss_duration() {
     let calculation_start_date_nd = NaiveDate::from_ymd_opt(2025, 01, 01).unwrap();
 
     let input = WizardTargetWeightInput {
-        age: 30,
+        birth_date: birth_date_for_age(30),
         sex: CalculationSex::MALE,
         current_weight: 100.0,
         height: 170.0,
@@ -595,7 +600,7 @@ fn calculate_weight_gain_duration() {
     let calculation_start_date_nd = NaiveDate::from_ymd_opt(2025, 01, 01).unwrap();
 
     let input = WizardTargetWeightInput {
-        age: 30,
+        birth_date: birth_date_for_age(30),
         sex: CalculationSex::FEMALE,
         current_weight: 50.0,
         height: 155.0,
@@ -652,7 +657,7 @@ fn calculate_target_weights_for_specific_weight_loss_goal() {
     .collect();
 
     let wizard_target_date_input = WizardTargetDateInput {
-        age: 30,
+        birth_date: birth_date_for_age(30),
         height: 170.0,
         current_weight: 85.0,
         sex: CalculationSex::MALE,
@@ -706,7 +711,7 @@ fn calculate_target_weights_for_specific_weight_gain_goal() {
     .collect();
 
     let wizard_target_date_input = WizardTargetDateInput {
-        age: 30,
+        birth_date: birth_date_for_age(30),
         height: 155.0,
         current_weight: 45.0,
         sex: CalculationSex::FEMALE,
@@ -775,7 +780,7 @@ fn wizard_create_targets_persists_all_three_records() {
 fn bmi_in_hold_range_yields_standard_weight() {
     scenario!("[OB-010]");
     let input = WizardInput {
-        age: 30,
+        birth_date: birth_date_for_age(30),
         weight: 70.0, // BMI = 70 / 1.75^2 ≈ 22.86 → StandardWeight
         height: 175.0,
         sex: CalculationSex::MALE,
@@ -820,3 +825,138 @@ fn enum_value_outside_allowed_set_rejected() {
     assert!(serde_json::from_str::<CalculationSex>("\"ROBOT\"").is_err());
     assert!(serde_json::from_str::<CalculationGoal>("\"MAINTAIN\"").is_err());
 }
+
+#[test]
+fn tdee_for_date_uses_the_body_data_that_applied_then() {
+    scenario!("[OB-021]");
+    let pool = setup_test_pool();
+    {
+        let mut conn = pool.get().unwrap();
+        for (effective_date, activity_level) in [("2026-01-01", 1.25), ("2026-06-01", 1.75)] {
+            BodyData::record(
+                &mut conn,
+                &NewBodyData {
+                    birth_date: "1995-09-01".to_string(),
+                    height: 180.0,
+                    weight: 90.0,
+                    sex: "MALE".to_string(),
+                    activity_level,
+                    effective_date: effective_date.to_string(),
+                },
+            )
+            .unwrap();
+        }
+    }
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    let winter = wizard_calculate_tdee_for_date(
+        app.state(),
+        "2026-03-01".to_string(),
+        5,
+        CalculationGoal::LOSS,
+    )
+    .unwrap();
+    let summer = wizard_calculate_tdee_for_date(
+        app.state(),
+        "2026-07-01".to_string(),
+        5,
+        CalculationGoal::LOSS,
+    )
+    .unwrap();
+
+    // Same BMR, different activity level in effect
+    assert_eq!(1995.0, winter.bmr);
+    assert_eq!(winter.bmr, summer.bmr);
+    assert_eq!(2494.0, winter.tdee);
+    assert_eq!(3491.0, summer.tdee);
+
+    // No profile recorded yet on that date
+    assert!(wizard_calculate_tdee_for_date(
+        app.state(),
+        "2025-01-01".to_string(),
+        5,
+        CalculationGoal::LOSS
+    )
+    .is_err());
+}
+
+#[test]
+fn bmr_uses_the_age_on_the_calculation_date() {
+    scenario!("[OB-022]");
+    let input = || WizardInput {
+        birth_date: "1996-05-01".to_string(),
+        weight: 90.0,
+        height: 180.0,
+        sex: CalculationSex::MALE,
+        activity_level: 1.5,
+        weekly_difference: 5,
+        calculation_goal: CalculationGoal::LOSS,
+    };
+
+    // 29 before the birthday, 30 from the birthday on
+    let before = calculate_as_of(input(), &NaiveDate::from_ymd_opt(2026, 4, 30).unwrap()).unwrap();
+    let after = calculate_as_of(input(), &NaiveDate::from_ymd_opt(2026, 5, 1).unwrap()).unwrap();
+
+    assert_eq!(2002.0, before.bmr);
+    assert_eq!(1995.0, after.bmr);
+}
+
+#[test]
+fn calculation_rejects_an_unsupported_age_on_the_calculation_date() {
+    scenario!("[OB-022]");
+    let input = || WizardInput {
+        birth_date: "1996-05-01".to_string(),
+        weight: 90.0,
+        height: 180.0,
+        sex: CalculationSex::MALE,
+        activity_level: 1.5,
+        weekly_difference: 5,
+        calculation_goal: CalculationGoal::LOSS,
+    };
+
+    // 17 on that date, although old enough today
+    let errors =
+        calculate_as_of(input(), &NaiveDate::from_ymd_opt(2014, 4, 30).unwrap()).unwrap_err();
+    assert_eq!(
+        errors.field_errors().get("birth_date").unwrap()[0].code,
+        "validation.wizard.age"
+    );
+    assert!(calculate_as_of(input(), &NaiveDate::from_ymd_opt(2014, 5, 1).unwrap()).is_ok());
+}
+
+#[test]
+fn target_calculations_check_the_age_on_the_start_date() {
+    scenario!("[OB-022]");
+    let target_weight = |start_date: &str| WizardTargetWeightInput {
+        birth_date: "1996-05-01".to_string(),
+        sex: CalculationSex::MALE,
+        current_weight: 90.0,
+        height: 180.0,
+        target_weight: 80.0,
+        start_date: start_date.to_string(),
+    };
+    let target_date = |start_date: &str| WizardTargetDateInput {
+        birth_date: "1996-05-01".to_string(),
+        sex: CalculationSex::MALE,
+        current_weight: 90.0,
+        height: 180.0,
+        calculation_goal: CalculationGoal::LOSS,
+        start_date: start_date.to_string(),
+        target_date: "2014-10-01".to_string(),
+    };
+    let code = |errors: ValidationErrors, field: &str| {
+        errors.field_errors().get(field).unwrap()[0].code.clone()
+    };
+
+    // 17 on that date, although old enough today
+    let errors = wizard_calculate_for_target_weight(target_weight("2014-04-30")).unwrap_err();
+    assert_eq!(code(errors, "birth_date"), "validation.wizard.age");
+    let errors = wizard_calculate_for_target_date(target_date("2014-04-30")).unwrap_err();
+    assert_eq!(code(errors, "birth_date"), "validation.wizard.age");
+    assert!(wizard_calculate_for_target_weight(target_weight("2014-05-01")).is_ok());
+    assert!(wizard_calculate_for_target_date(target_date("2014-05-01")).is_ok());
+
+    let errors = wizard_calculate_for_target_weight(target_weight("01.05.2014")).unwrap_err();
+    assert_eq!(code(errors, "start_date"), "validation.wizard.start_date");
+}
@@ -1,17 +1,28 @@
-use crate::helpers::setup_test_pool;
+use crate::helpers::{lifting_set, setup_test_pool};
 use diesel::prelude::*;
 use librefit_lib::db::schema::workout_session;
 use librefit_lib::scenario;
 use librefit_lib::service::workout::{
     add_workout_set, create_workout_for_date, delete_workout, delete_workout_set,
     discard_workout_session, end_workout_session, get_active_workout, get_exercise_library,
-    list_workouts, log_workout_set, pause_workout_session, resume_workout_session,
-    start_workout_session, update_workout_set, LiftingSetMetrics,
+    get_workout_types, list_workouts, log_activity_set, log_workout_set, pause_workout_session,
+    resume_workout_session, start_activity_session, start_workout_session, update_activity_set,
+    update_workout_set, CyclingSetMetrics, LiftingSetMetrics, LoggedSet, RunningSetMetrics,
+    SetKind, SetMetrics,
 };
 use tauri::Manager;
 
-fn metrics(reps: i32, weight_kg: f64) -> LiftingSetMetrics {
-    LiftingSetMetrics { reps, weight_kg }
+fn lifting(set: &LoggedSet) -> &LiftingSetMetrics {
+    set.metrics.lifting().expect("weight-lifting set")
+}
+
+fn run(duration_seconds: i32, distance_m: f64) -> RunningSetMetrics {
+    RunningSetMetrics {
+        duration_seconds,
+        distance_m,
+        avg_heart_rate: Some(155),
+        elevation_gain_m: None,
+    }
 }
 
 #[test]
@@ -50,13 +61,13 @@ fn log_set_records_under_exercise() {
     app.manage(pool);
 
     start_workout_session(app.state(), None).unwrap();
-    let detail = log_workout_set(app.state(), 1, metrics(10, 80.0)).unwrap();
+    let detail = log_workout_set(app.state(), 1, lifting_set(10, 80.0)).unwrap();
 
     assert_eq!(detail.exercises.len(), 1);
     assert_eq!(detail.exercises[0].exercise_id, 1);
     assert_eq!(detail.exercises[0].sets.len(), 1);
-    assert_eq!(detail.exercises[0].sets[0].metrics.reps, 10);
-    assert_eq!(detail.exercises[0].sets[0].metrics.weight_kg, 80.0);
+    assert_eq!(lifting(&detail.exercises[0].sets[0]).reps, Some(10));
+    assert_eq!(lifting(&detail.exercises[0].sets[0]).weight_kg, 80.0);
     assert!(!detail.exercises[0].sets[0].logged_at.is_empty());
 }
 
@@ -68,15 +79,15 @@ fn multiple_exercises_and_sets_accumulate_in_order() {
     app.manage(pool);
 
     start_workout_session(app.state(), None).unwrap();
-    log_workout_set(app.state(), 1, metrics(10, 80.0)).unwrap();
-    log_workout_set(app.state(), 1, metrics(8, 85.0)).unwrap();
-    let detail = log_workout_set(app.state(), 2, metrics(12, 40.0)).unwrap();
+    log_workout_set(app.state(), 1, lifting_set(10, 80.0)).unwrap();
+    log_workout_set(app.state(), 1, lifting_set(8, 85.0)).unwrap();
+    let detail = log_workout_set(app.state(), 2, lifting_set(12, 40.0)).unwrap();
 
     assert_eq!(detail.exercises.len(), 2);
     assert_eq!(detail.exercises[0].exercise_id, 1);
     assert_eq!(detail.exercises[0].sets.len(), 2);
-    assert_eq!(detail.exercises[0].sets[0].metrics.reps, 10);
-    assert_eq!(detail.exercises[0].sets[1].metrics.reps, 8);
+    assert_eq!(lifting(&detail.exercises[0].sets[0]).reps, Some(10));
+    assert_eq!(lifting(&detail.exercises[0].sets[1]).reps, Some(8));
     assert_eq!(detail.exercises[1].exercise_id, 2);
     assert_eq!(detail.exercises[1].sets.len(), 1);
 }
@@ -102,7 +113,7 @@ fn exercise_library_is_seeded_and_exposes_category_muscles_and_rest() {
     let app = tauri::test::mock_app();
     app.manage(pool);
 
-    let library = get_exercise_library(app.state()).unwrap();
+    let library = get_exercise_library(app.state(), None).unwrap();
     assert!(!library.is_empty());
 
     let bench = library
@@ -132,13 +143,13 @@ fn edit_set_updates_metrics() {
     app.manage(pool);
 
     start_workout_session(app.state(), None).unwrap();
-    let detail = log_workout_set(app.state(), 1, metrics(10, 80.0)).unwrap();
+    let detail = log_workout_set(app.state(), 1, lifting_set(10, 80.0)).unwrap();
     let set_id = detail.exercises[0].sets[0].id;
 
-    let updated = update_workout_set(app.state(), set_id, metrics(12, 100.0)).unwrap();
+    let updated = update_workout_set(app.state(), set_id, lifting_set(12, 100.0)).unwrap();
 
-    assert_eq!(updated.exercises[0].sets[0].metrics.reps, 12);
-    assert_eq!(updated.exercises[0].sets[0].metrics.weight_kg, 100.0);
+    assert_eq!(lifting(&updated.exercises[0].sets[0]).reps, Some(12));
+    assert_eq!(lifting(&updated.exercises[0].sets[0]).weight_kg, 100.0);
 }
 
 #[test]
@@ -149,7 +160,7 @@ fn delete_set_removes_it() {
     app.manage(pool);
 
     start_workout_session(app.state(), None).unwrap();
-    let detail = log_workout_set(app.state(), 1, metrics(10, 80.0)).unwrap();
+    let detail = log_workout_set(app.state(), 1, lifting_set(10, 80.0)).unwrap();
     let set_id = detail.exercises[0].sets[0].id;
 
     let after = delete_workout_set(app.state(), set_id).unwrap();
@@ -165,7 +176,7 @@ fn discard_deletes_session_and_data() {
     app.manage(pool);
 
     start_workout_session(app.state(), None).unwrap();
-    log_workout_set(app.state(), 1, metrics(10, 80.0)).unwrap();
+    log_workout_set(app.state(), 1, lifting_set(10, 80.0)).unwrap();
     discard_workout_session(app.state()).unwrap();
 
     assert!(get_active_workout(app.state()).unwrap().is_none());
@@ -180,7 +191,7 @@ fn invalid_set_metrics_are_rejected_and_not_persisted() {
 
     start_workout_session(app.state(), None).unwrap();
     // reps = 0 violates the schema (min 1)
-    let result = log_workout_set(app.state(), 1, metrics(0, 80.0));
+    let result = log_workout_set(app.state(), 1, lifting_set(0, 80.0));
 
     assert!(result.is_err());
     let detail = get_active_workout(app.state()).unwrap().unwrap();
@@ -243,7 +254,7 @@ fn exercise_find_returns_correct_exercise() {
     let app = tauri::test::mock_app();
     app.manage(pool);
 
-    let library = get_exercise_library(app.state()).unwrap();
+    let library = get_exercise_library(app.state(), None).unwrap();
     let bench_press = library.iter().find(|e| e.name == "Bench Press").unwrap();
 
     assert_eq!(bench_press.id, 1);
@@ -277,7 +288,14 @@ fn create_for_date_and_add_set_builds_completed_workout() {
     );
     assert!(get_active_workout(app.state()).unwrap().is_none());
 
-    let detail = add_workout_set(app.state(), created.session.id, 1, metrics(10, 80.0)).unwrap();
+    let detail = add_workout_set(
+        app.state(),
+        created.session.id,
+        1,
+        lifting_set(10, 80.0),
+        None,
+    )
+    .unwrap();
     assert_eq!(detail.exercises.len(), 1);
     assert_eq!(detail.exercises[0].sets.len(), 1);
     // The added set lands on the workout's date, not today.
@@ -299,11 +317,11 @@ fn list_workouts_returns_completed_in_range_excluding_active() {
     // A completed retrospective workout on 2026-05-01.
     let may =
         create_workout_for_date(app.state(), "2026-05-01T10:00:00.000Z".into(), None).unwrap();
-    add_workout_set(app.state(), may.session.id, 1, metrics(10, 80.0)).unwrap();
+    add_workout_set(app.state(), may.session.id, 1, lifting_set(10, 80.0), None).unwrap();
 
     // An active session (ended_at NULL) must be excluded from history.
     start_workout_session(app.state(), None).unwrap();
-    log_workout_set(app.state(), 1, metrics(5, 60.0)).unwrap();
+    log_workout_set(app.state(), 1, lifting_set(5, 60.0)).unwrap();
 
     // A wide window still returns only the completed one, with its set detail.
     let all = list_workouts(
@@ -314,7 +332,7 @@ fn list_workouts_returns_completed_in_range_excluding_active() {
     .unwrap();
     assert_eq!(all.len(), 1);
     assert_eq!(all[0].session.id, may.session.id);
-    assert_eq!(all[0].exercises[0].sets[0].metrics.reps, 10);
+    assert_eq!(lifting(&all[0].exercises[0].sets[0]).reps, Some(10));
 
     // A single-day window bounding 2026-05-01 returns it.
     let day = list_workouts(
@@ -344,7 +362,14 @@ fn delete_workout_removes_completed_and_refuses_active() {
 
     let completed =
         create_workout_for_date(app.state(), "2026-05-01T10:00:00.000Z".into(), None).unwrap();
-    add_workout_set(app.state(), completed.session.id, 1, metrics(10, 80.0)).unwrap();
+    add_workout_set(
+        app.state(),
+        completed.session.id,
+        1,
+        lifting_set(10, 80.0),
+        None,
+    )
+    .unwrap();
 
     delete_workout(app.state(), completed.session.id).unwrap();
     let after = list_workouts(
@@ -371,15 +396,326 @@ fn edit_and_delete_sets_on_completed_workout() {
 
     let created =
         create_workout_for_date(app.state(), "2026-05-01T10:00:00.000Z".into(), None).unwrap();
-    let detail = add_workout_set(app.state(), created.session.id, 1, metrics(10, 80.0)).unwrap();
+    let detail = add_workout_set(
+        app.state(),
+        created.session.id,
+        1,
+        lifting_set(10, 80.0),
+        None,
+    )
+    .unwrap();
     let set_id = detail.exercises[0].sets[0].id;
 
     // No active session exists, yet editing a completed session's set still works.
     assert!(get_active_workout(app.state()).unwrap().is_none());
-    let updated = update_workout_set(app.state(), set_id, metrics(12, 100.0)).unwrap();
-    assert_eq!(updated.exercises[0].sets[0].metrics.reps, 12);
-    assert_eq!(updated.exercises[0].sets[0].metrics.weight_kg, 100.0);
+    let updated = update_workout_set(app.state(), set_id, lifting_set(12, 100.0)).unwrap();
+    assert_eq!(lifting(&updated.exercises[0].sets[0]).reps, Some(12));
+    assert_eq!(lifting(&updated.exercises[0].sets[0]).weight_kg, 100.0);
 
     let removed = delete_workout_set(app.state(), set_id).unwrap();
     assert!(removed.exercises[0].sets.is_empty());
 }
+
+// ============================================================================
+// ENDURANCE WORKOUT TYPES
+// ============================================================================
+
+#[test]
+fn workout_types_are_seeded_with_their_own_library() {
+    scenario!("[WO-032]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    let mut types: Vec<String> = get_workout_types(app.state())
+        .unwrap()
+        .into_iter()
+        .map(|t| t.shortvalue)
+        .collect();
+    types.sort();
+    assert_eq!(types, vec!["cardio", "cycle", "row", "run", "swim", "wl"]);
+
+    let running = get_exercise_library(app.state(), Some("run".into())).unwrap();
+    let names: Vec<&str> = running.iter().map(|e| e.name.as_str()).collect();
+    assert_eq!(names, vec!["Outdoor Run", "Treadmill Run"]);
+    assert!(running.iter().all(|e| e.workout_type == "run"));
+
+    // The default library stays weight lifting only
+    let lifting_library = get_exercise_library(app.state(), None).unwrap();
+    assert!(lifting_library.iter().all(|e| e.workout_type == "wl"));
+    assert!(!lifting_library.iter().any(|e| e.name == "Outdoor Run"));
+}
+
+#[test]
+fn run_session_logs_and_reads_running_metrics() {
+    scenario!("[WO-032]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    let started = start_activity_session(app.state(), "run".into(), None).unwrap();
+    assert_eq!(started.session.workout_type, "run");
+
+    let detail = log_activity_set(app.state(), 53, SetMetrics::Running(run(1500, 5000.0))).unwrap();
+
+    assert_eq!(detail.exercises.len(), 1);
+    assert_eq!(detail.exercises[0].name, "Outdoor Run");
+    let set = &detail.exercises[0].sets[0];
+    assert_eq!(set.metrics, SetMetrics::Running(run(1500, 5000.0)));
+    match &set.metrics {
+        SetMetrics::Running(m) => assert_eq!(m.pace_seconds_per_km(), 300.0),
+        other => panic!("expected running metrics, got {:?}", other),
+    }
+    assert_eq!(set.metrics.volume(), 0.0);
+}
+
+#[test]
+fn activity_set_is_edited_in_its_own_schema() {
+    scenario!("[WO-032]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    let ride = |distance_m: f64| {
+        SetMetrics::Cycling(CyclingSetMetrics {
+            duration_seconds: 3600,
+            distance_m,
+            avg_heart_rate: None,
+            elevation_gain_m: Some(350.0),
+            avg_power_watts: Some(210),
+        })
+    };
+
+    start_activity_session(app.state(), "cycle".into(), Some("Commute".into())).unwrap();
+    let detail = log_activity_set(app.state(), 55, ride(25000.0)).unwrap();
+    let set_id = detail.exercises[0].sets[0].id;
+
+    let updated = update_activity_set(app.state(), set_id, ride(30000.0)).unwrap();
+
+    assert_eq!(updated.exercises[0].sets[0].metrics, ride(30000.0));
+    assert!(update_workout_set(app.state(), set_id, lifting_set(10, 80.0)).is_err());
+}
+
+#[test]
+fn mismatched_or_invalid_activity_sets_are_rejected() {
+    scenario!("[WO-033]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    assert!(start_activity_session(app.state(), "ski".into(), None).is_err());
+    start_activity_session(app.state(), "run".into(), None).unwrap();
+
+    // Lifting metrics in a run
+    assert!(log_workout_set(app.state(), 53, lifting_set(10, 80.0)).is_err());
+    // A swimming exercise in a run
+    assert!(log_activity_set(app.state(), 58, SetMetrics::Running(run(1500, 5000.0))).is_err());
+    // Running metrics out of range
+    let mut racing_heart = run(1500, 5000.0);
+    racing_heart.avg_heart_rate = Some(300);
+    assert!(log_activity_set(app.state(), 53, SetMetrics::Running(racing_heart)).is_err());
+    assert!(log_activity_set(app.state(), 53, SetMetrics::Running(run(0, 5000.0))).is_err());
+
+    let active = get_active_workout(app.state()).unwrap().unwrap();
+    assert!(active.exercises.is_empty());
+}
+
+#[test]
+fn lifting_session_rejects_endurance_sets() {
+    scenario!("[WO-033]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    start_workout_session(app.state(), None).unwrap();
+
+    assert!(log_activity_set(app.state(), 53, SetMetrics::Running(run(1500, 5000.0))).is_err());
+    assert!(log_workout_set(app.state(), 53, lifting_set(10, 80.0)).is_err());
+    let detail =
+        log_activity_set(app.state(), 1, SetMetrics::Lifting(lifting_set(10, 80.0))).unwrap();
+    assert_eq!(lifting(&detail.exercises[0].sets[0]).reps, Some(10));
+}
+
+// ============================================================================
+// TIME-BASED AND BODYWEIGHT SETS
+// ============================================================================
+
+#[test]
+fn time_based_and_bodyweight_sets_are_logged() {
+    scenario!("[WO-034]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    let plank = LiftingSetMetrics {
+        duration_seconds: Some(60),
+        ..Default::default()
+    };
+    let weighted_pull_up = LiftingSetMetrics {
+        reps: Some(8),
+        bodyweight: true,
+        added_load_kg: Some(20.0),
+        ..Default::default()
+    };
+    let assisted_pull_up = LiftingSetMetrics {
+        reps: Some(10),
+        bodyweight: true,
+        added_load_kg: Some(-25.0),
+        ..Default::default()
+    };
+
+    start_workout_session(app.state(), None).unwrap();
+    log_workout_set(app.state(), 10, plank.clone()).unwrap();
+    log_workout_set(app.state(), 6, weighted_pull_up.clone()).unwrap();
+    let detail = log_workout_set(app.state(), 6, assisted_pull_up.clone()).unwrap();
+
+    assert_eq!(lifting(&detail.exercises[0].sets[0]), &plank);
+    assert_eq!(lifting(&detail.exercises[1].sets[0]), &weighted_pull_up);
+    assert_eq!(lifting(&detail.exercises[1].sets[1]), &assisted_pull_up);
+
+    // Only added load counts towards volume; holds and assistance count zero
+    assert_eq!(plank.volume(), 0.0);
+    assert_eq!(weighted_pull_up.volume(), 160.0);
+    assert_eq!(assisted_pull_up.volume(), 0.0);
+}
+
+#[test]
+fn inconsistent_time_and_bodyweight_sets_are_rejected() {
+    scenario!("[WO-034]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    start_workout_session(app.state(), None).unwrap();
+
+    let invalid = [
+        // Neither reps nor a duration
+        LiftingSetMetrics {
+            weight_kg: 20.0,
+            ..Default::default()
+        },
+        // A bodyweight set carries extra weight as added load, not as external load
+        LiftingSetMetrics {
+            reps: Some(8),
+            weight_kg: 20.0,
+            bodyweight: true,
+            ..Default::default()
+        },
+        // Added load on a loaded set
+        LiftingSetMetrics {
+            reps: Some(8),
+            weight_kg: 60.0,
+            added_load_kg: Some(10.0),
+            ..Default::default()
+        },
+        LiftingSetMetrics {
+            duration_seconds: Some(0),
+            ..Default::default()
+        },
+    ];
+    for metrics in invalid {
+        assert!(log_workout_set(app.state(), 6, metrics).is_err());
+    }
+
+    let active = get_active_workout(app.state()).unwrap().unwrap();
+    assert!(active.exercises.is_empty());
+}
+
+// ============================================================================
+// SET ANNOTATIONS
+// ============================================================================
+
+#[test]
+fn sets_carry_effort_kind_and_note() {
+    scenario!("[WO-036]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    let top_set = LiftingSetMetrics {
+        reps: Some(5),
+        weight_kg: 100.0,
+        rpe: Some(8.5),
+        note: Some("Bar speed slowed on rep 4".to_string()),
+        ..Default::default()
+    };
+    let amrap = LiftingSetMetrics {
+        reps: Some(12),
+        weight_kg: 80.0,
+        rir: Some(0),
+        kind: SetKind::Amrap,
+        ..Default::default()
+    };
+
+    start_workout_session(app.state(), None).unwrap();
+    log_workout_set(app.state(), 1, top_set.clone()).unwrap();
+    let detail = log_workout_set(app.state(), 1, amrap.clone()).unwrap();
+
+    assert_eq!(lifting(&detail.exercises[0].sets[0]), &top_set);
+    assert_eq!(lifting(&detail.exercises[0].sets[0]).kind, SetKind::Working);
+    assert_eq!(lifting(&detail.exercises[0].sets[1]), &amrap);
+
+    let set_id = detail.exercises[0].sets[1].id;
+    let drop_set = LiftingSetMetrics {
+        kind: SetKind::Drop,
+        ..amrap
+    };
+    let updated = update_workout_set(app.state(), set_id, drop_set.clone()).unwrap();
+    assert_eq!(lifting(&updated.exercises[0].sets[1]), &drop_set);
+}
+
+#[test]
+fn invalid_annotations_are_rejected() {
+    scenario!("[WO-036]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    start_workout_session(app.state(), None).unwrap();
+
+    let invalid = [
+        // RPE and RIR rate the same thing
+        LiftingSetMetrics {
+            rpe: Some(8.0),
+            rir: Some(2),
+            ..lifting_set(5, 100.0)
+        },
+        LiftingSetMetrics {
+            rpe: Some(11.0),
+            ..lifting_set(5, 100.0)
+        },
+        LiftingSetMetrics {
+            rir: Some(-1),
+            ..lifting_set(5, 100.0)
+        },
+        LiftingSetMetrics {
+            note: Some("x".repeat(501)),
+            ..lifting_set(5, 100.0)
+        },
+    ];
+    for metrics in invalid {
+        assert!(log_workout_set(app.state(), 1, metrics).is_err());
+    }
+
+    let active = get_active_workout(app.state()).unwrap().unwrap();
+    assert!(active.exercises.is_empty());
+}
+
+#[test]
+fn warm_up_sets_are_excluded_from_volume() {
+    scenario!("[WO-037]");
+
+    let warm_up = LiftingSetMetrics {
+        kind: SetKind::Warmup,
+        ..lifting_set(10, 40.0)
+    };
+    let failure = LiftingSetMetrics {
+        kind: SetKind::Failure,
+        ..lifting_set(8, 80.0)
+    };
+
+    assert_eq!(warm_up.volume(), 0.0);
+    assert_eq!(warm_up.total_volume(), 400.0);
+    assert_eq!(failure.volume(), 640.0);
+    assert_eq!(SetMetrics::Lifting(warm_up).volume(), 0.0);
+}
@@ -0,0 +1,193 @@
+use crate::helpers::{lifting_set, setup_test_pool};
+use librefit_lib::scenario;
+use librefit_lib::service::workout::{
+    add_workout_exercise, end_workout_session, group_workout_exercises, log_workout_block_set,
+    log_workout_set, reorder_workout_exercises, start_workout_session, ungroup_workout_exercise,
+    SetMetrics, WorkoutDetail,
+};
+use tauri::Manager;
+
+fn names(detail: &WorkoutDetail) -> Vec<&str> {
+    detail.exercises.iter().map(|e| e.name.as_str()).collect()
+}
+
+fn block_ids(detail: &WorkoutDetail) -> Vec<i32> {
+    detail.exercises.iter().map(|e| e.id).collect()
+}
+
+/// Active session with Bench Press, Back Squat and Deadlift, in that order
+fn three_exercises(app: &tauri::App<tauri::test::MockRuntime>) -> WorkoutDetail {
+    start_workout_session(app.state(), None).unwrap();
+    for exercise_id in [1, 2, 3] {
+        log_workout_set(app.state(), exercise_id, lifting_set(5, 100.0)).unwrap();
+    }
+    log_workout_set(app.state(), 1, lifting_set(5, 100.0)).unwrap()
+}
+
+#[test]
+fn exercises_can_be_reordered() {
+    scenario!("[WO-045]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    let detail = three_exercises(&app);
+    let [bench, squat, deadlift] = block_ids(&detail)[..] else {
+        panic!("three blocks expected");
+    };
+    assert_eq!(
+        detail
+            .exercises
+            .iter()
+            .map(|e| e.position)
+            .collect::<Vec<_>>(),
+        vec![0, 1, 2]
+    );
+
+    let reordered =
+        reorder_workout_exercises(app.state(), detail.session.id, vec![deadlift, bench, squat])
+            .unwrap();
+    assert_eq!(
+        names(&reordered),
+        vec!["Deadlift", "Bench Press", "Back Squat"]
+    );
+    assert_eq!(reordered.exercises[1].sets.len(), 2);
+
+    // Every block exactly once
+    for order in [
+        vec![deadlift, bench],
+        vec![deadlift, bench, bench],
+        vec![deadlift, bench, squat, 999],
+    ] {
+        assert!(reorder_workout_exercises(app.state(), detail.session.id, order).is_err());
+    }
+
+    // The order is kept after the workout ends
+    let ended = end_workout_session(app.state()).unwrap();
+    assert_eq!(names(&ended), vec!["Deadlift", "Bench Press", "Back Squat"]);
+}
+
+#[test]
+fn an_exercise_can_be_done_in_several_blocks() {
+    scenario!("[WO-045]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    let detail = three_exercises(&app);
+    let heavy = detail.exercises[0].id;
+
+    let detail = add_workout_exercise(app.state(), detail.session.id, 1).unwrap();
+    assert_eq!(
+        names(&detail),
+        vec!["Bench Press", "Back Squat", "Deadlift", "Bench Press"]
+    );
+    assert_eq!(detail.exercises[3].position, 3);
+    assert!(detail.exercises[3].sets.is_empty());
+
+    // Logging by exercise goes to its last block
+    let detail = log_workout_set(app.state(), 1, lifting_set(10, 70.0)).unwrap();
+    assert_eq!(detail.exercises[0].sets.len(), 2);
+    assert_eq!(detail.exercises[3].sets.len(), 1);
+
+    // Logging into a block targets it explicitly
+    let detail = log_workout_block_set(
+        app.state(),
+        heavy,
+        SetMetrics::Lifting(lifting_set(3, 110.0)),
+        None,
+    )
+    .unwrap();
+    assert_eq!(detail.exercises[0].sets.len(), 3);
+    assert_eq!(detail.exercises[3].sets.len(), 1);
+
+    assert!(log_workout_block_set(
+        app.state(),
+        heavy,
+        SetMetrics::Lifting(lifting_set(0, 110.0)),
+        None
+    )
+    .is_err());
+    // Elliptical doesn't fit a weight-lifting workout
+    assert!(add_workout_exercise(app.state(), detail.session.id, 50).is_err());
+}
+
+#[test]
+fn supersets_group_blocks_and_count_rounds() {
+    scenario!("[WO-046]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    let detail = three_exercises(&app);
+    let session_id = detail.session.id;
+    let [bench, squat, deadlift] = block_ids(&detail)[..] else {
+        panic!("three blocks expected");
+    };
+
+    // Members follow each other where the first of them was, in the given order
+    let grouped = group_workout_exercises(app.state(), session_id, vec![deadlift, bench]).unwrap();
+    assert_eq!(block_ids(&grouped), vec![deadlift, bench, squat]);
+    let group_id = grouped.exercises[0].group_id;
+    assert!(group_id.is_some());
+    assert_eq!(grouped.exercises[1].group_id, group_id);
+    assert_eq!(grouped.exercises[2].group_id, None);
+
+    log_workout_set(app.state(), 3, lifting_set(5, 140.0)).unwrap();
+    let detail = log_workout_set(app.state(), 1, lifting_set(5, 100.0)).unwrap();
+    let rounds = |index: usize| -> Vec<Option<i32>> {
+        detail.exercises[index]
+            .sets
+            .iter()
+            .map(|s| s.round)
+            .collect()
+    };
+    assert_eq!(rounds(0), vec![Some(1), Some(2)]);
+    assert_eq!(rounds(1), vec![Some(1), Some(2), Some(3)]);
+    assert_eq!(rounds(2), vec![None]);
+
+    // A superset can't be split by reordering
+    assert!(
+        reorder_workout_exercises(app.state(), session_id, vec![deadlift, squat, bench]).is_err()
+    );
+    assert!(
+        reorder_workout_exercises(app.state(), session_id, vec![squat, deadlift, bench]).is_ok()
+    );
+
+    // At least two blocks, none already grouped
+    assert!(group_workout_exercises(app.state(), session_id, vec![squat]).is_err());
+    assert!(group_workout_exercises(app.state(), session_id, vec![squat, squat]).is_err());
+    assert!(group_workout_exercises(app.state(), session_id, vec![squat, bench]).is_err());
+
+    // Ungrouping a superset leaves both blocks on their own
+    let detail = ungroup_workout_exercise(app.state(), deadlift).unwrap();
+    assert!(detail.exercises.iter().all(|e| e.group_id.is_none()));
+    assert!(detail
+        .exercises
+        .iter()
+        .flat_map(|e| &e.sets)
+        .all(|s| s.round.is_none()));
+    assert_eq!(block_ids(&detail), vec![squat, bench, deadlift]);
+    assert!(ungroup_workout_exercise(app.state(), deadlift).is_err());
+}
+
+#[test]
+fn a_circuit_keeps_its_remaining_blocks() {
+    scenario!("[WO-046]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    let detail = three_exercises(&app);
+    let [bench, squat, deadlift] = block_ids(&detail)[..] else {
+        panic!("three blocks expected");
+    };
+
+    group_workout_exercises(app.state(), detail.session.id, vec![bench, squat, deadlift]).unwrap();
+    let detail = ungroup_workout_exercise(app.state(), squat).unwrap();
+
+    assert_eq!(block_ids(&detail), vec![bench, deadlift, squat]);
+    assert!(detail.exercises[0].group_id.is_some());
+    assert_eq!(detail.exercises[0].group_id, detail.exercises[1].group_id);
+    assert_eq!(detail.exercises[2].group_id, None);
+}
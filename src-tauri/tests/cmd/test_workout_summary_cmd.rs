@@ -0,0 +1,224 @@
+use crate::helpers::{history, lifting_set, setup_test_pool, TestPool};
+use diesel::prelude::*;
+use librefit_lib::db::schema::{workout_pause, workout_session};
+use librefit_lib::scenario;
+use librefit_lib::service::personal_record::RecordKind;
+use librefit_lib::service::workout::{
+    add_workout_set, create_workout_for_date, end_workout_session, log_workout_set,
+    start_workout_session, LiftingSetMetrics, NewWorkoutPause, SetKind,
+};
+use librefit_lib::service::workout_summary::SessionTotals;
+use librefit_lib::service::workout_template::{
+    create_workout_template, start_workout_session_from_template, TemplateExerciseInput,
+    WorkoutTemplateInput,
+};
+use tauri::Manager;
+
+/// Creates a past workout with the given sets of (exercise, reps, kg)
+fn past_workout(
+    app: &tauri::App<tauri::test::MockRuntime>,
+    started_at: &str,
+    sets: &[(i32, i32, f64)],
+) -> i32 {
+    let workout = create_workout_for_date(app.state(), started_at.to_string(), None).unwrap();
+    for (exercise_id, reps, weight_kg) in sets {
+        add_workout_set(
+            app.state(),
+            workout.session.id,
+            *exercise_id,
+            lifting_set(*reps, *weight_kg),
+            None,
+        )
+        .unwrap();
+    }
+    workout.session.id
+}
+
+fn set_times(pool: &TestPool, session_id: i32, started_at: &str, ended_at: &str) {
+    let mut conn = pool.get().unwrap();
+    diesel::update(workout_session::table.filter(workout_session::id.eq(session_id)))
+        .set((
+            workout_session::started_at.eq(started_at),
+            workout_session::ended_at.eq(ended_at),
+        ))
+        .execute(&mut conn)
+        .unwrap();
+}
+
+#[test]
+fn completed_workouts_carry_a_summary() {
+    scenario!("[WO-050]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool.clone());
+
+    let workout =
+        create_workout_for_date(app.state(), "2026-03-02T10:00:00.000Z".to_string(), None).unwrap();
+    let session_id = workout.session.id;
+    add_workout_set(
+        app.state(),
+        session_id,
+        1,
+        LiftingSetMetrics {
+            kind: SetKind::Warmup,
+            ..lifting_set(10, 40.0)
+        },
+        None,
+    )
+    .unwrap();
+    for _ in 0..3 {
+        add_workout_set(app.state(), session_id, 1, lifting_set(5, 100.0), None).unwrap();
+    }
+    add_workout_set(app.state(), session_id, 2, lifting_set(5, 140.0), None).unwrap();
+
+    // An hour with a quarter-hour pause
+    set_times(
+        &pool,
+        session_id,
+        "2026-03-02T10:00:00.000Z",
+        "2026-03-02T11:00:00.000Z",
+    );
+    {
+        let mut conn = pool.get().unwrap();
+        diesel::insert_into(workout_pause::table)
+            .values(&NewWorkoutPause {
+                session_id,
+                paused_at: "2026-03-02T10:30:00.000Z".to_string(),
+                resumed_at: Some("2026-03-02T10:45:00.000Z".to_string()),
+            })
+            .execute(&mut conn)
+            .unwrap();
+    }
+
+    let summary = history(&app)[0].summary.clone().unwrap();
+    assert_eq!(
+        summary.totals,
+        SessionTotals {
+            active_seconds: 45 * 60,
+            // Warm-ups add no volume
+            total_volume: 2200.0,
+            sets: 5,
+            reps: 30,
+        }
+    );
+    assert_eq!(
+        summary.muscles,
+        vec![
+            "chest",
+            "deltoids",
+            "gluteal",
+            "hamstring",
+            "quadriceps",
+            "triceps"
+        ]
+    );
+    assert!(summary
+        .personal_records
+        .iter()
+        .any(|r| r.exercise_id == 1 && r.kind == RecordKind::HeaviestWeight));
+    assert!(summary
+        .personal_records
+        .iter()
+        .any(|r| r.name == "Back Squat"));
+    assert!(summary.previous.is_none());
+
+    // Active sessions have no summary yet; ending one returns it
+    start_workout_session(app.state(), None).unwrap();
+    let active = log_workout_set(app.state(), 1, lifting_set(5, 100.0)).unwrap();
+    assert!(active.summary.is_none());
+    let ended = end_workout_session(app.state()).unwrap();
+    assert_eq!(ended.summary.unwrap().totals.sets, 1);
+}
+
+#[test]
+fn summary_compares_with_the_previous_session_of_the_same_exercises() {
+    scenario!("[WO-051]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    let first = past_workout(
+        &app,
+        "2026-03-02T10:00:00.000Z",
+        &[(1, 5, 100.0), (1, 5, 100.0)],
+    );
+    let mixed = past_workout(
+        &app,
+        "2026-03-04T10:00:00.000Z",
+        &[(1, 5, 100.0), (2, 5, 140.0)],
+    );
+    let latest = past_workout(
+        &app,
+        "2026-03-06T10:00:00.000Z",
+        &[(1, 5, 102.5), (1, 5, 102.5), (1, 4, 102.5)],
+    );
+
+    let workouts = history(&app);
+    let summary_of = |id: i32| {
+        workouts
+            .iter()
+            .find(|w| w.session.id == id)
+            .and_then(|w| w.summary.clone())
+            .unwrap()
+    };
+
+    let comparison = summary_of(latest).previous.unwrap();
+    assert_eq!(comparison.session_id, first);
+    assert_eq!(comparison.totals.total_volume, 1000.0);
+    assert_eq!(comparison.change.sets, 1);
+    assert_eq!(comparison.change.reps, 4);
+    assert_eq!(comparison.change.total_volume, 435.0);
+    // No earlier workout had the same exercises
+    assert!(summary_of(mixed).previous.is_none());
+    assert!(summary_of(first).previous.is_none());
+}
+
+#[test]
+fn summary_compares_with_the_previous_session_of_the_same_template() {
+    scenario!("[WO-051]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool.clone());
+
+    let template = create_workout_template(
+        app.state(),
+        WorkoutTemplateInput {
+            name: "Push".to_string(),
+            exercises: vec![TemplateExerciseInput {
+                exercise_id: 1,
+                target_sets: 3,
+                target_reps_min: 5,
+                target_reps_max: 5,
+                target_weight_kg: None,
+                target_rpe: None,
+                rest_seconds: None,
+            }],
+        },
+    )
+    .unwrap();
+
+    let first =
+        start_workout_session_from_template(app.state(), template.template.id, None).unwrap();
+    assert_eq!(first.session.template_id, Some(template.template.id));
+    log_workout_set(app.state(), 1, lifting_set(5, 100.0)).unwrap();
+    end_workout_session(app.state()).unwrap();
+    set_times(
+        &pool,
+        first.session.id,
+        "2026-03-02T10:00:00.000Z",
+        "2026-03-02T11:00:00.000Z",
+    );
+
+    // An ad-hoc workout of the same exercise in between doesn't count
+    past_workout(&app, "2026-03-03T10:00:00.000Z", &[(1, 5, 100.0)]);
+
+    start_workout_session_from_template(app.state(), template.template.id, None).unwrap();
+    log_workout_set(app.state(), 1, lifting_set(5, 100.0)).unwrap();
+    // A different exercise doesn't matter for a template
+    log_workout_set(app.state(), 2, lifting_set(5, 140.0)).unwrap();
+    let ended = end_workout_session(app.state()).unwrap();
+
+    let comparison = ended.summary.unwrap().previous.unwrap();
+    assert_eq!(comparison.session_id, first.session.id);
+    assert_eq!(comparison.change.sets, 1);
+}
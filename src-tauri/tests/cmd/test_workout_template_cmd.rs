@@ -0,0 +1,301 @@
+use crate::helpers::{lifting_set, setup_test_pool, TestPool};
+use diesel::prelude::*;
+use librefit_lib::db::schema::{exercise, workout_template, workout_template_exercise};
+use librefit_lib::scenario;
+use librefit_lib::service::workout::{
+    get_active_workout, log_workout_set, resolve_rest_seconds, start_workout_session, ExercisePlan,
+    DEFAULT_REST_SECONDS,
+};
+use librefit_lib::service::workout_template::{
+    create_workout_template, delete_workout_template, get_workout_template, list_workout_templates,
+    start_workout_session_from_template, update_workout_template, TemplateExerciseInput,
+    WorkoutTemplateInput,
+};
+use tauri::Manager;
+
+fn planned(exercise_id: i32, target_weight_kg: Option<f64>) -> TemplateExerciseInput {
+    TemplateExerciseInput {
+        exercise_id,
+        target_sets: 3,
+        target_reps_min: 8,
+        target_reps_max: 12,
+        target_weight_kg,
+        target_rpe: None,
+        rest_seconds: None,
+    }
+}
+
+fn template(name: &str, exercises: Vec<TemplateExerciseInput>) -> WorkoutTemplateInput {
+    WorkoutTemplateInput {
+        name: name.to_string(),
+        exercises,
+    }
+}
+
+fn count_templates(pool: &TestPool) -> (i64, i64) {
+    let mut conn = pool.get().unwrap();
+    let templates = workout_template::table
+        .count()
+        .get_result(&mut conn)
+        .unwrap();
+    let exercises = workout_template_exercise::table
+        .count()
+        .get_result(&mut conn)
+        .unwrap();
+    (templates, exercises)
+}
+
+#[test]
+fn create_stores_exercises_in_order_and_lists_alphabetically() {
+    scenario!("[WO-028]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    let push = create_workout_template(
+        app.state(),
+        template("Push", vec![planned(1, Some(80.0)), planned(4, None)]),
+    )
+    .unwrap();
+    create_workout_template(app.state(), template("Legs", vec![planned(2, None)])).unwrap();
+
+    assert_eq!(push.template.name, "Push");
+    assert_eq!(push.template.workout_type, "wl");
+    assert_eq!(push.exercises.len(), 2);
+    assert_eq!(push.exercises[0].exercise_id, 1);
+    assert_eq!(push.exercises[0].name, "Bench Press");
+    assert_eq!(push.exercises[0].position, 0);
+    assert_eq!(push.exercises[0].target_weight_kg, Some(80.0));
+    assert_eq!(push.exercises[1].exercise_id, 4);
+    assert_eq!(push.exercises[1].name, "Overhead Press");
+    assert_eq!(push.exercises[1].position, 1);
+
+    let fetched = get_workout_template(app.state(), push.template.id).unwrap();
+    assert_eq!(fetched.exercises.len(), 2);
+
+    let all = list_workout_templates(app.state()).unwrap();
+    let names: Vec<&str> = all.iter().map(|t| t.template.name.as_str()).collect();
+    assert_eq!(names, vec!["Legs", "Push"]);
+}
+
+#[test]
+fn invalid_templates_are_rejected() {
+    scenario!("[WO-028]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool.clone());
+
+    let mut inverted_range = planned(1, None);
+    inverted_range.target_reps_min = 12;
+    inverted_range.target_reps_max = 8;
+    let mut weight_and_rpe = planned(1, Some(80.0));
+    weight_and_rpe.target_rpe = Some(8.0);
+    let mut too_many_sets = planned(1, None);
+    too_many_sets.target_sets = 21;
+
+    for input in [
+        template("Push", vec![inverted_range]),
+        template("Push", vec![weight_and_rpe]),
+        template("Push", vec![too_many_sets]),
+        template("Push", vec![]),
+        template("", vec![planned(1, None)]),
+        template("Push", vec![planned(9999, None)]),
+        template("Push", vec![planned(53, None)]),
+    ] {
+        assert!(create_workout_template(app.state(), input).is_err());
+    }
+
+    assert_eq!(count_templates(&pool), (0, 0));
+}
+
+#[test]
+fn update_replaces_exercises_and_delete_removes_template() {
+    scenario!("[WO-029]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool.clone());
+
+    let created = create_workout_template(
+        app.state(),
+        template("Push", vec![planned(1, None), planned(4, None)]),
+    )
+    .unwrap();
+
+    let updated = update_workout_template(
+        app.state(),
+        created.template.id,
+        template("Push A", vec![planned(5, Some(60.0))]),
+    )
+    .unwrap();
+
+    assert_eq!(updated.template.id, created.template.id);
+    assert_eq!(updated.template.name, "Push A");
+    assert_eq!(updated.exercises.len(), 1);
+    assert_eq!(updated.exercises[0].exercise_id, 5);
+    assert_eq!(updated.exercises[0].position, 0);
+    assert_eq!(count_templates(&pool), (1, 1));
+
+    delete_workout_template(app.state(), created.template.id).unwrap();
+
+    assert_eq!(count_templates(&pool), (0, 0));
+    assert!(get_workout_template(app.state(), created.template.id).is_err());
+}
+
+#[test]
+fn sessions_keep_their_plan_after_template_edit_and_delete() {
+    scenario!("[WO-029]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    let created =
+        create_workout_template(app.state(), template("Push", vec![planned(1, Some(80.0))]))
+            .unwrap();
+    start_workout_session_from_template(app.state(), created.template.id, None).unwrap();
+
+    update_workout_template(
+        app.state(),
+        created.template.id,
+        template("Push", vec![planned(1, Some(100.0))]),
+    )
+    .unwrap();
+    delete_workout_template(app.state(), created.template.id).unwrap();
+
+    let active = get_active_workout(app.state()).unwrap().unwrap();
+    let plan = active.exercises[0].plan.clone().unwrap();
+    assert_eq!(plan.target_weight_kg, Some(80.0));
+}
+
+#[test]
+fn start_from_template_precreates_planned_exercises() {
+    scenario!("[WO-030]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    let mut bench = planned(1, None);
+    bench.target_sets = 5;
+    bench.target_reps_min = 5;
+    bench.target_reps_max = 5;
+    bench.target_rpe = Some(8.0);
+    bench.rest_seconds = Some(300);
+    let created = create_workout_template(
+        app.state(),
+        template("Push", vec![bench, planned(4, Some(40.0))]),
+    )
+    .unwrap();
+
+    let detail =
+        start_workout_session_from_template(app.state(), created.template.id, None).unwrap();
+
+    assert_eq!(detail.session.name.as_deref(), Some("Push"));
+    assert!(detail.session.ended_at.is_none());
+    assert_eq!(detail.exercises.len(), 2);
+    assert_eq!(detail.exercises[0].exercise_id, 1);
+    assert!(detail.exercises[0].sets.is_empty());
+    assert_eq!(
+        detail.exercises[0].plan,
+        Some(ExercisePlan {
+            target_sets: 5,
+            target_reps_min: 5,
+            target_reps_max: 5,
+            target_weight_kg: None,
+            target_rpe: Some(8.0),
+            rest_seconds: Some(300),
+        })
+    );
+    assert_eq!(detail.exercises[1].exercise_id, 4);
+    assert_eq!(
+        detail.exercises[1].plan.as_ref().unwrap().target_weight_kg,
+        Some(40.0)
+    );
+
+    // Logging a planned exercise fills its pre-created row instead of adding another
+    let detail = log_workout_set(app.state(), 1, lifting_set(5, 100.0)).unwrap();
+    assert_eq!(detail.exercises.len(), 2);
+    assert_eq!(detail.exercises[0].sets.len(), 1);
+}
+
+#[test]
+fn start_from_template_uses_given_name_and_refuses_while_active() {
+    scenario!("[WO-030]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    let created =
+        create_workout_template(app.state(), template("Push", vec![planned(1, None)])).unwrap();
+
+    let detail = start_workout_session_from_template(
+        app.state(),
+        created.template.id,
+        Some("Push (deload)".to_string()),
+    )
+    .unwrap();
+    assert_eq!(detail.session.name.as_deref(), Some("Push (deload)"));
+
+    let again = start_workout_session_from_template(app.state(), created.template.id, None);
+    assert!(again.is_err());
+    let active = get_active_workout(app.state()).unwrap().unwrap();
+    assert_eq!(active.session.id, detail.session.id);
+}
+
+#[test]
+fn rest_target_prefers_plan_over_exercise_default() {
+    scenario!("[WO-031]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool);
+
+    let mut bench = planned(1, None);
+    bench.rest_seconds = Some(300);
+    let created =
+        create_workout_template(app.state(), template("Push", vec![bench, planned(4, None)]))
+            .unwrap();
+
+    let detail =
+        start_workout_session_from_template(app.state(), created.template.id, None).unwrap();
+
+    // Bench Press defaults to 180 s, the plan says 300 s
+    assert_eq!(detail.exercises[0].default_rest_seconds, Some(180));
+    assert_eq!(detail.exercises[0].rest_target_seconds, 300);
+    // No plan rest: falls back to the Overhead Press default
+    assert_eq!(detail.exercises[1].rest_target_seconds, 180);
+}
+
+#[test]
+fn rest_target_falls_back_to_global_default() {
+    scenario!("[WO-031]");
+    let pool = setup_test_pool();
+    let app = tauri::test::mock_app();
+    app.manage(pool.clone());
+
+    {
+        let mut conn = pool.get().unwrap();
+        diesel::update(exercise::table.filter(exercise::id.eq(2)))
+            .set(exercise::default_rest_seconds.eq(None::<i32>))
+            .execute(&mut conn)
+            .unwrap();
+    }
+
+    start_workout_session(app.state(), None).unwrap();
+    log_workout_set(app.state(), 1, lifting_set(10, 80.0)).unwrap();
+    let detail = log_workout_set(app.state(), 2, lifting_set(10, 100.0)).unwrap();
+
+    assert!(detail.exercises[0].plan.is_none());
+    assert_eq!(detail.exercises[0].rest_target_seconds, 180);
+    assert_eq!(
+        detail.exercises[1].rest_target_seconds,
+        DEFAULT_REST_SECONDS
+    );
+}
+
+#[test]
+fn rest_precedence_is_plan_override_exercise_global() {
+    scenario!("[WO-031]");
+
+    assert_eq!(resolve_rest_seconds(Some(300), Some(60), Some(180)), 300);
+    assert_eq!(resolve_rest_seconds(None, Some(60), Some(180)), 60);
+    assert_eq!(resolve_rest_seconds(None, None, Some(180)), 180);
+    assert_eq!(resolve_rest_seconds(None, None, None), 90);
+}
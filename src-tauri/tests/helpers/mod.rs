@@ -1,3 +1,4 @@
+use chrono::{DateTime, Duration, Local, Months, NaiveDate, SecondsFormat};
 use diesel::r2d2::{ConnectionManager, Pool};
 use diesel::sqlite::SqliteConnection;
 use librefit_lib::db::migrations;
@@ -6,6 +7,8 @@ use librefit_lib::service::user::LibreUser;
 use librefit_lib::service::weight::{
     NewWeightTarget, NewWeightTracker, WeightTarget, WeightTracker,
 };
+use librefit_lib::service::workout::{list_workouts, LiftingSetMetrics, WorkoutDetail};
+use tauri::Manager;
 
 pub type TestPool = Pool<ConnectionManager<SqliteConnection>>;
 
@@ -24,6 +27,54 @@ pub fn setup_test_pool() -> TestPool {
     pool
 }
 
+/// Birth date (YYYY-MM-DD) of someone turning `age` today
+pub fn birth_date_for_age(age: u32) -> String {
+    Local::now()
+        .date_naive()
+        .checked_sub_months(Months::new(age * 12))
+        .expect("Birth date out of range")
+        .format("%Y-%m-%d")
+        .to_string()
+}
+
+/// Stored workout timestamp (RFC3339 UTC) of local noon on the given date
+pub fn local_noon_timestamp(date: &str) -> String {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .expect("Invalid date")
+        .and_hms_opt(12, 0, 0)
+        .unwrap()
+        .and_local_timezone(Local)
+        .unwrap()
+        .to_utc()
+        .to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+/// Stored timestamp (RFC3339 UTC) `minutes` after `started_at`
+pub fn offset(started_at: &str, minutes: i64) -> String {
+    (DateTime::parse_from_rfc3339(started_at).unwrap() + Duration::minutes(minutes))
+        .to_utc()
+        .to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+/// A working set of `reps` at `weight_kg`
+pub fn lifting_set(reps: i32, weight_kg: f64) -> LiftingSetMetrics {
+    LiftingSetMetrics {
+        reps: Some(reps),
+        weight_kg,
+        ..Default::default()
+    }
+}
+
+/// Every workout in the history, most recent first
+pub fn history(app: &tauri::App<tauri::test::MockRuntime>) -> Vec<WorkoutDetail> {
+    list_workouts(
+        app.state(),
+        "2000-01-01T00:00:00Z".to_string(),
+        "2100-01-01T00:00:00Z".to_string(),
+    )
+    .unwrap()
+}
+
 /// Creates a test user in the database
 pub fn create_test_user(pool: &TestPool, name: &str, avatar: &str) -> LibreUser {
     let mut conn = pool.get().expect("Failed to get connection");
@@ -1,5 +1,9 @@
-use crate::helpers::setup_test_pool;
-use librefit_lib::{db::schema::body_data::activity_level, service::body::BodyData};
+use crate::helpers::{birth_date_for_age, setup_test_pool};
+use librefit_lib::scenario;
+use librefit_lib::{
+    db::schema::body_data::activity_level,
+    service::body::{BodyData, NewBodyData},
+};
 
 /// Should fail when no body data exists
 #[test]
@@ -19,12 +23,19 @@ fn test_update_body_data_creates_new() {
     let mut conn = pool.get().unwrap();
 
     // When no body data exists, update_body_data should create one
-    let result = BodyData::update(&mut conn, &30, &175.0, &75.0, "male", &1.25);
+    let result = BodyData::update(
+        &mut conn,
+        &birth_date_for_age(30),
+        &175.0,
+        &75.0,
+        "male",
+        &1.25,
+    );
 
     assert!(result.is_ok());
     let created = result.unwrap();
     assert_eq!(created.id, 1); // First body data gets ID 1
-    assert_eq!(created.age, 30);
+    assert_eq!(created.birth_date, birth_date_for_age(30));
     assert_eq!(created.height, 175.0);
     assert_eq!(created.weight, 75.0);
     assert_eq!(created.sex, "male");
@@ -38,15 +49,30 @@ fn test_update_body_data_updates_existing() {
     let mut conn = pool.get().unwrap();
 
     // Create initial body data
-    BodyData::update(&mut conn, &25, &170.0, &70.0, "female", &1.25).unwrap();
+    BodyData::update(
+        &mut conn,
+        &birth_date_for_age(25),
+        &170.0,
+        &70.0,
+        "female",
+        &1.25,
+    )
+    .unwrap();
 
     // Update the body data
-    let result = BodyData::update(&mut conn, &26, &171.0, &69.0, "female", &1.5);
+    let result = BodyData::update(
+        &mut conn,
+        &birth_date_for_age(26),
+        &171.0,
+        &69.0,
+        "female",
+        &1.5,
+    );
 
     assert!(result.is_ok());
     let updated = result.unwrap();
     assert_eq!(updated.id, 1); // Should still be ID 1
-    assert_eq!(updated.age, 26);
+    assert_eq!(updated.birth_date, birth_date_for_age(26));
     assert_eq!(updated.height, 171.0);
     assert_eq!(updated.weight, 69.0);
     assert_eq!(updated.sex, "female");
@@ -54,7 +80,7 @@ fn test_update_body_data_updates_existing() {
 
     // Verify only one body data record exists
     let fetched = BodyData::get(&mut conn).unwrap();
-    assert_eq!(fetched.age, 26);
+    assert_eq!(fetched.birth_date, birth_date_for_age(26));
 }
 
 /// Should return created body data immediately
@@ -64,14 +90,22 @@ fn test_get_body_data_after_creation() {
     let mut conn = pool.get().unwrap();
 
     // Create body data
-    BodyData::update(&mut conn, &35, &180.0, &85.0, "male", &1.25).unwrap();
+    BodyData::update(
+        &mut conn,
+        &birth_date_for_age(35),
+        &180.0,
+        &85.0,
+        "male",
+        &1.25,
+    )
+    .unwrap();
 
     // Get body data
     let result = BodyData::get(&mut conn);
 
     assert!(result.is_ok());
     let fetched = result.unwrap();
-    assert_eq!(fetched.age, 35);
+    assert_eq!(fetched.birth_date, birth_date_for_age(35));
     assert_eq!(fetched.height, 180.0);
     assert_eq!(fetched.weight, 85.0);
     assert_eq!(fetched.sex, "male");
@@ -85,13 +119,37 @@ fn test_update_body_data_multiple_times() {
     let mut conn = pool.get().unwrap();
 
     // Create and update multiple times
-    BodyData::update(&mut conn, &20, &160.0, &50.0, "female", &1.25).unwrap();
-    BodyData::update(&mut conn, &21, &161.0, &51.0, "female", &1.5).unwrap();
-    let final_update = BodyData::update(&mut conn, &22, &162.0, &52.0, "female", &1.75).unwrap();
+    BodyData::update(
+        &mut conn,
+        &birth_date_for_age(20),
+        &160.0,
+        &50.0,
+        "female",
+        &1.25,
+    )
+    .unwrap();
+    BodyData::update(
+        &mut conn,
+        &birth_date_for_age(21),
+        &161.0,
+        &51.0,
+        "female",
+        &1.5,
+    )
+    .unwrap();
+    let final_update = BodyData::update(
+        &mut conn,
+        &birth_date_for_age(22),
+        &162.0,
+        &52.0,
+        "female",
+        &1.75,
+    )
+    .unwrap();
 
     // Should still be the same record (ID 1)
     assert_eq!(final_update.id, 1);
-    assert_eq!(final_update.age, 22);
+    assert_eq!(final_update.birth_date, birth_date_for_age(22));
     assert_eq!(final_update.height, 162.0);
     assert_eq!(final_update.weight, 52.0);
     assert_eq!(final_update.activity_level, 1.75);
@@ -104,10 +162,25 @@ fn test_update_body_data_change_sex() {
     let mut conn = pool.get().unwrap();
 
     // Create with one sex
-    BodyData::update(&mut conn, &30, &175.0, &75.0, "male", &1.25).unwrap();
+    BodyData::update(
+        &mut conn,
+        &birth_date_for_age(30),
+        &175.0,
+        &75.0,
+        "male",
+        &1.25,
+    )
+    .unwrap();
 
     // Update to different sex
-    let result = BodyData::update(&mut conn, &30, &175.0, &75.0, "female", &1.25);
+    let result = BodyData::update(
+        &mut conn,
+        &birth_date_for_age(30),
+        &175.0,
+        &75.0,
+        "female",
+        &1.25,
+    );
 
     assert!(result.is_ok());
     let updated = result.unwrap();
@@ -121,11 +194,18 @@ fn test_update_body_data_boundary_values() {
     let mut conn = pool.get().unwrap();
 
     // Test with edge case values
-    let result = BodyData::update(&mut conn, &18, &100.0, &30.0, "male", &2.0);
+    let result = BodyData::update(
+        &mut conn,
+        &birth_date_for_age(18),
+        &100.0,
+        &30.0,
+        "male",
+        &2.0,
+    );
 
     assert!(result.is_ok());
     let created = result.unwrap();
-    assert_eq!(created.age, 18);
+    assert_eq!(created.birth_date, birth_date_for_age(18));
     assert_eq!(created.height, 100.0);
     assert_eq!(created.weight, 30.0);
     assert_eq!(created.activity_level, 2.0);
@@ -138,11 +218,18 @@ fn test_update_body_data_high_values() {
     let mut conn = pool.get().unwrap();
 
     // Test with high values
-    let result = BodyData::update(&mut conn, &100, &250.0, &300.0, "male", &1.25);
+    let result = BodyData::update(
+        &mut conn,
+        &birth_date_for_age(100),
+        &250.0,
+        &300.0,
+        "male",
+        &1.25,
+    );
 
     assert!(result.is_ok());
     let created = result.unwrap();
-    assert_eq!(created.age, 100);
+    assert_eq!(created.birth_date, birth_date_for_age(100));
     assert_eq!(created.height, 250.0);
     assert_eq!(created.weight, 300.0);
 }
@@ -154,7 +241,14 @@ fn test_update_body_data_precision() {
     let mut conn = pool.get().unwrap();
 
     // Test decimal precision
-    let result = BodyData::update(&mut conn, &30, &175.456, &75.789, "male", &1.25);
+    let result = BodyData::update(
+        &mut conn,
+        &birth_date_for_age(30),
+        &175.456,
+        &75.789,
+        "male",
+        &1.25,
+    );
 
     assert!(result.is_ok());
     let created = result.unwrap();
@@ -169,7 +263,14 @@ fn test_update_body_data_custom_sex_value() {
     let mut conn = pool.get().unwrap();
 
     // Test with custom sex value (the database doesn't enforce enum, just string)
-    let result = BodyData::update(&mut conn, &30, &175.0, &75.0, "other", &1.25);
+    let result = BodyData::update(
+        &mut conn,
+        &birth_date_for_age(30),
+        &175.0,
+        &75.0,
+        "other",
+        &1.25,
+    );
 
     assert!(result.is_ok());
     let created = result.unwrap();
@@ -184,11 +285,12 @@ fn test_body_data_validation_age_too_low() {
 
     let body = BodyData {
         id: 1,
-        age: 17, // Invalid: below minimum of 18
+        birth_date: birth_date_for_age(17), // Invalid: below minimum of 18
         height: 175.0,
         weight: 75.0,
         sex: "male".to_string(),
         activity_level: 1.25,
+        effective_date: "2026-01-01".to_string(),
     };
 
     let validation = body.validate();
@@ -201,11 +303,12 @@ fn test_body_data_validation_age_too_high() {
 
     let body = BodyData {
         id: 1,
-        age: 100, // Invalid: above maximum of 99
+        birth_date: birth_date_for_age(100), // Invalid: above maximum of 99
         height: 175.0,
         weight: 75.0,
         sex: "male".to_string(),
         activity_level: 1.25,
+        effective_date: "2026-01-01".to_string(),
     };
 
     let validation = body.validate();
@@ -218,11 +321,12 @@ fn test_body_data_validation_height_too_low() {
 
     let body = BodyData {
         id: 1,
-        age: 30,
+        birth_date: birth_date_for_age(30),
         height: 99.9, // Invalid: below minimum of 100.0
         weight: 75.0,
         sex: "male".to_string(),
         activity_level: 1.25,
+        effective_date: "2026-01-01".to_string(),
     };
 
     let validation = body.validate();
@@ -235,11 +339,12 @@ fn test_body_data_validation_height_too_high() {
 
     let body = BodyData {
         id: 1,
-        age: 30,
+        birth_date: birth_date_for_age(30),
         height: 220.1, // Invalid: above maximum of 220.0
         weight: 75.0,
         sex: "male".to_string(),
         activity_level: 1.25,
+        effective_date: "2026-01-01".to_string(),
     };
 
     let validation = body.validate();
@@ -252,11 +357,12 @@ fn test_body_data_validation_weight_too_low() {
 
     let body = BodyData {
         id: 1,
-        age: 30,
+        birth_date: birth_date_for_age(30),
         height: 175.0,
         weight: 29.9, // Invalid: below minimum of 30.0
         sex: "male".to_string(),
         activity_level: 1.25,
+        effective_date: "2026-01-01".to_string(),
     };
 
     let validation = body.validate();
@@ -269,11 +375,12 @@ fn test_body_data_validation_weight_too_high() {
 
     let body = BodyData {
         id: 1,
-        age: 30,
+        birth_date: birth_date_for_age(30),
         height: 175.0,
         weight: 330.1, // Invalid: above maximum of 330.0
         sex: "male".to_string(),
         activity_level: 1.25,
+        effective_date: "2026-01-01".to_string(),
     };
 
     let validation = body.validate();
@@ -286,13 +393,109 @@ fn test_body_data_validation_activity_level_unknown() {
 
     let body = BodyData {
         id: 1,
-        age: 30,
+        birth_date: birth_date_for_age(30),
         height: 175.0,
         weight: 330.0,
         sex: "male".to_string(),
         activity_level: 1.6,
+        effective_date: "2026-01-01".to_string(),
     };
 
     let validation = body.validate();
     assert!(validation.is_err());
 }
+
+/// Body Data History
+
+#[test]
+fn recording_a_new_version_keeps_the_previous_one() {
+    scenario!("[PF-016]");
+    let pool = setup_test_pool();
+    let mut conn = pool.get().unwrap();
+
+    BodyData::record(&mut conn, &body_data_version("2026-01-01", 30, 80.0, 1.25)).unwrap();
+    BodyData::record(&mut conn, &body_data_version("2026-04-01", 30, 76.0, 1.5)).unwrap();
+
+    let history = BodyData::history(&mut conn).unwrap();
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[0].effective_date, "2026-01-01");
+    assert_eq!(history[0].activity_level, 1.25);
+    assert_eq!(history[1].effective_date, "2026-04-01");
+    assert_eq!(history[1].activity_level, 1.5);
+
+    // The current profile is the latest version
+    let current = BodyData::get(&mut conn).unwrap();
+    assert_eq!(current.effective_date, "2026-04-01");
+    assert_eq!(current.weight, 76.0);
+}
+
+#[test]
+fn as_of_returns_the_version_in_effect_on_that_date() {
+    scenario!("[PF-017]");
+    let pool = setup_test_pool();
+    let mut conn = pool.get().unwrap();
+
+    BodyData::record(&mut conn, &body_data_version("2026-01-01", 30, 80.0, 1.25)).unwrap();
+    BodyData::record(&mut conn, &body_data_version("2026-04-01", 30, 76.0, 1.5)).unwrap();
+
+    let winter = BodyData::as_of(&mut conn, "2026-03-31").unwrap();
+    assert_eq!(winter.effective_date, "2026-01-01");
+    assert_eq!(winter.activity_level, 1.25);
+
+    // A version applies from its effective date on
+    let spring = BodyData::as_of(&mut conn, "2026-04-01").unwrap();
+    assert_eq!(spring.effective_date, "2026-04-01");
+    assert_eq!(spring.weight, 76.0);
+
+    let later = BodyData::as_of(&mut conn, "2026-09-15").unwrap();
+    assert_eq!(later.effective_date, "2026-04-01");
+
+    // Nothing was recorded before the first version
+    assert!(BodyData::as_of(&mut conn, "2025-12-31").is_err());
+}
+
+#[test]
+fn recording_twice_on_the_same_date_replaces_that_version() {
+    scenario!("[PF-018]");
+    let pool = setup_test_pool();
+    let mut conn = pool.get().unwrap();
+
+    let first =
+        BodyData::record(&mut conn, &body_data_version("2026-01-01", 30, 80.0, 1.25)).unwrap();
+    let corrected =
+        BodyData::record(&mut conn, &body_data_version("2026-01-01", 30, 79.0, 1.25)).unwrap();
+
+    assert_eq!(corrected.id, first.id);
+    assert_eq!(corrected.weight, 79.0);
+    assert_eq!(BodyData::history(&mut conn).unwrap().len(), 1);
+}
+
+#[test]
+fn test_body_data_validation_effective_date_in_future() {
+    use validator::Validate;
+
+    let body = BodyData {
+        id: 1,
+        birth_date: birth_date_for_age(30),
+        height: 175.0,
+        weight: 75.0,
+        sex: "male".to_string(),
+        activity_level: 1.25,
+        effective_date: "2999-01-01".to_string(),
+    };
+
+    let validation = body.validate();
+    assert!(validation.is_err());
+}
+
+/// Builds a body data version with fixed height and sex
+fn body_data_version(effective_date: &str, age: u32, weight: f32, activity: f32) -> NewBodyData {
+    NewBodyData {
+        birth_date: birth_date_for_age(age),
+        height: 175.0,
+        weight,
+        sex: "MALE".to_string(),
+        activity_level: activity,
+        effective_date: effective_date.to_string(),
+    }
+}
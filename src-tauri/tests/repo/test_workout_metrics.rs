@@ -6,24 +6,91 @@
 //!   2. the JSON1 SQL migration transform, run against the real bundled SQLite.
 
 use crate::helpers::setup_test_pool;
+use diesel::connection::SimpleConnection;
 use diesel::prelude::*;
 use diesel::sql_query;
 use librefit_lib::db::schema::workout_set;
+use librefit_lib::scenario;
 use librefit_lib::service::workout::{
-    LiftingSetMetrics, WorkoutExercise, WorkoutSession, WorkoutSet, WL_PAYLOAD_VER,
+    LiftingSetMetrics, SetMetrics, SwimmingSetMetrics, WorkoutExercise, WorkoutSession, WorkoutSet,
+    WL_PAYLOAD_VER,
 };
 
 #[test]
 fn from_stored_ignores_unknown_keys() {
-    // "permissive on parse" — an extra/future key (e.g. rpe) does not break a v1 read.
-    let m = LiftingSetMetrics::from_stored(1, r#"{"reps":10,"weightKg":80.0,"rpe":8}"#).unwrap();
-    assert_eq!(m.reps, 10);
+    // "permissive on parse" — an extra/future key (e.g. tempo) does not break a v1 read.
+    let m = LiftingSetMetrics::from_stored(1, r#"{"reps":10,"weightKg":80.0,"tempo":"3-1-1"}"#)
+        .unwrap();
+    assert_eq!(m.reps, Some(10));
     assert_eq!(m.weight_kg, 80.0);
 }
 
+#[test]
+fn from_stored_upcasts_v1_to_a_loaded_set() {
+    let m = LiftingSetMetrics::from_stored(1, r#"{"reps":10,"weightKg":80.0}"#).unwrap();
+    assert_eq!(
+        m,
+        LiftingSetMetrics {
+            reps: Some(10),
+            weight_kg: 80.0,
+            ..Default::default()
+        }
+    );
+}
+
 #[test]
 fn from_stored_rejects_unknown_payload_version() {
-    assert!(LiftingSetMetrics::from_stored(2, r#"{"reps":10,"weightKg":80.0}"#).is_err());
+    assert!(LiftingSetMetrics::from_stored(4, r#"{"reps":10,"weightKg":80.0}"#).is_err());
+}
+
+#[test]
+fn set_metrics_dispatch_on_workout_type() {
+    let stored = r#"{"durationSeconds":1200,"distanceM":1000.0,"poolLengthM":25}"#;
+
+    let swim = SetMetrics::from_stored("swim", 1, stored).unwrap();
+    assert_eq!(
+        swim,
+        SetMetrics::Swimming(SwimmingSetMetrics {
+            duration_seconds: 1200,
+            distance_m: 1000.0,
+            avg_heart_rate: None,
+            pool_length_m: Some(25),
+        })
+    );
+    // The same blob is no lifting set, and unknown types are rejected
+    assert!(SetMetrics::from_stored("wl", 1, stored).is_err());
+    assert!(SetMetrics::from_stored("ski", 1, stored).is_err());
+}
+
+#[test]
+fn set_metrics_are_tagged_on_the_wire_but_stored_untagged() {
+    let lifting = SetMetrics::Lifting(LiftingSetMetrics {
+        reps: Some(10),
+        weight_kg: 80.0,
+        ..Default::default()
+    });
+
+    assert_eq!(
+        serde_json::to_value(&lifting).unwrap(),
+        serde_json::json!({
+            "type": "wl",
+            "data": {
+                "reps": 10,
+                "durationSeconds": null,
+                "weightKg": 80.0,
+                "bodyweight": false,
+                "addedLoadKg": null,
+                "rpe": null,
+                "rir": null,
+                "kind": "working",
+                "note": null
+            }
+        })
+    );
+    assert_eq!(
+        lifting.to_stored().unwrap(),
+        r#"{"reps":10,"durationSeconds":null,"weightKg":80.0,"bodyweight":false,"addedLoadKg":null,"rpe":null,"rir":null,"kind":"working","note":null}"#
+    );
 }
 
 #[test]
@@ -31,12 +98,14 @@ fn json1_structural_migration_transforms_stored_payload() {
     let pool = setup_test_pool();
     let mut conn = pool.get().unwrap();
 
-    // Persist a real v1 set through the repo (FK chain: session -> exercise -> set).
+    // Persist a real current-version set through the repo (FK chain: session -> exercise
+    // -> set).
     let session = WorkoutSession::start(&mut conn, "wl", None).unwrap();
     let we = WorkoutExercise::add_or_get(&mut conn, session.id, 1).unwrap();
     let metrics = serde_json::to_string(&LiftingSetMetrics {
-        reps: 10,
+        reps: Some(10),
         weight_kg: 80.0,
+        ..Default::default()
     })
     .unwrap();
     let set = WorkoutSet::log(
@@ -49,17 +118,20 @@ fn json1_structural_migration_transforms_stored_payload() {
     .unwrap();
 
     // The documented JSON1 migration path for a structural change, expressed as the SQL
-    // a future Diesel migration would run: rename weightKg -> loadKg, add rpe, bump version.
-    let affected = sql_query(
+    // a future Diesel migration would run: rename weightKg -> loadKg, add tempo, bump version.
+    // (The shipped v1 -> v2 migration is exercised below.)
+    let next_ver = WL_PAYLOAD_VER + 1;
+    let affected = sql_query(format!(
         "UPDATE workout_set \
          SET metrics = json_set( \
                           json_remove( \
                              json_set(metrics, '$.loadKg', json_extract(metrics, '$.weightKg')), \
                              '$.weightKg'), \
-                          '$.rpe', 8), \
-             payload_ver = 2 \
-         WHERE payload_ver = 1",
-    )
+                          '$.tempo', '3-1-1'), \
+             payload_ver = {} \
+         WHERE payload_ver = {}",
+        next_ver, WL_PAYLOAD_VER
+    ))
     .execute(&mut conn)
     .unwrap();
     assert_eq!(affected, 1);
@@ -70,17 +142,135 @@ fn json1_structural_migration_transforms_stored_payload() {
         .filter(workout_set::id.eq(set.id))
         .first(&mut conn)
         .unwrap();
-    assert_eq!(ver, 2);
+    assert_eq!(ver, next_ver);
     let v: serde_json::Value = serde_json::from_str(&blob).unwrap();
     assert_eq!(v["loadKg"], 80.0);
-    assert_eq!(v["rpe"], 8);
+    assert_eq!(v["tempo"], "3-1-1");
     assert!(v.get("weightKg").is_none());
 
     // The queryability win: `payload_ver` makes un-migrated rows findable — none remain.
     let unmigrated: i64 = workout_set::table
-        .filter(workout_set::payload_ver.lt(2))
+        .filter(workout_set::payload_ver.lt(next_ver))
         .count()
         .get_result(&mut conn)
         .unwrap();
     assert_eq!(unmigrated, 0);
 }
+
+#[test]
+fn lifting_v2_migration_upgrades_stored_v1_sets() {
+    scenario!("[WO-035]");
+    let pool = setup_test_pool();
+    let mut conn = pool.get().unwrap();
+
+    // v1 sets as they were stored before the upgrade: Bench Press (barbell), Pull-up
+    // (bodyweight, with a dip belt), and a run set that must stay untouched.
+    let lifting = WorkoutSession::start(&mut conn, "wl", None).unwrap();
+    let bench = WorkoutExercise::add_or_get(&mut conn, lifting.id, 1).unwrap();
+    let pull_up = WorkoutExercise::add_or_get(&mut conn, lifting.id, 6).unwrap();
+    let running = WorkoutSession::create_completed(
+        &mut conn,
+        "run",
+        None,
+        "2026-05-30T18:00:00.000Z".to_string(),
+    )
+    .unwrap();
+    let outdoor_run = WorkoutExercise::add_or_get(&mut conn, running.id, 53).unwrap();
+
+    let logged_at = "2026-05-31T18:00:00.000Z".to_string();
+    let mut log_v1 = |we_id: i32, json: &str| {
+        WorkoutSet::log(&mut conn, we_id, json.to_string(), 1, logged_at.clone())
+            .unwrap()
+            .id
+    };
+    let bench_set = log_v1(bench.id, r#"{"reps":5,"weightKg":100.0}"#);
+    let pull_up_set = log_v1(pull_up.id, r#"{"reps":8,"weightKg":20.0}"#);
+    let run_set = log_v1(
+        outdoor_run.id,
+        r#"{"durationSeconds":1500,"distanceM":5000.0}"#,
+    );
+
+    conn.batch_execute(include_str!(
+        "../../migrations/2026-10-19-140000_lifting_set_metrics_v2/up.sql"
+    ))
+    .unwrap();
+
+    let mut stored = |id: i32| -> (i32, String) {
+        workout_set::table
+            .select((workout_set::payload_ver, workout_set::metrics))
+            .filter(workout_set::id.eq(id))
+            .first(&mut conn)
+            .unwrap()
+    };
+
+    let (ver, blob) = stored(bench_set);
+    assert_eq!(ver, 2);
+    assert_eq!(
+        LiftingSetMetrics::from_stored(ver, &blob).unwrap(),
+        LiftingSetMetrics {
+            reps: Some(5),
+            weight_kg: 100.0,
+            ..Default::default()
+        }
+    );
+
+    // The former load of a bodyweight exercise is its added load
+    let (ver, blob) = stored(pull_up_set);
+    assert_eq!(ver, 2);
+    let pull_up_metrics = LiftingSetMetrics::from_stored(ver, &blob).unwrap();
+    assert_eq!(
+        pull_up_metrics,
+        LiftingSetMetrics {
+            reps: Some(8),
+            weight_kg: 0.0,
+            bodyweight: true,
+            added_load_kg: Some(20.0),
+            ..Default::default()
+        }
+    );
+    assert_eq!(pull_up_metrics.volume(), 160.0);
+
+    let (ver, _) = stored(run_set);
+    assert_eq!(ver, 1);
+}
+
+#[test]
+fn annotation_migration_marks_stored_v2_sets_as_working() {
+    scenario!("[WO-036]");
+    let pool = setup_test_pool();
+    let mut conn = pool.get().unwrap();
+
+    let session = WorkoutSession::start(&mut conn, "wl", None).unwrap();
+    let we = WorkoutExercise::add_or_get(&mut conn, session.id, 1).unwrap();
+    let set = WorkoutSet::log(
+        &mut conn,
+        we.id,
+        r#"{"reps":5,"durationSeconds":null,"weightKg":100.0,"bodyweight":false,"addedLoadKg":null}"#
+            .to_string(),
+        2,
+        "2026-05-31T18:00:00.000Z".to_string(),
+    )
+    .unwrap();
+
+    conn.batch_execute(include_str!(
+        "../../migrations/2026-10-19-150000_lifting_set_annotations/up.sql"
+    ))
+    .unwrap();
+
+    let (ver, blob): (i32, String) = workout_set::table
+        .select((workout_set::payload_ver, workout_set::metrics))
+        .filter(workout_set::id.eq(set.id))
+        .first(&mut conn)
+        .unwrap();
+    assert_eq!(ver, WL_PAYLOAD_VER);
+    let v: serde_json::Value = serde_json::from_str(&blob).unwrap();
+    assert_eq!(v["kind"], "working");
+    assert_eq!(
+        LiftingSetMetrics::from_stored(ver, &blob).unwrap(),
+        LiftingSetMetrics {
+            reps: Some(5),
+            weight_kg: 100.0,
+            ..Default::default()
+        }
+    );
+}
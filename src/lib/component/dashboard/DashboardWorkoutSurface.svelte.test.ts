@@ -2,6 +2,7 @@ import { render, fireEvent, screen } from '@testing-library/svelte';
 import { describe, it, expect, vi } from 'vitest';
 import DashboardWorkoutSurface from './DashboardWorkoutSurface.svelte';
 import type { WorkoutDetail } from '$lib/api';
+import { workingSet } from '$lib/workout/metrics';
 
 // The dashboard idle workout surface: cards (DH-013), refresh-on-update (DH-015),
 // loading (DH-017), and error+retry (DH-018). Presentational, so we drive it by props.
@@ -20,7 +21,13 @@ function workout(id: number, name: string): WorkoutDetail {
 				exerciseId: 1,
 				name: 'Bench Press',
 				defaultRestSeconds: 90,
-				sets: [{ id, loggedAt: '2026-06-04T10:05:00.000Z', metrics: { reps: 10, weightKg: 80 } }]
+				sets: [
+					{
+						id,
+						loggedAt: '2026-06-04T10:05:00.000Z',
+						metrics: { type: 'wl', data: workingSet(10, 80) }
+					}
+				]
 			}
 		],
 		pauses: []
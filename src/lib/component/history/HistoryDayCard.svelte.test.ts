@@ -4,6 +4,7 @@ import userEvent from '@testing-library/user-event';
 import type { Intake, IntakeTarget, WeightTracker, WorkoutDetail } from '$lib/api';
 import HistoryDayCard from './HistoryDayCard.svelte';
 import TestWrapper from '../../../../tests/utils/TestWrapper.svelte';
+import { workingSet } from '$lib/workout/metrics';
 
 const mockCategories = [
 	{ shortvalue: 'b', longvalue: 'Breakfast' },
@@ -60,7 +61,13 @@ function makeWorkout(id: number, name: string): WorkoutDetail {
 				exerciseId: 1,
 				name: 'Bench Press',
 				defaultRestSeconds: 90,
-				sets: [{ id, loggedAt: '2026-05-20T10:05:00.000Z', metrics: { reps: 10, weightKg: 80 } }]
+				sets: [
+					{
+						id,
+						loggedAt: '2026-05-20T10:05:00.000Z',
+						metrics: { type: 'wl', data: workingSet(10, 80) }
+					}
+				]
 			}
 		],
 		pauses: []
@@ -6,7 +6,7 @@ import { CalculationSexSchema } from '$lib/api/gen';
 
 function makeProps() {
 	const wizardInput: WizardInput = $state({
-		age: 30,
+		birthDate: '1996-05-01',
 		sex: CalculationSexSchema.enum.MALE,
 		weight: 85,
 		height: 180,
@@ -21,7 +21,7 @@ function makeProps() {
 }
 
 describe('Body', () => {
-	it('[OB-005] should render the nickname, sex, age, height, and weight inputs', () => {
+	it('[OB-005] should render the nickname, sex, birth date, height, and weight inputs', () => {
 		const props = makeProps();
 		render(Body, { props });
 
@@ -32,15 +32,15 @@ describe('Body', () => {
 		expect(screen.getByRole('button', { name: /Male/ })).toBeTruthy();
 		expect(screen.getByRole('button', { name: /Female/ })).toBeTruthy();
 
-		// Range inputs for age/height/weight are present.
-		expect(screen.getByText(/Age/)).toBeTruthy();
+		// A date input for the birth date, range inputs for height/weight.
+		expect(screen.getByLabelText(/Date of birth/)).toBeTruthy();
 		expect(screen.getByText(/Height/)).toBeTruthy();
 		expect(screen.getByText(/Weight/)).toBeTruthy();
 	});
 
-	it('[OB-005] should reflect updated wizardInput values on the range inputs', () => {
+	it('[OB-005] should reflect updated wizardInput values on the inputs', () => {
 		const props = makeProps();
-		props.wizardInput.age = 42;
+		props.wizardInput.birthDate = '1984-02-29';
 		props.wizardInput.height = 175;
 		props.wizardInput.weight = 70;
 
@@ -50,8 +50,11 @@ describe('Body', () => {
 			document.querySelectorAll<HTMLInputElement>('input[type="range"]')
 		).map((el) => el.value);
 
-		// Order in Body.svelte is Age, Height, Weight.
-		expect(ranges).toContain('42');
+		expect(document.querySelector<HTMLInputElement>('input[type="date"]')?.value).toBe(
+			'1984-02-29'
+		);
+		// Order in Body.svelte is Height, Weight.
+		expect(ranges).toHaveLength(2);
 		expect(ranges).toContain('175');
 		expect(ranges).toContain('70');
 	});
@@ -6,7 +6,7 @@ function makePayload(overrides: Partial<SetupPayload> = {}): SetupPayload {
 		userName: 'Alice',
 		userAvatar: 'avatar-seed',
 		input: {
-			age: 30,
+			birthDate: '1996-05-01',
 			sex: 'FEMALE' as never,
 			weight: 70,
 			height: 170,
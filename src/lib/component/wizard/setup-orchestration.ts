@@ -18,7 +18,7 @@ export interface SetupPayload {
 export interface SetupDeps {
 	updateUser: (args: { userName: string; userAvatar: string }) => Promise<LibreUser | null>;
 	updateBodyData: (args: {
-		age: number;
+		birthDate: string;
 		sex: WizardInput['sex'];
 		height: number;
 		weight: number;
@@ -57,7 +57,7 @@ export async function performSetup(payload: SetupPayload, deps: SetupDeps): Prom
 		currentStep = 'body';
 		await deps.onStepStart?.('body');
 		await deps.updateBodyData({
-			age: payload.input.age,
+			birthDate: payload.input.birthDate,
 			sex: payload.input.sex,
 			height: payload.input.height,
 			weight: payload.input.weight,
@@ -1,6 +1,7 @@
 import { describe, it, expect, vi } from 'vitest';
 import { render, fireEvent, screen } from '@testing-library/svelte';
 import SetMask from './SetMask.svelte';
+import { workingSet } from '$lib/workout/metrics';
 
 // Reps + weight entry. Submits validated `LiftingSetMetrics`; client validation
 // mirrors the backend schema (the canonical bounds live in metrics.test.ts).
@@ -25,7 +26,7 @@ describe('SetMask', () => {
 		const onsubmit = vi.fn();
 		render(SetMask, { props: { reps: 10, weightKg: 80, onsubmit } });
 		await fireEvent.click(screen.getByRole('button', { name: 'Log set' }));
-		expect(onsubmit).toHaveBeenCalledWith({ reps: 10, weightKg: 80 });
+		expect(onsubmit).toHaveBeenCalledWith(workingSet(10, 80));
 	});
 
 	it('blocks submit and shows an error for invalid reps', async () => {
@@ -8,8 +8,10 @@ import {
 	getExerciseLibrary,
 	updateWorkoutSet,
 	type ExerciseDetail,
+	type LoggedSet,
 	type WorkoutDetail
 } from '$lib/api';
+import { workingSet } from '$lib/workout/metrics';
 
 // Flat-CRUD editor ([HI-020] edit, [HI-022] add). The persistence commands are
 // stubbed; we assert the editor calls them with the right args. SetMask submits its
@@ -50,7 +52,7 @@ const exerciseView = (
 	id: number,
 	exerciseId: number,
 	name: string,
-	sets: { id: number; loggedAt: string; metrics: { reps: number; weightKg: number } }[]
+	sets: { id: number; loggedAt: string; metrics: LoggedSet['metrics'] }[]
 ) => ({ id, exerciseId, name, defaultRestSeconds: 90, sets });
 
 beforeEach(() => vi.clearAllMocks());
@@ -62,7 +64,11 @@ describe('WorkoutEditModal', () => {
 		vi.mocked(addWorkoutSet).mockResolvedValue(
 			session(100, [
 				exerciseView(1, 1, 'Bench Press', [
-					{ id: 1, loggedAt: '2026-06-01T12:00:00.000Z', metrics: { reps: 8, weightKg: 20 } }
+					{
+						id: 1,
+						loggedAt: '2026-06-01T12:00:00.000Z',
+						metrics: { type: 'wl', data: workingSet(8, 20) }
+					}
 				])
 			])
 		);
@@ -79,14 +85,18 @@ describe('WorkoutEditModal', () => {
 
 		expect(createWorkoutForDate).toHaveBeenCalledTimes(1);
 		expect(addWorkoutSet).toHaveBeenCalledWith(
-			expect.objectContaining({ sessionId: 100, exerciseId: 1, metrics: { reps: 8, weightKg: 20 } })
+			expect.objectContaining({ sessionId: 100, exerciseId: 1, metrics: workingSet(8, 20) })
 		);
 	});
 
 	it('[HI-020] [DH-014] edit mode: editing a set calls updateWorkoutSet with the set id', async () => {
 		const detail = session(5, [
 			exerciseView(10, 1, 'Bench Press', [
-				{ id: 50, loggedAt: '2026-06-01T12:00:00.000Z', metrics: { reps: 10, weightKg: 80 } }
+				{
+					id: 50,
+					loggedAt: '2026-06-01T12:00:00.000Z',
+					metrics: { type: 'wl', data: workingSet(10, 80) }
+				}
 			])
 		]);
 		vi.mocked(updateWorkoutSet).mockResolvedValue(detail);
@@ -98,14 +108,18 @@ describe('WorkoutEditModal', () => {
 
 		expect(updateWorkoutSet).toHaveBeenCalledWith({
 			setId: 50,
-			metrics: { reps: 10, weightKg: 80 }
+			metrics: workingSet(10, 80)
 		});
 	});
 
 	it('[HI-020] edit mode: deleting a set calls deleteWorkoutSet with the set id', async () => {
 		const detail = session(5, [
 			exerciseView(10, 1, 'Bench Press', [
-				{ id: 50, loggedAt: '2026-06-01T12:00:00.000Z', metrics: { reps: 10, weightKg: 80 } }
+				{
+					id: 50,
+					loggedAt: '2026-06-01T12:00:00.000Z',
+					metrics: { type: 'wl', data: workingSet(10, 80) }
+				}
 			])
 		]);
 		vi.mocked(deleteWorkoutSet).mockResolvedValue(
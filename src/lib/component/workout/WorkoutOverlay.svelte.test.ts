@@ -21,6 +21,7 @@ vi.mock('$lib/api', () => ({
 import * as api from '$lib/api';
 import WorkoutOverlay from './WorkoutOverlay.svelte';
 import { WorkoutStore } from '$lib/workout/workout-state.svelte';
+import { workingSet } from '$lib/workout/metrics';
 
 type Detail = Awaited<ReturnType<typeof api.getActiveWorkout>>;
 
@@ -71,7 +72,13 @@ describe('WorkoutOverlay', () => {
 					exerciseId: 1,
 					name: 'Back Squat',
 					defaultRestSeconds: 180,
-					sets: [{ id: 1, loggedAt: new Date().toISOString(), metrics: { reps: 8, weightKg: 100 } }]
+					sets: [
+						{
+							id: 1,
+							loggedAt: new Date().toISOString(),
+							metrics: { type: 'wl', data: workingSet(8, 100) }
+						}
+					]
 				}
 			])
 		);
@@ -93,8 +100,16 @@ describe('WorkoutOverlay', () => {
 					name: 'Back Squat',
 					defaultRestSeconds: 180,
 					sets: [
-						{ id: 1, loggedAt: new Date().toISOString(), metrics: { reps: 8, weightKg: 100 } },
-						{ id: 2, loggedAt: new Date().toISOString(), metrics: { reps: 6, weightKg: 110 } }
+						{
+							id: 1,
+							loggedAt: new Date().toISOString(),
+							metrics: { type: 'wl', data: workingSet(8, 100) }
+						},
+						{
+							id: 2,
+							loggedAt: new Date().toISOString(),
+							metrics: { type: 'wl', data: workingSet(6, 110) }
+						}
 					]
 				}
 			])
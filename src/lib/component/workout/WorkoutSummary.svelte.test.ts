@@ -1,7 +1,7 @@
 import { describe, it, expect, vi } from 'vitest';
 import { render, fireEvent, screen } from '@testing-library/svelte';
 import WorkoutSummary from './WorkoutSummary.svelte';
-import type { SessionSummary } from '$lib/workout/metrics';
+import { workingSet, type SessionSummary } from '$lib/workout/metrics';
 import type { WorkoutExerciseView } from '$lib/api';
 
 // Post-workout summary (WO-022): this-session totals + worked-muscle map + the
@@ -42,8 +42,16 @@ describe('WorkoutSummary', () => {
 				name: 'Back Squat',
 				defaultRestSeconds: 180,
 				sets: [
-					{ id: 1, loggedAt: '2026-06-02T10:00:00.000Z', metrics: { reps: 8, weightKg: 100 } },
-					{ id: 2, loggedAt: '2026-06-02T10:03:00.000Z', metrics: { reps: 8, weightKg: 100 } }
+					{
+						id: 1,
+						loggedAt: '2026-06-02T10:00:00.000Z',
+						metrics: { type: 'wl', data: workingSet(8, 100) }
+					},
+					{
+						id: 2,
+						loggedAt: '2026-06-02T10:03:00.000Z',
+						metrics: { type: 'wl', data: workingSet(8, 100) }
+					}
 				]
 			}
 		] as unknown as WorkoutExerciseView[];
@@ -2,6 +2,7 @@ import { render, fireEvent, screen } from '@testing-library/svelte';
 import { describe, it, expect, vi } from 'vitest';
 import WorkoutSummaryCard from './WorkoutSummaryCard.svelte';
 import type { WorkoutDetail } from '$lib/api';
+import { workingSet } from '$lib/workout/metrics';
 
 function detail(): WorkoutDetail {
 	return {
@@ -18,7 +19,13 @@ function detail(): WorkoutDetail {
 				exerciseId: 1,
 				name: 'Bench Press',
 				defaultRestSeconds: 90,
-				sets: [{ id: 1, loggedAt: '2026-06-04T10:05:00.000Z', metrics: { reps: 10, weightKg: 80 } }]
+				sets: [
+					{
+						id: 1,
+						loggedAt: '2026-06-04T10:05:00.000Z',
+						metrics: { type: 'wl', data: workingSet(10, 80) }
+					}
+				]
 			}
 		],
 		pauses: []
@@ -4,6 +4,7 @@ import {
 	Daytime,
 	default_date_format,
 	display_date_format,
+	getAgeFromBirthDate,
 	getDateAsStr,
 	getDaytimeFoodCategory,
 	getDaytimeGreeting,
@@ -40,6 +41,13 @@ describe('parseStringAsDate function', () => {
 	});
 });
 
+describe('birth date functions', () => {
+	it('should return the age in completed years', () => {
+		expect(getAgeFromBirthDate('1996-05-01', new Date(2026, 3, 30))).toBe(29);
+		expect(getAgeFromBirthDate('1996-05-01', new Date(2026, 4, 1))).toBe(30);
+	});
+});
+
 describe('convertDateStrToDisplayDateStr function', () => {
 	it('should convert date string from default to display format', () => {
 		const dateString = '2022-08-01';
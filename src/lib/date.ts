@@ -1,4 +1,4 @@
-import { getHours, format, parse } from 'date-fns';
+import { differenceInYears, getHours, format, parse } from 'date-fns';
 
 export const default_date_format = 'yyyy-MM-dd'; // used for internal storage and fetch requests
 export const display_date_format = 'dd.MM.yyyy'; // used for display only
@@ -19,6 +19,11 @@ export function parseStringAsDate(str: string, dateFormat?: string | undefined)
   return parse(str.valueOf(), dateFormat.valueOf(), new Date());
 }
 
+/** Age in completed years on `on` of a birth date stored in the default format */
+export function getAgeFromBirthDate(birthDate: string, on: Date = new Date()) {
+  return differenceInYears(on, parseStringAsDate(birthDate));
+}
+
 export function convertDateStrToDisplayDateStr(str: string, displayFormat?: string | undefined) {
   const date = parseStringAsDate(str, displayFormat);
   return getDisplayDateAsStr(date);
@@ -9,6 +9,7 @@ import {
 	workoutVolume
 } from '$lib/workout/history';
 import type { ExerciseDetail, WorkoutDetail } from '$lib/api';
+import { workingSet } from '$lib/workout/metrics';
 
 function detail(overrides: Partial<WorkoutDetail['session']> = {}, exercises = []): WorkoutDetail {
 	return {
@@ -45,8 +46,16 @@ describe('workout card derivations', () => {
 				name: 'Bench Press',
 				defaultRestSeconds: 90,
 				sets: [
-					{ id: 1, loggedAt: '2026-05-01T10:05:00.000Z', metrics: { reps: 10, weightKg: 80 } },
-					{ id: 2, loggedAt: '2026-05-01T10:10:00.000Z', metrics: { reps: 8, weightKg: 85 } }
+					{
+						id: 1,
+						loggedAt: '2026-05-01T10:05:00.000Z',
+						metrics: { type: 'wl', data: workingSet(10, 80) }
+					},
+					{
+						id: 2,
+						loggedAt: '2026-05-01T10:10:00.000Z',
+						metrics: { type: 'wl', data: workingSet(8, 85) }
+					}
 				]
 			}
 		] as never);
@@ -3,20 +3,33 @@ import {
 	computeActiveWorkTime,
 	computeRestRemaining,
 	computeSetsCompleted,
+	computeSetVolume,
 	computeTotalVolume,
 	DEFAULT_REST_SECONDS,
+	formatSet,
 	isPaused,
 	isResting,
 	prefillFromPrevious,
 	summarize,
-	validateLiftingSet
+	validateLiftingSet,
+	workingSet
 } from './metrics';
-import type { LoggedSet, WorkoutDetail, WorkoutExerciseView, WorkoutPauseView } from '$lib/api';
+import type {
+	LiftingSetMetrics,
+	LoggedSet,
+	WorkoutDetail,
+	WorkoutExerciseView,
+	WorkoutPauseView
+} from '$lib/api';
 
 const at = (iso: string) => Date.parse(iso);
 
+function liftingSet(id: number, data: LiftingSetMetrics, loggedAt: string): LoggedSet {
+	return { id, loggedAt, metrics: { type: 'wl', data } } as LoggedSet;
+}
+
 function set(id: number, reps: number, weightKg: number, loggedAt: string): LoggedSet {
-	return { id, loggedAt, metrics: { reps, weightKg } };
+	return liftingSet(id, workingSet(reps, weightKg), loggedAt);
 }
 
 function exercise(id: number, sets: LoggedSet[], defaultRestSeconds?: number): WorkoutExerciseView {
@@ -86,6 +99,30 @@ describe('workout derived metrics', () => {
 		expect(computeTotalVolume(exercises)).toBe(10 * 80 + 8 * 80 + 5 * 100);
 	});
 
+	it('[WO-006] total volume skips warm-ups and counts only added bodyweight load', () => {
+		const loggedAt = '2026-06-01T10:01:00.000Z';
+		const warmup = { ...workingSet(10, 40), kind: 'warmup' } as LiftingSetMetrics;
+		const weighted = { ...workingSet(8, 0), bodyweight: true, addedLoadKg: 10 };
+		const assisted = { ...workingSet(8, 0), bodyweight: true, addedLoadKg: -20 };
+		const timed: LiftingSetMetrics = { ...workingSet(1, 0), reps: undefined, durationSeconds: 45 };
+		expect(computeSetVolume(warmup)).toBe(0);
+		expect(computeSetVolume(weighted)).toBe(80);
+		expect(computeSetVolume(assisted)).toBe(0);
+		expect(computeSetVolume(timed)).toBe(0);
+		expect(
+			computeTotalVolume([
+				exercise(1, [
+					liftingSet(1, warmup, loggedAt),
+					liftingSet(2, weighted, loggedAt),
+					set(3, 5, 100, loggedAt)
+				])
+			])
+		).toBe(580);
+		expect(formatSet(liftingSet(2, weighted, loggedAt))).toBe('8 × BW +10 kg');
+		expect(formatSet(liftingSet(4, timed, loggedAt))).toBe('45 s');
+		expect(formatSet(set(3, 5, 100, loggedAt))).toBe('5 × 100 kg');
+	});
+
 	it('[WO-007] sets completed counts logged sets across the workout', () => {
 		const exercises = [
 			exercise(1, [
@@ -142,7 +179,7 @@ describe('workout derived metrics', () => {
 			set(1, 10, 80, '2026-06-01T10:01:00.000Z'),
 			set(2, 8, 82.5, '2026-06-01T10:03:00.000Z')
 		]);
-		expect(prefillFromPrevious(withSets)).toEqual({ reps: 8, weightKg: 82.5 });
+		expect(prefillFromPrevious(withSets)).toEqual(workingSet(8, 82.5));
 		expect(prefillFromPrevious(exercise(2, []))).toBeNull();
 	});
 
@@ -166,10 +203,15 @@ describe('workout derived metrics', () => {
 	});
 
 	it('[WO-019] client validation rejects out-of-range metrics, accepts valid', () => {
-		expect(validateLiftingSet({ reps: 10, weightKg: 80 })).toBeNull();
-		expect(validateLiftingSet({ reps: 0, weightKg: 80 })).toMatch(/Reps/);
-		expect(validateLiftingSet({ reps: 1.5, weightKg: 80 })).toMatch(/Reps/);
-		expect(validateLiftingSet({ reps: 10, weightKg: -1 })).toMatch(/Weight/);
-		expect(validateLiftingSet({ reps: 10, weightKg: 1001 })).toMatch(/Weight/);
+		expect(validateLiftingSet(workingSet(10, 80))).toBeNull();
+		expect(validateLiftingSet(workingSet(0, 80))).toMatch(/Reps/);
+		expect(validateLiftingSet(workingSet(1.5, 80))).toMatch(/Reps/);
+		expect(validateLiftingSet(workingSet(10, -1))).toMatch(/Weight/);
+		expect(validateLiftingSet(workingSet(10, 1001))).toMatch(/Weight/);
+		// Reps are optional for timed sets, but one of them is needed
+		const timed = { ...workingSet(1, 0), reps: undefined };
+		expect(validateLiftingSet({ ...timed, durationSeconds: 45 })).toBeNull();
+		expect(validateLiftingSet({ ...timed, durationSeconds: 4000 })).toMatch(/Duration/);
+		expect(validateLiftingSet(timed)).toMatch(/reps or a duration/);
 	});
 });
@@ -9,6 +9,7 @@
  */
 import type {
 	LiftingSetMetrics,
+	LoggedSet,
 	WorkoutDetail,
 	WorkoutExerciseView,
 	WorkoutPauseView
@@ -40,14 +41,57 @@ export function isPaused(pauses: WorkoutPauseView[]): boolean {
 	return pauses.some((p) => !p.resumedAt);
 }
 
-/** Total volume: Σ(reps × weightKg) across all logged sets. */
+/** The lifting metrics of a logged set, or null for sets of other workout types. */
+export function liftingMetrics(set: LoggedSet): LiftingSetMetrics | null {
+	return set.metrics.type === 'wl' ? set.metrics.data : null;
+}
+
+/** A plain working set of reps at an external load, as the set mask logs it. */
+export function workingSet(reps: number, weightKg: number): LiftingSetMetrics {
+	return { reps, weightKg, bodyweight: false, kind: 'working' };
+}
+
+/**
+ * Working volume of a lifting set, mirroring the backend `LiftingSetMetrics::volume`:
+ * reps × external load. Warm-ups and time-only sets count nothing; bodyweight sets
+ * count only their added load.
+ */
+export function computeSetVolume(metrics: LiftingSetMetrics): number {
+	if (metrics.kind === 'warmup') return 0;
+	const load = metrics.bodyweight ? Math.max(metrics.addedLoadKg ?? 0, 0) : metrics.weightKg;
+	return (metrics.reps ?? 0) * load;
+}
+
+/** Total volume: Σ working volume across all logged lifting sets. */
 export function computeTotalVolume(exercises: WorkoutExerciseView[]): number {
 	return exercises.reduce(
-		(acc, ex) => acc + ex.sets.reduce((s, set) => s + set.metrics.reps * set.metrics.weightKg, 0),
+		(acc, ex) =>
+			acc +
+			ex.sets.reduce((s, set) => {
+				const metrics = liftingMetrics(set);
+				return s + (metrics ? computeSetVolume(metrics) : 0);
+			}, 0),
 		0
 	);
 }
 
+/** Short label of a logged set, e.g. `8 × 100 kg`, `10 × BW +10 kg` or `45 s`. */
+export function formatSet(set: LoggedSet): string {
+	if (set.metrics.type !== 'wl') {
+		return `${Math.round(set.metrics.data.durationSeconds / 60)} min`;
+	}
+	const metrics = set.metrics.data;
+	const amount = metrics.reps ?? `${metrics.durationSeconds} s`;
+	if (!metrics.bodyweight) {
+		// An unloaded timed set (e.g. a plank) is just its duration
+		if (metrics.reps == null && metrics.weightKg === 0) return `${amount}`;
+		return `${amount} × ${metrics.weightKg} kg`;
+	}
+	const added = metrics.addedLoadKg ?? 0;
+	if (added === 0) return `${amount} × BW`;
+	return `${amount} × BW ${added > 0 ? '+' : '−'}${Math.abs(added)} kg`;
+}
+
 /** Sets completed: the count of logged sets in the session. */
 export function computeSetsCompleted(exercises: WorkoutExerciseView[]): number {
 	return exercises.reduce((acc, ex) => acc + ex.sets.length, 0);
@@ -101,24 +145,50 @@ export function isResting(
  */
 export function prefillFromPrevious(exercise: WorkoutExerciseView): LiftingSetMetrics | null {
 	if (exercise.sets.length === 0) return null;
-	const last = exercise.sets[exercise.sets.length - 1];
-	return { reps: last.metrics.reps, weightKg: last.metrics.weightKg };
+	const last = liftingMetrics(exercise.sets[exercise.sets.length - 1]);
+	if (!last) return null;
+	return {
+		reps: last.reps,
+		durationSeconds: last.durationSeconds,
+		weightKg: last.weightKg,
+		bodyweight: last.bodyweight,
+		addedLoadKg: last.addedLoadKg,
+		kind: last.kind
+	};
 }
 
 // Bounds mirror the backend `LiftingSetMetrics` validator (reps 1–1000,
-// weight 0–1000 kg). The generated Zod schema coerces/bounds too; these are
-// checked here for inline pre-submit feedback per `_conv-validation`; the Rust
-// command stays authoritative.
+// duration 1–3600 s, weight 0–1000 kg; reps or a duration required). The
+// generated Zod schema coerces/bounds too; these are checked here for inline
+// pre-submit feedback per `_conv-validation`; the Rust command stays
+// authoritative.
 const REPS_MIN = 1;
 const REPS_MAX = 1000;
+const DURATION_MIN = 1;
+const DURATION_MAX = 3600;
 const WEIGHT_MIN = 0;
 const WEIGHT_MAX = 1000;
 
 /** Validate set metrics; returns an error message, or `null` when valid. */
 export function validateLiftingSet(metrics: LiftingSetMetrics): string | null {
-	if (!Number.isInteger(metrics.reps) || metrics.reps < REPS_MIN || metrics.reps > REPS_MAX) {
+	// Optional fields arrive as null from the backend
+	if (metrics.reps == null && metrics.durationSeconds == null) {
+		return 'A set needs reps or a duration';
+	}
+	if (
+		metrics.reps != null &&
+		(!Number.isInteger(metrics.reps) || metrics.reps < REPS_MIN || metrics.reps > REPS_MAX)
+	) {
 		return `Reps must be between ${REPS_MIN} and ${REPS_MAX}`;
 	}
+	if (
+		metrics.durationSeconds != null &&
+		(!Number.isInteger(metrics.durationSeconds) ||
+			metrics.durationSeconds < DURATION_MIN ||
+			metrics.durationSeconds > DURATION_MAX)
+	) {
+		return 'Duration must be between 1 second and 1 hour';
+	}
 	if (
 		!Number.isFinite(metrics.weightKg) ||
 		metrics.weightKg < WEIGHT_MIN ||
@@ -26,7 +26,7 @@ function makeData() {
 	return {
 		bodyData: {
 			id: 1,
-			age: 30,
+			birthDate: '1996-05-01',
 			sex: 'MALE',
 			height: 180,
 			weight: 80,
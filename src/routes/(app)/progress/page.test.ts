@@ -40,6 +40,7 @@ vi.mock('@number-flow/svelte', () => {
 
 import Page from './+page.svelte';
 import { listWorkouts } from '$lib/api';
+import { workingSet } from '$lib/workout/metrics';
 
 // jsdom in this setup has no full localStorage; back it with a simple Map.
 const lsStore = new Map<string, string>();
@@ -73,7 +74,13 @@ function progressWorkout(id: number, name: string) {
 				exerciseId: 1,
 				name: 'Bench Press',
 				defaultRestSeconds: 90,
-				sets: [{ id, loggedAt: '2026-06-04T10:05:00.000Z', metrics: { reps: 10, weightKg: 80 } }]
+				sets: [
+					{
+						id,
+						loggedAt: '2026-06-04T10:05:00.000Z',
+						metrics: { type: 'wl', data: workingSet(10, 80) }
+					}
+				]
 			}
 		],
 		pauses: []
@@ -40,19 +40,22 @@ describe('wizard re-run page', () => {
 	it('[PF-014] should pre-populate body data on the first wizard step from the loader', () => {
 		renderWizard({
 			id: 1,
-			age: 42,
+			birthDate: '1984-02-29',
 			sex: 'FEMALE',
 			height: 165,
 			weight: 62,
 			activityLevel: 1.5
 		});
 
-		// Step 1 (Body) renders range inputs bound to age/height/weight.
+		// Step 1 (Body) renders a date input for the birth date and range inputs for
+		// height/weight.
+		expect(document.querySelector<HTMLInputElement>('input[type="date"]')?.value).toBe(
+			'1984-02-29'
+		);
 		const ranges = Array.from(
 			document.querySelectorAll<HTMLInputElement>('input[type="range"]')
 		).map((el) => el.value);
 
-		expect(ranges).toContain('42');
 		expect(ranges).toContain('165');
 		expect(ranges).toContain('62');
 	});
@@ -60,7 +63,7 @@ describe('wizard re-run page', () => {
 	it('[PF-014] should display the wizard header with the first-step title', () => {
 		renderWizard({
 			id: 1,
-			age: 30,
+			birthDate: '1996-05-01',
 			sex: 'MALE',
 			height: 180,
 			weight: 80,